@@ -217,6 +217,7 @@ fn xyk_create_pool_with_lrna_should_be_filtered_by_call_filter() {
 			amount_a: UNITS,
 			asset_b: DOT,
 			amount_b: UNITS,
+			fee: None,
 		});
 
 		assert!(!hydradx_runtime::CallFilter::contains(&call));
@@ -1,9 +1,11 @@
 #![cfg(test)]
 
 use crate::polkadot_test_net::*;
+use frame_support::traits::OnIdle;
+use frame_support::weights::Weight;
 use frame_support::{assert_noop, assert_ok};
 use frame_system::RawOrigin;
-use hydradx_runtime::{Balances, CircuitBreaker, Omnipool, OmnipoolCollectionId, Tokens, Uniques};
+use hydradx_runtime::{Balances, CircuitBreaker, Omnipool, OmnipoolCollectionId, Tokens, TreasuryAccount, Uniques};
 use orml_traits::MultiCurrency;
 use primitives::constants::chain::CORE_ASSET_ID;
 use primitives::Balance;
@@ -577,6 +579,59 @@ fn remove_liquidity_to_omnipool_should_not_fail_when_liquidity_limit_per_block_e
 	});
 }
 
+#[test]
+fn hub_asset_buyback_should_update_trade_volume_of_sold_asset() {
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+
+		assert_ok!(Tokens::set_balance(
+			RawOrigin::Root.into(),
+			ALICE.into(),
+			LRNA,
+			1_000 * UNITS,
+			0,
+		));
+
+		// Selling hub asset to the pool creates negative imbalance
+		assert_ok!(Omnipool::sell(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			LRNA,
+			CORE_ASSET_ID,
+			1_000 * UNITS,
+			0
+		));
+
+		assert_ok!(Balances::set_balance(
+			RawOrigin::Root.into(),
+			TreasuryAccount::get(),
+			1_000 * UNITS,
+			0,
+		));
+		assert_ok!(Omnipool::set_imbalance_buyback_budget(
+			RawOrigin::Root.into(),
+			10 * UNITS
+		));
+
+		let volume_in = CircuitBreaker::allowed_trade_volume_limit_per_asset(CORE_ASSET_ID)
+			.unwrap()
+			.volume_in;
+
+		//Act
+		Omnipool::on_idle(hydradx_runtime::System::block_number(), Weight::MAX);
+
+		//Assert
+		let sold_amount = 1_000 * UNITS - Balances::free_balance(&TreasuryAccount::get());
+		assert!(sold_amount > 0);
+		assert_eq!(
+			CircuitBreaker::allowed_trade_volume_limit_per_asset(CORE_ASSET_ID)
+				.unwrap()
+				.volume_in,
+			volume_in + sold_amount
+		);
+	});
+}
+
 fn init_omnipool() {
 	assert_ok!(hydradx_runtime::Omnipool::add_token(
 		hydradx_runtime::RuntimeOrigin::root(),
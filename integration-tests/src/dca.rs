@@ -19,7 +19,7 @@ use hydradx_traits::router::Trade;
 use hydradx_traits::Registry;
 use orml_traits::MultiCurrency;
 use orml_traits::MultiReservableCurrency;
-use pallet_dca::types::{Order, Schedule};
+use pallet_dca::types::{Order, PortfolioOrder, Schedule};
 use pallet_stableswap::types::AssetAmount;
 use pallet_stableswap::MAX_ASSETS_IN_POOL;
 use polkadot_primitives::v2::BlockNumber;
@@ -664,6 +664,69 @@ mod omnipool {
 		});
 	}
 
+	#[test]
+	fn portfolio_schedule_should_sell_budget_split_between_its_orders() {
+		TestNet::reset();
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipol();
+			assert_ok!(Omnipool::add_token(
+				RuntimeOrigin::root(),
+				DOT,
+				FixedU128::from_float(0.9),
+				Permill::from_percent(60),
+				Omnipool::protocol_account(),
+			));
+			do_trade_to_populate_oracle(DAI, HDX, UNITS);
+			do_trade_to_populate_oracle(DOT, HDX, UNITS);
+			set_relaychain_block_number(10);
+			do_trade_to_populate_oracle(DAI, HDX, UNITS);
+			do_trade_to_populate_oracle(DOT, HDX, UNITS);
+
+			let dca_budget = 1000 * UNITS;
+			let amount_to_sell = 100 * UNITS;
+			let schedule = Schedule {
+				owner: AccountId::from(ALICE),
+				period: 5u32,
+				total_amount: dca_budget,
+				max_retries: None,
+				stability_threshold: None,
+				slippage: Some(Permill::from_percent(5)),
+				order: Order::Portfolio {
+					asset_in: HDX,
+					amount_in: amount_to_sell,
+					orders: BoundedVec::truncate_from(vec![
+						PortfolioOrder {
+							asset_out: DAI,
+							weight: Permill::from_percent(70),
+							min_amount_out: Balance::MIN,
+							route: create_bounded_vec(vec![]),
+						},
+						PortfolioOrder {
+							asset_out: DOT,
+							weight: Permill::from_percent(30),
+							min_amount_out: Balance::MIN,
+							route: create_bounded_vec(vec![]),
+						},
+					]),
+				},
+			};
+			create_schedule(ALICE, schedule);
+
+			//Act
+			set_relaychain_block_number(11);
+
+			//Assert
+			let fee = Currencies::free_balance(HDX, &Treasury::account_id()) - TREASURY_ACCOUNT_INIT_BALANCE;
+			assert!(fee > 0);
+			assert_reserved_balance!(&ALICE.into(), HDX, dca_budget - amount_to_sell - fee);
+			assert!(Currencies::free_balance(DAI, &AccountId::from(ALICE)) > ALICE_INITIAL_DAI_BALANCE);
+			assert!(Currencies::free_balance(DOT, &AccountId::from(ALICE)) > ALICE_INITIAL_DOT_BALANCE);
+			assert_eq!(count_dca_event!(pallet_dca::Event::PortfolioTradeExecuted { .. }), 2);
+			assert_eq!(count_dca_event!(pallet_dca::Event::TradeFailed { .. }), 0);
+		});
+	}
+
 	#[test]
 	fn sell_schedule_execution_should_work_when_hub_asset_is_sold() {
 		TestNet::reset();
@@ -2164,6 +2227,7 @@ mod xyk {
 				1000 * UNITS,
 				DAI,
 				2000 * UNITS,
+				None,
 			));
 
 			//For populating oracle
@@ -2411,6 +2475,7 @@ fn crate_xyk_pool(asset_a: AssetId, amount_a: Balance, asset_b: AssetId, amount_
 		amount_a,
 		asset_b,
 		amount_b,
+		None,
 	));
 }
 
@@ -3033,6 +3098,7 @@ fn create_xyk_pool_with_amounts(asset_a: u32, amount_a: u128, asset_b: u32, amou
 		amount_a,
 		asset_b,
 		amount_b,
+		None,
 	));
 }
 
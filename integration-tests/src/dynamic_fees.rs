@@ -75,6 +75,49 @@ fn fees_should_initialize_lazily_to_min_value_when_first_trade_happens() {
 	});
 }
 
+#[test]
+fn fees_should_initialize_lazily_to_asset_fee_params_override_when_set() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		init_omnipool();
+		init_oracle();
+		hydradx_run_to_block(10);
+
+		let asset_fee_params = pallet_dynamic_fees::types::FeeParams {
+			min_fee: Permill::from_percent(2),
+			max_fee: Permill::from_percent(10),
+			decay: FixedU128::from_rational(5, 1_000_000),
+			amplification: FixedU128::from(2),
+		};
+		let protocol_fee_params =
+			<hydradx_runtime::Runtime as pallet_dynamic_fees::Config>::ProtocolFeeParameters::get();
+
+		assert_ok!(hydradx_runtime::DynamicFees::set_asset_fee_params(
+			hydradx_runtime::RuntimeOrigin::root(),
+			HDX,
+			asset_fee_params,
+			protocol_fee_params,
+		));
+
+		//Act
+		assert_ok!(hydradx_runtime::Omnipool::sell(
+			hydradx_runtime::RuntimeOrigin::signed(DAVE.into()),
+			DOT,
+			HDX,
+			2 * DOT_UNITS,
+			0,
+		));
+
+		//Assert
+		assert_eq!(
+			hydradx_runtime::DynamicFees::current_fees(HDX).unwrap().asset_fee,
+			asset_fee_params.min_fee
+		);
+	});
+}
+
 #[test]
 fn fees_should_initialize_lazily_to_min_value_when_first_buy_happens() {
 	TestNet::reset();
@@ -311,6 +354,99 @@ fn fees_should_change_only_one_when_trades_happen_in_the_same_block() {
 	});
 }
 
+#[test]
+fn xyk_pool_fee_should_change_when_trades_happen_in_different_blocks() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		set_balance(DAVE.into(), HDX, 10_000 * UNITS as i128);
+		set_balance(DAVE.into(), DOT, 1_000 * DOT_UNITS as i128);
+
+		assert_ok!(hydradx_runtime::XYK::create_pool(
+			hydradx_runtime::RuntimeOrigin::signed(DAVE.into()),
+			HDX,
+			1_000 * UNITS,
+			DOT,
+			100 * DOT_UNITS,
+			None,
+		));
+		let pool = hydradx_runtime::XYK::pair_account_from_assets(HDX, DOT);
+
+		hydradx_run_to_block(10);
+
+		assert_ok!(hydradx_runtime::XYK::sell(
+			hydradx_runtime::RuntimeOrigin::signed(DAVE.into()),
+			DOT,
+			HDX,
+			10 * DOT_UNITS,
+			0,
+			false,
+		));
+
+		// Fees are not recalculated because nothing has been provided by oracle yet
+		assert!(hydradx_runtime::XYKDynamicFees::current_fees(&pool).is_none());
+
+		hydradx_run_to_block(11);
+
+		//Act
+		assert_ok!(hydradx_runtime::XYK::sell(
+			hydradx_runtime::RuntimeOrigin::signed(DAVE.into()),
+			DOT,
+			HDX,
+			10 * DOT_UNITS,
+			0,
+			false,
+		));
+
+		//Assert
+		let asset_fee_params = <hydradx_runtime::Runtime as pallet_dynamic_fees::Config<
+			hydradx_runtime::XYKDynamicFeesInstance,
+		>>::AssetFeeParameters::get();
+
+		let fee_entry = hydradx_runtime::XYKDynamicFees::current_fees(&pool).unwrap();
+		assert!(fee_entry.asset_fee > asset_fee_params.min_fee);
+		assert_eq!(fee_entry.timestamp, 11_u32);
+	});
+}
+
+#[test]
+fn xyk_pool_should_charge_pool_fee_when_it_is_min_pool_fee_and_there_is_no_volume() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		//Arrange
+		set_balance(DAVE.into(), HDX, 10_000 * UNITS as i128);
+		set_balance(DAVE.into(), DOT, 1_000 * DOT_UNITS as i128);
+
+		assert_ok!(hydradx_runtime::XYK::create_pool(
+			hydradx_runtime::RuntimeOrigin::signed(DAVE.into()),
+			HDX,
+			1_000 * UNITS,
+			DOT,
+			100 * DOT_UNITS,
+			Some((1, 1_000)),
+		));
+
+		let hdx_balance = hydradx_runtime::Balances::free_balance(&AccountId::from(DAVE));
+
+		//Act
+		assert_ok!(hydradx_runtime::XYK::sell(
+			hydradx_runtime::RuntimeOrigin::signed(DAVE.into()),
+			DOT,
+			HDX,
+			10 * DOT_UNITS,
+			0,
+			false,
+		));
+
+		//Assert
+		// amount out without fee is 90_909_090_909_090, fee is 0.1% of it
+		let received = hydradx_runtime::Balances::free_balance(&AccountId::from(DAVE)) - hdx_balance;
+		assert_eq!(received, 90_909_090_909_090 - 90_909_090_909);
+	});
+}
+
 fn set_balance(who: hydradx_runtime::AccountId, currency: AssetId, amount: i128) {
 	assert_ok!(hydradx_runtime::Currencies::update_balance(
 		hydradx_runtime::RuntimeOrigin::root(),
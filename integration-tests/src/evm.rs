@@ -10,7 +10,7 @@ use hydradx_runtime::{
 		addr,
 		handle::EvmDataWriter,
 		multicurrency::{Action, MultiCurrencyPrecompile},
-		Address, Bytes, EvmAddress, HydraDXPrecompiles,
+		Address, Bytes, EvmAddress, HydraDXPrecompiles, ROUTER_ADDR,
 	},
 	AssetRegistry, Balances, CallFilter, Currencies, RuntimeCall, RuntimeOrigin, Tokens, TransactionPause, EVM,
 };
@@ -18,7 +18,7 @@ use orml_traits::MultiCurrency;
 use pallet_evm::*;
 use pretty_assertions::assert_eq;
 use sp_core::{blake2_256, H160, H256, U256};
-use sp_runtime::{traits::SignedExtension, FixedU128, Permill};
+use sp_runtime::{traits::SignedExtension, FixedPointNumber, FixedU128, Permill};
 use std::borrow::Cow;
 use xcm_emulator::TestExt;
 
@@ -337,7 +337,7 @@ mod currency_precompile {
 	}
 
 	#[test]
-	fn precompile_for_currency_approve_allowance_should_fail_as_not_supported() {
+	fn precompile_for_currency_approve_should_set_allowance() {
 		TestNet::reset();
 
 		Hydra::execute_with(|| {
@@ -358,7 +358,47 @@ mod currency_precompile {
 				input: data,
 				context: Context {
 					address: evm_address(),
-					caller: native_asset_ethereum_address(),
+					caller: evm_address(),
+					apparent_value: U256::from(0),
+				},
+				core_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: EvmDataWriter::new().write(true).build()
+				})
+			);
+			assert_eq!(
+				Currencies::allowance((HDX, evm_account(), evm_account2())),
+				50u128 * UNITS
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_currency_approve_should_fail_in_static_context() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			let data = EvmDataWriter::new_with_selector(Action::Approve)
+				.write(Address::from(evm_address2()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: evm_address(),
+					caller: evm_address(),
 					apparent_value: U256::from(0),
 				},
 				core_address: native_asset_ethereum_address(),
@@ -371,19 +411,23 @@ mod currency_precompile {
 			//Assert
 			assert_eq!(
 				result,
-				Err(PrecompileFailure::Error {
-					exit_status: pallet_evm::ExitError::Other("not supported".into())
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "can't call non-static function in static context".into()
 				})
 			);
+			assert_eq!(Currencies::allowance((HDX, evm_account(), evm_account2())), 0);
 		});
 	}
 
 	#[test]
-	fn precompile_for_currency_allowance_should_fail_as_not_supported() {
+	fn precompile_for_currency_allowance_should_work() {
 		TestNet::reset();
 
 		Hydra::execute_with(|| {
 			//Arrange
+			assert_ok!(Currencies::approve(HDX, &evm_account(), &evm_account2(), 100 * UNITS));
+
 			let data = EvmDataWriter::new_with_selector(Action::Allowance)
 				.write(Address::from(evm_address()))
 				.write(Address::from(evm_address2()))
@@ -404,17 +448,24 @@ mod currency_precompile {
 			let result = CurrencyPrecompile::execute(&mut handle);
 
 			//Assert
+
+			// 100 * UNITS
+			let expected_output = hex! {"
+				00000000000000000000000000000000 000000000000000000005AF3107A4000
+			"};
+
 			assert_eq!(
 				result,
-				Err(PrecompileFailure::Error {
-					exit_status: pallet_evm::ExitError::Other("not supported".into())
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: expected_output.to_vec()
 				})
 			);
 		});
 	}
 
 	#[test]
-	fn precompile_for_transfer_from_should_fail_as_not_supported() {
+	fn precompile_for_transfer_from_should_work_when_allowance_is_sufficient() {
 		TestNet::reset();
 
 		Hydra::execute_with(|| {
@@ -425,6 +476,7 @@ mod currency_precompile {
 				HDX,
 				100 * UNITS as i128,
 			));
+			assert_ok!(Currencies::approve(HDX, &evm_account(), &evm_account2(), 80 * UNITS));
 
 			let data = EvmDataWriter::new_with_selector(Action::TransferFrom)
 				.write(Address::from(evm_address()))
@@ -434,6 +486,48 @@ mod currency_precompile {
 
 			let mut handle = MockHandle {
 				input: data,
+				context: Context {
+					address: evm_address2(),
+					caller: evm_address2(),
+					apparent_value: U256::from(0),
+				},
+				core_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(result.unwrap().exit_status, ExitSucceed::Returned);
+			assert_balance!(evm_account2(), HDX, 50u128 * UNITS);
+			assert_eq!(
+				Currencies::allowance((HDX, evm_account(), evm_account2())),
+				30u128 * UNITS
+			);
+		});
+	}
+
+	#[test]
+	fn precompile_for_transfer_from_should_work_when_max_uint256_is_approved() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				HDX,
+				100 * UNITS as i128,
+			));
+
+			let approve_data = EvmDataWriter::new_with_selector(Action::Approve)
+				.write(Address::from(evm_address2()))
+				.write(U256::MAX)
+				.build();
+
+			let mut approve_handle = MockHandle {
+				input: approve_data,
 				context: Context {
 					address: evm_address(),
 					caller: evm_address(),
@@ -443,20 +537,135 @@ mod currency_precompile {
 				is_static: false,
 			};
 
+			assert_eq!(
+				CurrencyPrecompile::execute(&mut approve_handle).unwrap().exit_status,
+				ExitSucceed::Returned
+			);
+			assert_eq!(Currencies::allowance((HDX, evm_account(), evm_account2())), u128::MAX);
+
+			let data = EvmDataWriter::new_with_selector(Action::TransferFrom)
+				.write(Address::from(evm_address()))
+				.write(Address::from(evm_address2()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: evm_address2(),
+					caller: evm_address2(),
+					apparent_value: U256::from(0),
+				},
+				core_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(result.unwrap().exit_status, ExitSucceed::Returned);
+			assert_balance!(evm_account2(), HDX, 50u128 * UNITS);
+			assert_eq!(Currencies::allowance((HDX, evm_account(), evm_account2())), u128::MAX);
+		});
+	}
+
+	#[test]
+	fn precompile_for_transfer_from_should_fail_when_amount_overflows_balance() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				HDX,
+				100 * UNITS as i128,
+			));
+			assert_ok!(Currencies::approve(HDX, &evm_account(), &evm_account2(), u128::MAX));
+
+			let data = EvmDataWriter::new_with_selector(Action::TransferFrom)
+				.write(Address::from(evm_address()))
+				.write(Address::from(evm_address2()))
+				.write(U256::MAX)
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: evm_address2(),
+					caller: evm_address2(),
+					apparent_value: U256::from(0),
+				},
+				core_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
 			//Act
 			let result = CurrencyPrecompile::execute(&mut handle);
 
 			//Assert
 			assert_eq!(
 				result,
-				Err(PrecompileFailure::Error {
-					exit_status: pallet_evm::ExitError::Other("not supported".into())
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "amount overflow".into()
 				})
 			);
 			assert_balance!(evm_account2(), HDX, 0);
 		});
 	}
 
+	#[test]
+	fn precompile_for_transfer_from_should_fail_when_allowance_is_insufficient() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				HDX,
+				100 * UNITS as i128,
+			));
+			assert_ok!(Currencies::approve(HDX, &evm_account(), &evm_account2(), 10 * UNITS));
+
+			let data = EvmDataWriter::new_with_selector(Action::TransferFrom)
+				.write(Address::from(evm_address()))
+				.write(Address::from(evm_address2()))
+				.write(U256::from(50u128 * UNITS))
+				.build();
+
+			let mut handle = MockHandle {
+				input: data,
+				context: Context {
+					address: evm_address2(),
+					caller: evm_address2(),
+					apparent_value: U256::from(0),
+				},
+				core_address: native_asset_ethereum_address(),
+				is_static: false,
+			};
+
+			//Act
+			let result = CurrencyPrecompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "InsufficientAllowance".into()
+				})
+			);
+			assert_balance!(evm_account2(), HDX, 0);
+			assert_eq!(
+				Currencies::allowance((HDX, evm_account(), evm_account2())),
+				10u128 * UNITS
+			);
+		});
+	}
+
 	fn account_to_default_evm_address(account_id: &impl Encode) -> EvmAddress {
 		let payload = (b"evm:", account_id);
 		EvmAddress::from_slice(&payload.using_encoded(blake2_256)[0..20])
@@ -468,6 +677,203 @@ mod currency_precompile {
 	}
 }
 
+mod router_precompile {
+	use super::*;
+	use hydradx_runtime::{
+		evm::precompiles::router::{Action, RouterPrecompile},
+		Omnipool, Router,
+	};
+	use pretty_assertions::assert_eq;
+
+	type Precompile = RouterPrecompile<hydradx_runtime::Runtime>;
+
+	fn create_router_handle(data: Vec<u8>, is_static: bool) -> MockHandle {
+		MockHandle {
+			input: data,
+			context: Context {
+				address: ROUTER_ADDR,
+				caller: evm_address(),
+				apparent_value: U256::zero(),
+			},
+			core_address: ROUTER_ADDR,
+			is_static,
+		}
+	}
+
+	#[test]
+	fn quote_sell_should_return_amount_out_of_router_quote() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipol();
+
+			let amount_in = 10 * UNITS;
+			let data = EvmDataWriter::new_with_selector(Action::QuoteSell)
+				.write(HDX)
+				.write(DAI)
+				.write(U256::from(amount_in))
+				.build();
+
+			let mut handle = create_router_handle(data, true);
+
+			//Act
+			let result = Precompile::execute(&mut handle);
+
+			//Assert
+			let expected_amount_out = Router::quote_sell(HDX, DAI, amount_in, vec![])
+				.unwrap()
+				.last()
+				.unwrap()
+				.amount_out;
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: EvmDataWriter::new().write(expected_amount_out).build()
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn sell_should_work_with_default_route() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipol();
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				hydradx_runtime::RuntimeOrigin::root(),
+				evm_account(),
+				HDX,
+				100 * UNITS as i128,
+			));
+
+			let amount_in = 10 * UNITS;
+			let expected_amount_out = Router::quote_sell(HDX, DAI, amount_in, vec![])
+				.unwrap()
+				.last()
+				.unwrap()
+				.amount_out;
+
+			let data = EvmDataWriter::new_with_selector(Action::Sell)
+				.write(HDX)
+				.write(DAI)
+				.write(U256::from(amount_in))
+				.write(U256::from(expected_amount_out))
+				.build();
+
+			let mut handle = create_router_handle(data, false);
+
+			//Act
+			let result = Precompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: EvmDataWriter::new().write(expected_amount_out).build()
+				})
+			);
+			assert_balance!(evm_account(), HDX, 90 * UNITS);
+			assert_balance!(evm_account(), DAI, expected_amount_out);
+		});
+	}
+
+	#[test]
+	fn sell_should_fail_in_static_context() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipol();
+
+			let data = EvmDataWriter::new_with_selector(Action::Sell)
+				.write(HDX)
+				.write(DAI)
+				.write(U256::from(10 * UNITS))
+				.write(U256::zero())
+				.build();
+
+			let mut handle = create_router_handle(data, true);
+
+			//Act
+			let result = Precompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Err(PrecompileFailure::Revert {
+					exit_status: ExitRevert::Reverted,
+					output: "can't call non-static function in static context".into()
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn omnipool_spot_price_should_return_price_of_asset_a_denominated_in_asset_b() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipol();
+
+			let data = EvmDataWriter::new_with_selector(Action::OmnipoolSpotPrice)
+				.write(HDX)
+				.write(DAI)
+				.build();
+
+			let mut handle = create_router_handle(data, true);
+
+			//Act
+			let result = Precompile::execute(&mut handle);
+
+			//Assert
+			let hdx_price = Omnipool::load_asset_state(HDX).unwrap().price().unwrap();
+			let dai_price = Omnipool::load_asset_state(DAI).unwrap().price().unwrap();
+			let expected_price = hdx_price / dai_price;
+			assert_eq!(
+				result,
+				Ok(PrecompileOutput {
+					exit_status: ExitSucceed::Returned,
+					output: EvmDataWriter::new().write(expected_price.into_inner()).build()
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn omnipool_spot_price_should_fail_when_asset_is_not_in_omnipool() {
+		TestNet::reset();
+
+		Hydra::execute_with(|| {
+			//Arrange
+			init_omnipol();
+
+			let data = EvmDataWriter::new_with_selector(Action::OmnipoolSpotPrice)
+				.write(HDX)
+				.write(BTC)
+				.build();
+
+			let mut handle = create_router_handle(data, true);
+
+			//Act
+			let result = Precompile::execute(&mut handle);
+
+			//Assert
+			assert_eq!(
+				result,
+				Err(PrecompileFailure::Error {
+					exit_status: ExitError::Other("Price not available.".into())
+				})
+			);
+		});
+	}
+}
+
 #[test]
 fn dispatch_should_work_with_remark() {
 	TestNet::reset();
@@ -792,11 +1198,11 @@ impl PrecompileHandle for MockHandle {
 	}
 
 	fn remaining_gas(&self) -> u64 {
-		unimplemented!()
+		u64::MAX
 	}
 
 	fn log(&mut self, _: H160, _: Vec<H256>, _: Vec<u8>) -> Result<(), ExitError> {
-		unimplemented!()
+		Ok(())
 	}
 
 	fn code_address(&self) -> H160 {
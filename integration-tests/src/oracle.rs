@@ -14,7 +14,7 @@ use hydradx_traits::{
 };
 use pallet_ema_oracle::OracleError;
 use polkadot_primitives::v2::BlockNumber;
-use primitives::constants::chain::OMNIPOOL_SOURCE;
+use primitives::constants::chain::{OMNIPOOL_SOURCE, XYK_SOURCE};
 use xcm_emulator::TestExt;
 
 pub fn hydradx_run_to_block(to: BlockNumber) {
@@ -137,3 +137,105 @@ fn omnipool_hub_asset_trades_are_ingested_into_oracle() {
 		}
 	});
 }
+
+#[test]
+fn omnipool_trades_are_recorded_in_price_snapshots() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// arrange
+		hydradx_run_to_block(2);
+
+		init_omnipool();
+
+		assert_ok!(hydradx_runtime::Omnipool::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			DAI,
+			5 * UNITS,
+			0,
+		));
+
+		// act
+		hydradx_run_to_block(5);
+
+		// assert
+		let (last_block_price, _) = EmaOracle::get_price(HDX, LRNA, LastBlock, OMNIPOOL_SOURCE).unwrap();
+		assert_eq!(
+			EmaOracle::get_twap(OMNIPOOL_SOURCE, (HDX, LRNA), 2, 2),
+			Ok(last_block_price)
+		);
+		assert!(EmaOracle::get_twap(OMNIPOOL_SOURCE, (HDX, LRNA), 2, 4).is_ok());
+		assert_eq!(
+			EmaOracle::get_twap(OMNIPOOL_SOURCE, (HDX, LRNA), 1, 4),
+			Err(OracleError::NotPresent)
+		);
+	});
+}
+
+#[test]
+fn xyk_trades_are_ingested_into_oracle() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// arrange
+		hydradx_run_to_block(2);
+
+		for asset in [HDX, DOT] {
+			assert_ok!(hydradx_runtime::Currencies::update_balance(
+				RuntimeOrigin::root(),
+				DAVE.into(),
+				asset,
+				10_000 * UNITS as i128,
+			));
+		}
+
+		assert_ok!(hydradx_runtime::XYK::create_pool(
+			RuntimeOrigin::signed(DAVE.into()),
+			HDX,
+			1_000 * UNITS,
+			DOT,
+			100 * UNITS,
+			None,
+		));
+
+		// act
+		assert_ok!(hydradx_runtime::XYK::sell(
+			RuntimeOrigin::signed(DAVE.into()),
+			DOT,
+			HDX,
+			UNITS,
+			0,
+			false,
+		));
+
+		hydradx_run_to_block(3);
+
+		// assert
+		assert!(EmaOracle::get_price(HDX, DOT, LastBlock, XYK_SOURCE).is_ok());
+
+		// act
+		assert_ok!(EmaOracle::remove_oracle(RuntimeOrigin::root(), XYK_SOURCE, (HDX, DOT)));
+
+		// assert
+		assert_eq!(
+			EmaOracle::get_price(HDX, DOT, LastBlock, XYK_SOURCE),
+			Err(OracleError::NotPresent)
+		);
+
+		// act
+		assert_ok!(hydradx_runtime::XYK::sell(
+			RuntimeOrigin::signed(DAVE.into()),
+			DOT,
+			HDX,
+			UNITS,
+			0,
+			false,
+		));
+
+		hydradx_run_to_block(4);
+
+		// assert
+		assert!(EmaOracle::get_price(HDX, DOT, LastBlock, XYK_SOURCE).is_ok());
+	});
+}
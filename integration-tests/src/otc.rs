@@ -37,6 +37,9 @@ fn place_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			false,
+			None,
+			None,
+			None,
 		));
 
 		// Assert
@@ -61,6 +64,9 @@ fn partial_fill_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			true,
+			None,
+			None,
+			None,
 		));
 
 		// Act
@@ -92,6 +98,9 @@ fn fill_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			true,
+			None,
+			None,
+			None,
 		));
 
 		// Act
@@ -122,6 +131,9 @@ fn cancel_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			true,
+			None,
+			None,
+			None,
 		));
 
 		// Act
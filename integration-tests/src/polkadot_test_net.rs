@@ -340,6 +340,18 @@ pub fn hydra_ext() -> sp_io::TestExternalities {
 	)
 	.unwrap();
 
+	<pallet_stableswap_liquidity_mining::GenesisConfig as GenesisBuild<Runtime>>::assimilate_storage(
+		&pallet_stableswap_liquidity_mining::GenesisConfig::default(),
+		&mut t,
+	)
+	.unwrap();
+
+	<pallet_xyk_liquidity_mining::GenesisConfig as GenesisBuild<Runtime>>::assimilate_storage(
+		&pallet_xyk_liquidity_mining::GenesisConfig::default(),
+		&mut t,
+	)
+	.unwrap();
+
 	let mut ext = sp_io::TestExternalities::new(t);
 	ext.execute_with(|| {
 		System::set_block_number(1);
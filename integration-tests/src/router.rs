@@ -3349,6 +3349,7 @@ fn create_xyk_pool(asset_a: u32, asset_b: u32) {
 		100 * UNITS,
 		asset_b,
 		50 * UNITS,
+		None,
 	));
 }
 
@@ -3372,6 +3373,7 @@ fn create_xyk_pool_with_amounts(asset_a: u32, amount_a: u128, asset_b: u32, amou
 		amount_a,
 		asset_b,
 		amount_b,
+		None,
 	));
 }
 
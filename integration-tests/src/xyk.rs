@@ -32,6 +32,7 @@ fn pair_account_should_be_added_into_whitelist_when_pool_is_created() {
 			100 * UNITS,
 			asset_b,
 			200 * UNITS,
+			None,
 		));
 
 		//assert
@@ -54,6 +55,7 @@ fn pair_account_should_be_removed_from_whitelist_when_pool_was_destroyed() {
 			100 * UNITS,
 			asset_b,
 			200 * UNITS,
+			None,
 		));
 		assert!(DustRemovalWhitelist::contains(&pair_account(asset_a, asset_b)));
 
@@ -85,6 +87,7 @@ fn pool_should_be_created_when_it_was_destroyed_previously() {
 			100 * UNITS,
 			asset_b,
 			200 * UNITS,
+			None,
 		));
 		assert_ok!(XYK::remove_liquidity(
 			RuntimeOrigin::signed(ALICE.into()),
@@ -100,6 +103,7 @@ fn pool_should_be_created_when_it_was_destroyed_previously() {
 			100 * UNITS,
 			asset_b,
 			200 * UNITS,
+			None,
 		));
 	});
 }
@@ -119,6 +123,7 @@ fn share_asset_id_should_be_offset() {
 			100 * UNITS,
 			asset_b,
 			200 * UNITS,
+			None,
 		));
 
 		let share_token = XYK::get_share_token(AssetPair {
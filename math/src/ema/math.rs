@@ -6,6 +6,8 @@ use crate::types::{Balance, Fraction, Ratio};
 
 use num_traits::{One, Zero};
 use primitive_types::{U128, U256, U512};
+use sp_arithmetic::traits::CheckedDiv;
+use sp_arithmetic::{FixedPointNumber, FixedU128};
 
 /// EmaPrice is a rational number represented by a `u128` for both numerator and denominator.
 pub type EmaPrice = Ratio;
@@ -117,6 +119,35 @@ pub fn smoothing_from_period(period: u64) -> Fraction {
 	fraction::frac(2, u128::from(period.max(1)).saturating_add(1))
 }
 
+/// Add the `price` valid for `blocks` blocks to the `cumulative` price.
+///
+/// The price is converted to a fixed point number (saturating if it doesn't fit). The cumulative
+/// price wraps around on overflow, so the difference of two cumulative prices stays correct as long
+/// as the sum of the prices between them fits into `FixedU128`.
+pub fn accumulate_price(cumulative: FixedU128, price: EmaPrice, blocks: u64) -> FixedU128 {
+	let price = FixedU128::checked_from_rational(price.n, price.d).unwrap_or_else(|| FixedU128::from_inner(u128::MAX));
+	FixedU128::from_inner(
+		cumulative
+			.into_inner()
+			.wrapping_add(price.into_inner().wrapping_mul(u128::from(blocks))),
+	)
+}
+
+/// Calculate the time-weighted average price over `blocks` blocks from the cumulative prices at the
+/// start (`start`) and at the end (`end`) of the window.
+///
+/// The cumulative prices are subtracted with wrapping, see [`accumulate_price`].
+///
+/// Returns `None` if `blocks` is zero.
+pub fn time_weighted_average_price(start: FixedU128, end: FixedU128, blocks: u64) -> Option<EmaPrice> {
+	if blocks.is_zero() {
+		return None;
+	}
+	let twap = FixedU128::from_inner(end.into_inner().wrapping_sub(start.into_inner()))
+		.checked_div(&FixedU128::saturating_from_integer(blocks))?;
+	Some(EmaPrice::new(twap.into_inner(), FixedU128::DIV))
+}
+
 /// Calculate a weighted average for the given prices.
 /// `prev` is the previous oracle value, `incoming` is the new value to integrate.
 /// `weight` is how much weight to give the new value.
@@ -12,7 +12,7 @@ mod invariants;
 mod test_data;
 use test_data::*;
 
-use num_traits::One;
+use num_traits::{One, Zero};
 use primitive_types::{U128, U256, U512};
 use rug::Rational;
 use sp_arithmetic::{FixedPointNumber, FixedU128};
@@ -363,3 +363,59 @@ fn precision_of_ema_over_balancer_expanded_one_year_data_scrape_history_should_b
 		);
 	}
 }
+
+#[test]
+fn accumulate_price_works() {
+	let cumulative = FixedU128::from(10);
+	assert_eq!(
+		accumulate_price(cumulative, EmaPrice::new(3, 2), 4),
+		FixedU128::from(16)
+	);
+	assert_eq!(accumulate_price(cumulative, EmaPrice::new(3, 2), 0), cumulative);
+	assert_eq!(
+		accumulate_price(FixedU128::zero(), EmaPrice::new(1, 4), 2),
+		FixedU128::from_rational(1, 2)
+	);
+}
+
+#[test]
+fn accumulate_price_wraps_on_overflow() {
+	let cumulative = FixedU128::from_inner(u128::MAX - FixedU128::DIV + 1);
+	assert_eq!(
+		accumulate_price(cumulative, EmaPrice::new(3, 1), 2),
+		FixedU128::from_inner(5 * FixedU128::DIV)
+	);
+}
+
+#[test]
+fn time_weighted_average_price_works() {
+	let start = accumulate_price(FixedU128::zero(), EmaPrice::new(1, 1), 10);
+	let end = accumulate_price(start, EmaPrice::new(3, 1), 10);
+	assert_eq!(
+		time_weighted_average_price(FixedU128::zero(), end, 20),
+		Some(EmaPrice::new(2 * FixedU128::DIV, FixedU128::DIV))
+	);
+	assert_eq!(
+		time_weighted_average_price(start, end, 10),
+		Some(EmaPrice::new(3 * FixedU128::DIV, FixedU128::DIV))
+	);
+}
+
+#[test]
+fn time_weighted_average_price_fails_for_invalid_input() {
+	assert_eq!(
+		time_weighted_average_price(FixedU128::zero(), FixedU128::one(), 0),
+		None
+	);
+}
+
+#[test]
+fn time_weighted_average_price_works_when_cumulative_price_wraps() {
+	let start = FixedU128::from_inner(u128::MAX - FixedU128::DIV + 1);
+	let end = accumulate_price(start, EmaPrice::new(3, 1), 2);
+	assert!(end < start);
+	assert_eq!(
+		time_weighted_average_price(start, end, 2),
+		Some(EmaPrice::new(3 * FixedU128::DIV, FixedU128::DIV))
+	);
+}
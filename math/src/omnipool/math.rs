@@ -528,6 +528,45 @@ pub fn calculate_delta_imbalance(
 	to_balance!(delta_imbalance_hp).ok()
 }
 
+/// Calculate delta changes of a sell of asset for hub asset which is burned to reduce imbalance.
+///
+/// Amount sold is limited so that the hub asset bought does not exceed the imbalance.
+pub fn calculate_hub_asset_buyback_state_changes(
+	asset_state: &AssetReserveState<Balance>,
+	amount: Balance,
+	imbalance: Balance,
+) -> Option<HubTradeStateChange<Balance>> {
+	let (reserve_hp, hub_reserve_hp, imbalance_hp) = to_u256!(asset_state.reserve, asset_state.hub_reserve, imbalance);
+
+	// amount which buys exactly `imbalance` of hub asset
+	let amount = if imbalance_hp < hub_reserve_hp {
+		let max_amount_hp = reserve_hp
+			.checked_mul(imbalance_hp)
+			.and_then(|v| v.checked_div(hub_reserve_hp.checked_sub(imbalance_hp)?))?;
+		min(amount, to_balance!(max_amount_hp).unwrap_or(Balance::MAX))
+	} else {
+		amount
+	};
+
+	let amount_hp = to_u256!(amount);
+
+	let delta_hub_reserve_hp = amount_hp
+		.checked_mul(hub_reserve_hp)
+		.and_then(|v| v.checked_div(reserve_hp.checked_add(amount_hp)?))?;
+
+	let delta_hub_reserve = min(to_balance!(delta_hub_reserve_hp).ok()?, imbalance);
+
+	Some(HubTradeStateChange {
+		asset: AssetStateChange {
+			delta_reserve: Increase(amount),
+			delta_hub_reserve: Decrease(delta_hub_reserve),
+			..Default::default()
+		},
+		delta_imbalance: Increase(delta_hub_reserve),
+		fee: TradeFee::default(),
+	})
+}
+
 pub fn calculate_spot_sprice(
 	asset_a: &AssetReserveState<Balance>,
 	asset_b: &AssetReserveState<Balance>,
@@ -1,9 +1,9 @@
 use crate::omnipool::types::{AssetReserveState, BalanceUpdate, Position, TradeFee, I129};
 use crate::omnipool::{
 	calculate_add_liquidity_state_changes, calculate_buy_for_hub_asset_state_changes, calculate_buy_state_changes,
-	calculate_cap_difference, calculate_delta_imbalance, calculate_remove_liquidity_state_changes,
-	calculate_sell_hub_state_changes, calculate_sell_state_changes, calculate_tvl_cap_difference,
-	calculate_withdrawal_fee, verify_asset_cap,
+	calculate_cap_difference, calculate_delta_imbalance, calculate_hub_asset_buyback_state_changes,
+	calculate_remove_liquidity_state_changes, calculate_sell_hub_state_changes, calculate_sell_state_changes,
+	calculate_tvl_cap_difference, calculate_withdrawal_fee, verify_asset_cap,
 };
 use crate::types::Balance;
 use num_traits::{One, Zero};
@@ -881,6 +881,58 @@ fn calculate_tvl_cap_diff_should_work_correctly() {
 	assert_eq!(result, Some(0));
 }
 
+#[test]
+fn calculate_hub_asset_buyback_should_work_when_imbalance_is_not_reached() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let state_changes = calculate_hub_asset_buyback_state_changes(&asset_state, UNIT, 10 * UNIT);
+
+	assert!(state_changes.is_some());
+
+	let state_changes = state_changes.unwrap();
+
+	assert_eq!(state_changes.asset.delta_reserve, BalanceUpdate::Increase(UNIT));
+	assert_eq!(
+		state_changes.asset.delta_hub_reserve,
+		BalanceUpdate::Decrease(1_818_181_818_181)
+	);
+	assert_eq!(
+		state_changes.delta_imbalance,
+		BalanceUpdate::Increase(1_818_181_818_181)
+	);
+}
+
+#[test]
+fn calculate_hub_asset_buyback_should_limit_amount_when_imbalance_is_reached() {
+	let asset_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let state_changes = calculate_hub_asset_buyback_state_changes(&asset_state, UNIT, UNIT);
+
+	assert!(state_changes.is_some());
+
+	let state_changes = state_changes.unwrap();
+
+	assert_eq!(
+		state_changes.asset.delta_reserve,
+		BalanceUpdate::Increase(526_315_789_473)
+	);
+	assert_eq!(
+		state_changes.asset.delta_hub_reserve,
+		BalanceUpdate::Decrease(999_999_999_998)
+	);
+	assert_eq!(state_changes.delta_imbalance, BalanceUpdate::Increase(999_999_999_998));
+}
+
 #[test]
 fn calculate_remove_liquidity_should_apply_correct_fee() {
 	let asset_state = AssetReserveState {
@@ -293,6 +293,31 @@ pub fn calculate_withdraw_one_asset<const D: u8, const Y: u8>(
 	Some((amount_out, fee))
 }
 
+/// Given amount of shares and asset reserves, calculate corresponding amounts of all pool assets to be withdrawn.
+///
+/// Amounts are proportional to the reserves and rounded down. No fee is applied.
+pub fn calculate_liquidity_out(
+	reserves: &[AssetReserve],
+	shares: Balance,
+	share_asset_issuance: Balance,
+) -> Option<Vec<Balance>> {
+	if share_asset_issuance.is_zero() || shares > share_asset_issuance {
+		return None;
+	}
+
+	let (shares_hp, issuance_hp) = to_u256!(shares, share_asset_issuance);
+
+	reserves
+		.iter()
+		.map(|reserve| {
+			let amount = to_u256!(reserve.amount)
+				.checked_mul(shares_hp)?
+				.checked_div(issuance_hp)?;
+			Balance::try_from(amount).ok()
+		})
+		.collect()
+}
+
 /// Calculate amount of an asset that has to be added as liquidity to the pool in exchange of given amount of shares.
 pub fn calculate_add_one_asset<const D: u8, const Y: u8>(
 	reserves: &[AssetReserve],
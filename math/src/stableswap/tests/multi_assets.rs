@@ -805,3 +805,48 @@ fn share_price_calculation_should_work_with_6_decimals() {
 		Some((279206572581786940496760242, 279158579738033226972960348441675415837))
 	);
 }
+
+#[test]
+fn calculate_liquidity_out_should_return_proportional_amounts() {
+	let balances = [
+		AssetReserve::new(1_000_000_000_000_000, 12),
+		AssetReserve::new(2_000_000_000_000_000, 12),
+		AssetReserve::new(3_000_000_000, 6),
+	];
+
+	let issuance: Balance = 6_000_000_000_000_000_000;
+	let shares: Balance = 600_000_000_000_000_000;
+
+	let result = calculate_liquidity_out(&balances, shares, issuance);
+
+	assert_eq!(
+		result,
+		Some(vec![100_000_000_000_000, 200_000_000_000_000, 300_000_000])
+	);
+}
+
+#[test]
+fn calculate_liquidity_out_should_round_down() {
+	let balances = [AssetReserve::new(10, 12), AssetReserve::new(11, 12)];
+
+	let result = calculate_liquidity_out(&balances, 1, 3);
+
+	assert_eq!(result, Some(vec![3, 3]));
+}
+
+#[test]
+fn calculate_liquidity_out_should_return_all_reserves_when_all_shares_are_withdrawn() {
+	let balances = [AssetReserve::new(1_234_567, 12), AssetReserve::new(7_654_321, 18)];
+
+	let result = calculate_liquidity_out(&balances, 1_000, 1_000);
+
+	assert_eq!(result, Some(vec![1_234_567, 7_654_321]));
+}
+
+#[test]
+fn calculate_liquidity_out_should_fail_when_shares_exceed_issuance() {
+	let balances = [AssetReserve::new(1_000, 12), AssetReserve::new(1_000, 12)];
+
+	assert_eq!(calculate_liquidity_out(&balances, 1_001, 1_000), None);
+	assert_eq!(calculate_liquidity_out(&balances, 0, 0), None);
+}
@@ -184,6 +184,10 @@ pub fn parachain_genesis(
 		duster,
 		omnipool_warehouse_lm: Default::default(),
 		omnipool_liquidity_mining: Default::default(),
+		stableswap_warehouse_lm: Default::default(),
+		stableswap_liquidity_mining: Default::default(),
+		xyk_warehouse_lm: Default::default(),
+		xyk_liquidity_mining: Default::default(),
 		evm_chain_id: hydradx_runtime::EVMChainIdConfig {
 			chain_id: 2_222_222u32.into(),
 		},
@@ -28,7 +28,11 @@ pub use fc_rpc::{
 };
 pub use fc_rpc_core::types::{FeeHistoryCache, FeeHistoryCacheLimit, FilterPool};
 use fp_rpc::{ConvertTransaction, ConvertTransactionRuntimeApi, EthereumRuntimeRPCApi};
-use hydradx_runtime::{opaque::Block, AccountId, Balance, Index};
+use hydradx_runtime::{
+	opaque::Block,
+	pallet_ema_oracle::{OracleError, Price},
+	AccountId, AssetId, Balance, BlockNumber, Index,
+};
 use jsonrpsee::RpcModule;
 use sc_client_api::{
 	backend::{Backend, StateBackend, StorageProvider},
@@ -101,9 +105,13 @@ where
 	C: Send + Sync + 'static,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: pallet_route_executor_rpc::RouterRuntimeApi<Block, AssetId, Balance>,
+	C::Api: pallet_ema_oracle_rpc::EmaOracleRuntimeApi<Block, AssetId, Balance, BlockNumber, Price, OracleError>,
 	C::Api: BlockBuilderApi<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use pallet_ema_oracle_rpc::{EmaOracle, EmaOracleApiServer};
+	use pallet_route_executor_rpc::{Router, RouterApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -115,7 +123,9 @@ where
 	} = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Router::new(client.clone()).into_rpc())?;
+	module.merge(EmaOracle::new(client).into_rpc())?;
 
 	Ok(module)
 }
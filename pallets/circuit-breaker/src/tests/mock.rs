@@ -189,6 +189,7 @@ impl orml_tokens::Config for Test {
 parameter_types! {
 	pub const HDXAssetId: AssetId = HDX;
 	pub const LRNAAssetId: AssetId = LRNA;
+	pub const TreasuryAccount: AccountId = 100;
 	pub const PosiitionCollectionId: u32= 1000;
 
 	pub ProtocolFee: Permill = PROTOCOL_FEE.with(|v| *v.borrow());
@@ -209,6 +210,8 @@ impl pallet_omnipool::Config for Test {
 	type Currency = Tokens;
 	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type HubAssetId = LRNAAssetId;
+	type ImbalanceBuybackAccount = TreasuryAccount;
+	type ImbalanceBuybackAssetId = HDXAssetId;
 	type WeightInfo = ();
 	type HdxAssetId = HDXAssetId;
 	type NFTCollectionId = PosiitionCollectionId;
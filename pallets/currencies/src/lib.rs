@@ -35,6 +35,18 @@
 //! `Config::NativeCurrency`.
 //! - `update_balance` - Update balance by signed integer amount, in a given
 //!   currency, root origin required.
+//!
+//! ### Allowances
+//!
+//! The module also keeps ERC-20 style allowances, used by the EVM precompile
+//! exposing assets as ERC-20 tokens.
+//!
+//! - `approve` - Set the amount of a currency which a spender can transfer on
+//!   behalf of the owner.
+//! - `allowance` - Get the amount of a currency which a spender can transfer on
+//!   behalf of the owner.
+//! - `transfer_from` - Transfer some balance on behalf of the owner, spending
+//!   the allowance.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
@@ -58,7 +70,7 @@ use orml_traits::{
 };
 use orml_utilities::with_transaction_result;
 use sp_runtime::{
-	traits::{CheckedSub, MaybeSerializeDeserialize, StaticLookup, Zero},
+	traits::{Bounded, CheckedSub, MaybeSerializeDeserialize, StaticLookup, Zero},
 	DispatchError, DispatchResult,
 };
 use sp_std::{fmt::Debug, marker, result};
@@ -116,6 +128,8 @@ pub mod module {
 		BalanceTooLow,
 		/// Deposit result is not expected
 		DepositFailed,
+		/// Allowance of the spender is too low.
+		InsufficientAllowance,
 	}
 
 	#[pallet::event]
@@ -146,8 +160,29 @@ pub mod module {
 			who: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// Allowance has been set.
+		Approved {
+			currency_id: CurrencyIdOf<T>,
+			owner: T::AccountId,
+			spender: T::AccountId,
+			amount: BalanceOf<T>,
+		},
 	}
 
+	/// Amount of a currency which a spender can transfer on behalf of the owner.
+	#[pallet::storage]
+	#[pallet::getter(fn allowance)]
+	pub type Allowances<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, CurrencyIdOf<T>>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
@@ -217,6 +252,59 @@ pub mod module {
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Set the amount of `currency_id` which `spender` can transfer on behalf of `owner`.
+	pub fn approve(
+		currency_id: CurrencyIdOf<T>,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			Allowances::<T>::remove((currency_id, owner, spender));
+		} else {
+			Allowances::<T>::insert((currency_id, owner, spender), amount);
+		}
+
+		Self::deposit_event(Event::Approved {
+			currency_id,
+			owner: owner.clone(),
+			spender: spender.clone(),
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Transfer `amount` of `currency_id` from `from` to `to` on behalf of `spender`.
+	///
+	/// The allowance of the spender is decreased by `amount`, unless it is set to the max value.
+	pub fn transfer_from(
+		currency_id: CurrencyIdOf<T>,
+		spender: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		with_transaction_result(|| {
+			if spender != from {
+				let allowance = Self::allowance((currency_id, from, spender));
+				if allowance != BalanceOf::<T>::max_value() {
+					let remaining = allowance
+						.checked_sub(&amount)
+						.ok_or(Error::<T>::InsufficientAllowance)?;
+					if remaining.is_zero() {
+						Allowances::<T>::remove((currency_id, from, spender));
+					} else {
+						Allowances::<T>::insert((currency_id, from, spender), remaining);
+					}
+				}
+			}
+
+			<Self as MultiCurrency<T::AccountId>>::transfer(currency_id, from, to, amount)
+		})
+	}
+}
+
 impl<T: Config> MultiCurrency<T::AccountId> for Pallet<T> {
 	type CurrencyId = CurrencyIdOf<T>;
 	type Balance = BalanceOf<T>;
@@ -394,3 +394,85 @@ fn call_event_should_work() {
 			}));
 		});
 }
+
+#[test]
+fn approve_should_set_allowance() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::approve(X_TOKEN_ID, &ALICE, &BOB, 50));
+			assert_eq!(Currencies::allowance((X_TOKEN_ID, ALICE, BOB)), 50);
+			assert_eq!(Currencies::allowance((NATIVE_CURRENCY_ID, ALICE, BOB)), 0);
+			System::assert_last_event(RuntimeEvent::Currencies(crate::Event::Approved {
+				currency_id: X_TOKEN_ID,
+				owner: ALICE,
+				spender: BOB,
+				amount: 50,
+			}));
+
+			assert_ok!(Currencies::approve(X_TOKEN_ID, &ALICE, &BOB, 0));
+			assert!(!Allowances::<Runtime>::contains_key((X_TOKEN_ID, ALICE, BOB)));
+		});
+}
+
+#[test]
+fn transfer_from_should_spend_allowance() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::approve(X_TOKEN_ID, &ALICE, &BOB, 50));
+
+			assert_ok!(Currencies::transfer_from(X_TOKEN_ID, &BOB, &ALICE, &EVA, 30));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &ALICE), 70);
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &EVA), 30);
+			assert_eq!(Currencies::allowance((X_TOKEN_ID, ALICE, BOB)), 20);
+
+			assert_noop!(
+				Currencies::transfer_from(X_TOKEN_ID, &BOB, &ALICE, &EVA, 30),
+				Error::<Runtime>::InsufficientAllowance
+			);
+		});
+}
+
+#[test]
+fn transfer_from_should_remove_allowance_when_it_is_spent() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::approve(X_TOKEN_ID, &ALICE, &BOB, 50));
+
+			assert_ok!(Currencies::transfer_from(X_TOKEN_ID, &BOB, &ALICE, &EVA, 50));
+			assert_eq!(Currencies::free_balance(X_TOKEN_ID, &EVA), 50);
+			assert!(!Allowances::<Runtime>::contains_key((X_TOKEN_ID, ALICE, BOB)));
+		});
+}
+
+#[test]
+fn transfer_from_should_not_spend_max_allowance() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::approve(NATIVE_CURRENCY_ID, &ALICE, &BOB, u64::MAX));
+
+			assert_ok!(Currencies::transfer_from(NATIVE_CURRENCY_ID, &BOB, &ALICE, &EVA, 30));
+			assert_eq!(NativeCurrency::free_balance(&EVA), 30);
+			assert_eq!(Currencies::allowance((NATIVE_CURRENCY_ID, ALICE, BOB)), u64::MAX);
+		});
+}
+
+#[test]
+fn transfer_from_should_keep_allowance_when_transfer_fails() {
+	ExtBuilder::default()
+		.one_hundred_for_alice_n_bob()
+		.build()
+		.execute_with(|| {
+			assert_ok!(Currencies::approve(X_TOKEN_ID, &EVA, &BOB, 50));
+
+			assert!(Currencies::transfer_from(X_TOKEN_ID, &BOB, &EVA, &ALICE, 30).is_err());
+			assert_eq!(Currencies::allowance((X_TOKEN_ID, EVA, BOB)), 50);
+		});
+}
@@ -0,0 +1,41 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for querying DCA schedules.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query DCA schedules and their execution history.
+	pub trait DcaApi<AccountId, ScheduleId, Schedule, ScheduleHistory> where
+		AccountId: Codec,
+		ScheduleId: Codec,
+		Schedule: Codec,
+		ScheduleHistory: Codec,
+	{
+		/// Returns the execution summary of a schedule.
+		///
+		/// The summary of a completed or terminated schedule is available only during the retention period.
+		fn schedule_history(schedule_id: ScheduleId) -> Option<ScheduleHistory>;
+
+		/// Returns the schedules of `owner` which are not completed or terminated yet.
+		fn active_schedules(owner: AccountId) -> Vec<(ScheduleId, Schedule)>;
+	}
+}
@@ -40,6 +40,11 @@
 //! For both successful and failed trades, a fee is deducted from the schedule owner.
 //! The fee is deducted in the sold (`amount_in`) currency.
 //!
+//! The owner can choose to pay the fee out of the bought currency of successful trades instead.
+//! The fee can also be pre-funded in native currency by a sponsor account approved by the owner, in which
+//! case it is paid by the sponsor as long as the sponsorship covers it. The unspent sponsorship is returned
+//! to the sponsor when the schedule is completed or terminated, or when the owner approves another sponsor.
+//!
 //! A trade can fail due to two main reasons:
 //!
 //! 1. Price Stability Error: If the price difference between the short oracle price and the current price
@@ -55,11 +60,44 @@
 //!
 //! If a trade fails due to other types of errors, the order is terminated without any retry logic.
 //!
+//! ## Limit orders
+//!
+//! Schedules with `LimitSell` and `LimitBuy` orders are executed only when the last block oracle price
+//! of `asset_out` denominated in `asset_in` is lower than or equal to the specified `limit_price`.
+//! The trade limits of such orders are derived from the limit price.
+//!
+//! If the price condition is not met in the planned block, the trade is not executed and the schedule
+//! is replanned for the next period. The fee is deducted for every check of the price condition.
+//!
+//! A limit order can have an optional expiry block, after which the schedule is terminated.
+//!
+//! ## Portfolio orders
+//!
+//! A `Portfolio` order splits the amount sold in each execution among several sell orders
+//! according to their weights, which must sum up to 100%. All orders are executed in the same block
+//! and the result of each of them is emitted in a `PortfolioTradeExecuted` event.
+//!
+//! If any of the orders fails, none of them is executed and the execution is handled as a failed trade.
+//! The schedule is completed when the share of any order in the next execution would be below `MinimumTradingLimit`.
+//!
+//! ## Modifying a Schedule
+//!
+//! The owner of a schedule can pause it, in which case it is removed from its planned execution block
+//! while its budget stays reserved. A paused schedule can be resumed starting from a specified block.
+//!
+//! The period, slippage, stability threshold and max retries of an existing schedule can be updated,
+//! and its budget can be topped up without re-creating the schedule.
+//!
 //! ## Terminating a Schedule
 //!
 //! Both users and technical origin can terminate a DCA schedule. However, users can only terminate schedules that they own.
 //!
-//! Once a schedule is terminated, it is completely and permanently removed from the blockchain.
+//! Once a schedule is terminated, it is permanently removed from the blockchain, except for its execution history.
+//!
+//! ## Execution history
+//!
+//! A summary of the executions of each schedule is stored in `ScheduleHistories`. It is kept for
+//! `HistoryRetentionPeriod` blocks after the schedule is completed or terminated.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -82,7 +120,7 @@ use orml_traits::{arithmetic::CheckedAdd, MultiCurrency, NamedMultiReservableCur
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
-use sp_runtime::traits::{CheckedMul, One};
+use sp_runtime::traits::{CheckedMul, One, Zero};
 use sp_runtime::{
 	traits::{BlockNumberProvider, Saturating},
 	ArithmeticError, BoundedVec, DispatchError, FixedPointNumber, FixedU128, Permill, Rounding,
@@ -130,6 +168,8 @@ pub mod pallet {
 		fn on_initialize(current_blocknumber: T::BlockNumber) -> Weight {
 			let mut weight = <T as pallet::Config>::WeightInfo::on_initialize_with_empty_block();
 
+			weight.saturating_accrue(Self::remove_expired_histories(current_blocknumber));
+
 			let mut randomness_generator = Self::get_randomness_generator(current_blocknumber, None);
 
 			let mut schedule_ids: Vec<ScheduleId> = ScheduleIdsPerBlock::<T>::take(current_blocknumber).to_vec();
@@ -146,23 +186,31 @@ pub mod pallet {
 					continue;
 				};
 
+				if Self::is_expired(&schedule, current_blocknumber) {
+					Self::terminate_schedule(schedule_id, &schedule, Error::<T>::ScheduleExpired.into());
+					continue;
+				}
+
 				let weight_for_single_execution = Self::get_trade_weight(&schedule.order);
 				weight.saturating_accrue(weight_for_single_execution);
 
-				if let Err(e) = Self::prepare_schedule(
+				let deferred_fee_weight = match Self::prepare_schedule(
 					current_blocknumber,
 					weight_for_single_execution,
 					schedule_id,
 					&schedule,
 					&mut randomness_generator,
 				) {
-					if e != Error::<T>::PriceUnstable.into() {
-						Self::terminate_schedule(schedule_id, &schedule, e);
-					};
-					continue;
+					Ok(deferred_fee_weight) => deferred_fee_weight,
+					Err(e) => {
+						if e != Error::<T>::PriceUnstable.into() && e != Error::<T>::LimitPriceNotReached.into() {
+							Self::terminate_schedule(schedule_id, &schedule, e);
+						};
+						continue;
+					}
 				};
 
-				match Self::execute_trade(schedule_id, &schedule) {
+				match Self::execute_trade(schedule_id, &schedule, deferred_fee_weight) {
 					Ok(amounts) => {
 						if let Err(err) = Self::replan_or_complete(
 							schedule_id,
@@ -180,14 +228,29 @@ pub mod pallet {
 							who: schedule.owner.clone(),
 							error,
 						});
+						Self::record_error(schedule_id, error);
 
 						if error != Error::<T>::TradeLimitReached.into()
 							&& error != Error::<T>::SlippageLimitReached.into()
 						{
+							// Deferred fee could not be taken from the bought amount, so it is taken from the
+							// remaining budget before it is unreserved. Termination proceeds even if it fails.
+							let _ = Self::take_deferred_transaction_fee_from_user(
+								schedule_id,
+								&schedule,
+								deferred_fee_weight,
+							);
 							Self::terminate_schedule(schedule_id, &schedule, error);
 						} else if let Err(retry_error) =
-							Self::retry_schedule(schedule_id, &schedule, current_blocknumber, &mut randomness_generator)
-						{
+							Self::take_deferred_transaction_fee_from_user(schedule_id, &schedule, deferred_fee_weight)
+								.and_then(|_| {
+									Self::retry_schedule(
+										schedule_id,
+										&schedule,
+										current_blocknumber,
+										&mut randomness_generator,
+									)
+								}) {
 							Self::terminate_schedule(schedule_id, &schedule, retry_error);
 						}
 					}
@@ -273,6 +336,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type NamedReserveId: Get<NamedReserveIdentifier>;
 
+		/// The number of blocks the execution history of a schedule is kept after its completion or termination
+		#[pallet::constant]
+		type HistoryRetentionPeriod: Get<Self::BlockNumber>;
+
 		/// Convert a weight value into a deductible fee
 		type WeightToFee: WeightToFee<Balance = Balance>;
 
@@ -294,7 +361,7 @@ pub mod pallet {
 			who: T::AccountId,
 			period: BlockNumberFor<T>,
 			total_amount: Balance,
-			order: Order<T::AssetId>,
+			order: Order<T::AssetId, BlockNumberFor<T>>,
 		},
 		///The DCA is planned for blocknumber
 		ExecutionPlanned {
@@ -309,20 +376,64 @@ pub mod pallet {
 			amount_in: Balance,
 			amount_out: Balance,
 		},
+		///The order of a DCA portfolio is successfully executed
+		PortfolioTradeExecuted {
+			id: ScheduleId,
+			who: T::AccountId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+		},
 		///The DCA trade execution is failed
 		TradeFailed {
 			id: ScheduleId,
 			who: T::AccountId,
 			error: DispatchError,
 		},
-		///The DCA is terminated and completely removed from the chain
+		///The DCA is terminated and removed from the chain, its execution history is kept for `HistoryRetentionPeriod`
 		Terminated {
 			id: ScheduleId,
 			who: T::AccountId,
 			error: DispatchError,
 		},
-		///The DCA is completed and completely removed from the chain
+		///The DCA is completed and removed from the chain, its execution history is kept for `HistoryRetentionPeriod`
 		Completed { id: ScheduleId, who: T::AccountId },
+		///The DCA is paused and removed from its planned execution block
+		Paused { id: ScheduleId, who: T::AccountId },
+		///The paused DCA is resumed
+		Resumed { id: ScheduleId, who: T::AccountId },
+		///The execution parameters of the DCA are updated
+		ScheduleUpdated { id: ScheduleId, who: T::AccountId },
+		///The budget of the DCA is increased
+		ToppedUp {
+			id: ScheduleId,
+			who: T::AccountId,
+			amount: Balance,
+		},
+		///The currency in which the owner pays the execution fees is set
+		FeeCurrencySet {
+			id: ScheduleId,
+			who: T::AccountId,
+			fee_currency: FeeCurrency,
+		},
+		///The sponsor of the DCA execution fees is approved or the approval is revoked by the owner
+		SponsorApproved {
+			id: ScheduleId,
+			who: T::AccountId,
+			sponsor: Option<T::AccountId>,
+		},
+		///The execution fees of the DCA are funded by a sponsor
+		FeesSponsored {
+			id: ScheduleId,
+			sponsor: T::AccountId,
+			amount: Balance,
+		},
+		///The unspent sponsored fees are returned to the sponsor
+		SponsorshipRefunded {
+			id: ScheduleId,
+			sponsor: T::AccountId,
+			amount: Balance,
+		},
 		///Randomness generation failed possibly coming from missing data about relay chain
 		RandomnessGenerationFailed {
 			block: BlockNumberFor<T>,
@@ -362,6 +473,30 @@ pub mod pallet {
 		NoParentHashFound,
 		///Error that should not really happen only in case of invalid state of the schedule storage entries
 		InvalidState,
+		///The limit price of the order is not reached, leading to replanning for the next period
+		LimitPriceNotReached,
+		///The limit price of the order is zero
+		InvalidLimitPrice,
+		///The expiry block of the limit order has passed
+		ScheduleExpired,
+		///The schedule is already paused
+		ScheduleAlreadyPaused,
+		///The schedule is not paused
+		ScheduleNotPaused,
+		///The period of the schedule is zero
+		InvalidPeriod,
+		///The amount is zero
+		InvalidAmount,
+		///The portfolio is empty, its weights do not sum up to 100% or its orders are not unique
+		InvalidPortfolio,
+		///The fee of portfolio orders can not be paid in the bought assets
+		FeeCurrencyNotSupported,
+		///The execution fee is bigger than the amount bought by the trade
+		FeeExceedsBoughtAmount,
+		///The sponsor is not approved by the schedule owner
+		SponsorNotApproved,
+		///The schedule is not sponsored
+		SponsorshipNotFound,
 	}
 
 	/// Id sequencer for schedules
@@ -391,12 +526,44 @@ pub mod pallet {
 	#[pallet::getter(fn retries_on_error)]
 	pub type RetriesOnError<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, u8, ValueQuery>;
 
+	/// Keep tracking of the paused schedules
+	#[pallet::storage]
+	#[pallet::getter(fn paused_schedules)]
+	pub type PausedSchedules<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, (), OptionQuery>;
+
 	/// Keep tracking of the schedule ids to be executed in the block
 	#[pallet::storage]
 	#[pallet::getter(fn schedule_ids_per_block)]
 	pub type ScheduleIdsPerBlock<T: Config> =
 		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, BoundedVec<ScheduleId, T::MaxSchedulePerBlock>, ValueQuery>;
 
+	/// Summary of the executions of DCA schedules
+	#[pallet::storage]
+	#[pallet::getter(fn schedule_history)]
+	pub type ScheduleHistories<T: Config> =
+		StorageMap<_, Blake2_128Concat, ScheduleId, ScheduleHistory<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Keep tracking of the schedule histories to be removed in the block
+	#[pallet::storage]
+	pub type HistoryExpirations<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Twox64Concat, ScheduleId, (), OptionQuery>;
+
+	/// Keep tracking of the schedules paying the execution fee in the bought asset
+	#[pallet::storage]
+	#[pallet::getter(fn fee_currency)]
+	pub type FeeCurrencies<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, FeeCurrency, ValueQuery>;
+
+	/// Sponsors approved by the schedule owners to fund the execution fees
+	#[pallet::storage]
+	#[pallet::getter(fn approved_sponsor)]
+	pub type ApprovedSponsors<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, T::AccountId, OptionQuery>;
+
+	/// Execution fees of schedules funded by sponsors
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorship)]
+	pub type Sponsorships<T: Config> =
+		StorageMap<_, Blake2_128Concat, ScheduleId, Sponsorship<T::AccountId>, OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates a new DCA (Dollar-Cost Averaging) schedule and plans the next execution
@@ -424,8 +591,9 @@ pub mod pallet {
 		/// Emits `Scheduled` and `ExecutionPlanned` event when successful.
 		///
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::schedule()
-			+ <T as Config>::AmmTradeWeights::calculate_buy_trade_amounts_weight(&schedule.order.get_route_or_default::<T::RouteProvider>()))]
+		#[pallet::weight(schedule.order.get_routes_or_default::<T::RouteProvider>().iter()
+			.fold(<T as Config>::WeightInfo::schedule(), |weight, route| weight
+				.saturating_add(<T as Config>::AmmTradeWeights::calculate_buy_trade_amounts_weight(route))))]
 		#[transactional]
 		pub fn schedule(
 			origin: OriginFor<T>,
@@ -444,12 +612,33 @@ pub mod pallet {
 				Error::<T>::TotalAmountIsSmallerThanMinBudget
 			);
 
+			if let Some((limit_price, expires_at)) = schedule.order.get_limit() {
+				ensure!(!limit_price.is_zero(), Error::<T>::InvalidLimitPrice);
+				if let Some(expires_at) = expires_at {
+					ensure!(
+						expires_at > frame_system::Pallet::<T>::current_block_number(),
+						Error::<T>::BlockNumberIsNotInFuture
+					);
+				}
+			}
+
+			if let Order::Portfolio {
+				asset_in,
+				amount_in,
+				orders,
+			} = &schedule.order
+			{
+				Self::validate_portfolio(*asset_in, *amount_in, orders)?;
+			}
+
 			let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
 
 			let amount_in = match schedule.order {
-				Order::Sell { amount_in, .. } => amount_in,
-				Order::Buy { amount_out, .. } => {
-					let route = schedule.order.get_route_or_default::<T::RouteProvider>();
+				Order::Sell { amount_in, .. }
+				| Order::LimitSell { amount_in, .. }
+				| Order::Portfolio { amount_in, .. } => amount_in,
+				Order::Buy { amount_out, .. } | Order::LimitBuy { amount_out, .. } => {
+					let route = Self::get_route_or_default(&schedule.order);
 					Self::get_amount_in_for_buy(&amount_out, &route)?
 				}
 			};
@@ -542,37 +731,364 @@ pub mod pallet {
 
 			Self::try_unreserve_all(schedule_id, &schedule);
 
+			//Paused schedules are not planned for any block
+			if !PausedSchedules::<T>::contains_key(schedule_id) {
+				let next_execution_block = Self::get_next_execution_block(next_execution_block)?;
+				Self::remove_schedule_id_from_block(schedule_id, next_execution_block)?;
+			}
+
+			Self::record_error(schedule_id, Error::<T>::ManuallyTerminated.into());
+			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
+
+			Self::deposit_event(Event::Terminated {
+				id: schedule_id,
+				who: schedule.owner,
+				error: Error::<T>::ManuallyTerminated.into(),
+			});
+
+			Ok(())
+		}
+
+		/// Pauses a DCA schedule by removing it from its planned execution block.
+		///
+		/// The reserved budget and the schedule id are kept, so the schedule can be resumed later.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `next_execution_block`: block number where the schedule is planned.
+		///
+		/// Emits `Paused` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::pause())]
+		#[transactional]
+		pub fn pause(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			next_execution_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(
+				!PausedSchedules::<T>::contains_key(schedule_id),
+				Error::<T>::ScheduleAlreadyPaused
+			);
+
 			let next_execution_block = Self::get_next_execution_block(next_execution_block)?;
+			Self::remove_schedule_id_from_block(schedule_id, next_execution_block)?;
 
-			//Remove schedule id from next execution block
-			ScheduleIdsPerBlock::<T>::try_mutate_exists(
-				next_execution_block,
-				|maybe_schedule_ids| -> DispatchResult {
-					let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+			PausedSchedules::<T>::insert(schedule_id, ());
 
-					let index = schedule_ids
-						.binary_search(&schedule_id)
-						.map_err(|_| Error::<T>::ScheduleNotFound)?;
+			Self::deposit_event(Event::Paused { id: schedule_id, who });
 
-					schedule_ids.remove(index);
+			Ok(())
+		}
 
-					if schedule_ids.is_empty() {
-						*maybe_schedule_ids = None;
-					}
+		/// Resumes a paused DCA schedule and plans its next execution for the specified block.
+		///
+		/// If the block is not specified, the execution is planned for the next block.
+		/// If the given block is full, the execution will be planned in the subsequent block.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `start_execution_block`: block number where the execution of the schedule is resumed
+		///
+		/// Emits `Resumed` and `ExecutionPlanned` events when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume())]
+		#[transactional]
+		pub fn resume(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			start_execution_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(
+				PausedSchedules::<T>::take(schedule_id).is_some(),
+				Error::<T>::ScheduleNotPaused
+			);
+
+			let next_execution_block = Self::get_next_execution_block(start_execution_block)?;
+			let mut randomness_generator =
+				Self::get_randomness_generator(frame_system::Pallet::<T>::current_block_number(), Some(schedule_id));
+
+			Self::deposit_event(Event::Resumed {
+				id: schedule_id,
+				who: who.clone(),
+			});
+
+			Self::plan_schedule_for_block(&who, next_execution_block, schedule_id, &mut randomness_generator)?;
+
+			Ok(())
+		}
+
+		/// Updates the execution parameters of a DCA schedule.
+		///
+		/// Only the specified parameters are updated, the others are left unchanged.
+		/// The new period is applied when the schedule is planned after its next execution.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `period`: new time period (in blocks) between two schedule executions
+		/// - `slippage`: new slippage limit
+		/// - `stability_threshold`: new price stability threshold
+		/// - `max_retries`: new maximum number of retries
+		///
+		/// Emits `ScheduleUpdated` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_schedule())]
+		#[transactional]
+		pub fn update_schedule(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			period: Option<BlockNumberFor<T>>,
+			slippage: Option<Permill>,
+			stability_threshold: Option<Permill>,
+			max_retries: Option<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Schedules::<T>::try_mutate(schedule_id, |maybe_schedule| -> DispatchResult {
+				let schedule = maybe_schedule.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+				ensure!(who == schedule.owner, Error::<T>::Forbidden);
+
+				if let Some(period) = period {
+					ensure!(!period.is_zero(), Error::<T>::InvalidPeriod);
+					schedule.period = period;
+				}
+				if slippage.is_some() {
+					schedule.slippage = slippage;
+				}
+				if stability_threshold.is_some() {
+					schedule.stability_threshold = stability_threshold;
+				}
+				if max_retries.is_some() {
+					schedule.max_retries = max_retries;
+				}
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ScheduleUpdated { id: schedule_id, who });
+
+			Ok(())
+		}
+
+		/// Increases the remaining budget of a DCA schedule.
+		///
+		/// The specified `amount` is reserved in the `amount_in` currency of the order.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `amount`: amount to add to the budget of the schedule
+		///
+		/// Emits `ToppedUp` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::top_up())]
+		#[transactional]
+		pub fn top_up(origin: OriginFor<T>, schedule_id: ScheduleId, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+
+			Schedules::<T>::try_mutate(schedule_id, |maybe_schedule| -> DispatchResult {
+				let schedule = maybe_schedule.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+				ensure!(who == schedule.owner, Error::<T>::Forbidden);
+
+				schedule.total_amount = schedule
+					.total_amount
+					.checked_add(amount)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				RemainingAmounts::<T>::try_mutate(schedule_id, |maybe_remaining_amount| -> DispatchResult {
+					let remaining_amount = maybe_remaining_amount
+						.as_mut()
+						.defensive_ok_or(Error::<T>::InvalidState)?;
+					*remaining_amount = remaining_amount.checked_add(amount).ok_or(ArithmeticError::Overflow)?;
 					Ok(())
-				},
-			)?;
+				})?;
 
-			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
+				T::Currencies::reserve_named(&T::NamedReserveId::get(), schedule.order.get_asset_in(), &who, amount)
+			})?;
 
-			Self::deposit_event(Event::Terminated {
+			Self::deposit_event(Event::ToppedUp {
 				id: schedule_id,
-				who: schedule.owner,
-				error: Error::<T>::ManuallyTerminated.into(),
+				who,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the currency in which the owner pays the execution fees of a DCA schedule.
+		///
+		/// When the bought asset is set, the fee is taken from the amount bought by each trade.
+		/// Executions without trade are still charged from the reserved budget.
+		/// The native price of the bought asset must be available.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `fee_currency`: the currency to pay the fees in
+		///
+		/// Emits `FeeCurrencySet` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_currency())]
+		#[transactional]
+		pub fn set_fee_currency(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			fee_currency: FeeCurrency,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+
+			match fee_currency {
+				FeeCurrency::SoldAsset => FeeCurrencies::<T>::remove(schedule_id),
+				FeeCurrency::BoughtAsset => {
+					let asset_out = schedule
+						.order
+						.get_asset_out()
+						.ok_or(Error::<T>::FeeCurrencyNotSupported)?;
+					Self::convert_weight_to_fee(Self::get_trade_weight(&schedule.order), asset_out)?;
+					FeeCurrencies::<T>::insert(schedule_id, fee_currency);
+				}
+			}
+
+			Self::deposit_event(Event::FeeCurrencySet {
+				id: schedule_id,
+				who,
+				fee_currency,
+			});
+
+			Ok(())
+		}
+
+		/// Approves the account which can fund the execution fees of a DCA schedule.
+		///
+		/// If the schedule is funded by a previously approved sponsor, the unspent amount is returned to it.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `sponsor`: the account to approve, or `None` to revoke the approval
+		///
+		/// Emits `SponsorApproved` event when successful.
+		///
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_sponsor())]
+		#[transactional]
+		pub fn approve_sponsor(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			sponsor: Option<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+
+			let is_sponsored_by_other = Sponsorships::<T>::get(schedule_id)
+				.map_or(false, |sponsorship| Some(&sponsorship.sponsor) != sponsor.as_ref());
+			if is_sponsored_by_other {
+				Self::refund_sponsorship(schedule_id);
+			}
+
+			ApprovedSponsors::<T>::mutate_exists(schedule_id, |approved_sponsor| *approved_sponsor = sponsor.clone());
+
+			Self::deposit_event(Event::SponsorApproved {
+				id: schedule_id,
+				who,
+				sponsor,
+			});
+
+			Ok(())
+		}
+
+		/// Funds the execution fees of a DCA schedule owned by another account.
+		///
+		/// The sponsor has to be approved by the schedule owner via `approve_sponsor`.
+		/// The specified `amount` is reserved in native currency from the sponsor. While the sponsorship
+		/// covers the fee, it is paid by the sponsor instead of the owner. The unspent amount is returned
+		/// to the sponsor when the schedule is completed or terminated.
+		///
+		/// Parameters:
+		/// - `origin`: sponsor
+		/// - `schedule_id`: schedule id
+		/// - `amount`: amount in native currency to add to the sponsorship
+		///
+		/// Emits `FeesSponsored` event when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::sponsor_fees())]
+		#[transactional]
+		pub fn sponsor_fees(origin: OriginFor<T>, schedule_id: ScheduleId, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!amount.is_zero(), Error::<T>::InvalidAmount);
+			ensure!(Schedules::<T>::contains_key(schedule_id), Error::<T>::ScheduleNotFound);
+			ensure!(
+				ApprovedSponsors::<T>::get(schedule_id).as_ref() == Some(&who),
+				Error::<T>::SponsorNotApproved
+			);
+
+			Sponsorships::<T>::try_mutate(schedule_id, |maybe_sponsorship| -> DispatchResult {
+				let sponsorship = maybe_sponsorship.get_or_insert_with(|| Sponsorship {
+					sponsor: who.clone(),
+					remaining: Balance::zero(),
+				});
+				//NOTE: sponsorship of previously approved sponsor is refunded when another sponsor is approved
+				ensure!(sponsorship.sponsor == who, Error::<T>::InvalidState);
+
+				sponsorship.remaining = sponsorship
+					.remaining
+					.checked_add(amount)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				T::Currencies::reserve_named(&T::NamedReserveId::get(), T::NativeAssetId::get(), &who, amount)
+			})?;
+
+			Self::deposit_event(Event::FeesSponsored {
+				id: schedule_id,
+				sponsor: who,
+				amount,
 			});
 
 			Ok(())
 		}
+
+		/// Stops sponsoring the execution fees of a DCA schedule and returns the unspent amount to the sponsor.
+		///
+		/// Parameters:
+		/// - `origin`: sponsor
+		/// - `schedule_id`: schedule id
+		///
+		/// Emits `SponsorshipRefunded` event when successful.
+		///
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_sponsorship())]
+		#[transactional]
+		pub fn withdraw_sponsorship(origin: OriginFor<T>, schedule_id: ScheduleId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let sponsorship = Sponsorships::<T>::get(schedule_id).ok_or(Error::<T>::SponsorshipNotFound)?;
+			ensure!(sponsorship.sponsor == who, Error::<T>::Forbidden);
+
+			Self::refund_sponsorship(schedule_id);
+
+			Ok(())
+		}
 	}
 }
 
@@ -614,114 +1130,250 @@ impl<T: Config> Pallet<T> {
 		schedule_id: ScheduleId,
 		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
 		randomness_generator: &mut StdRng,
-	) -> DispatchResult {
-		Self::take_transaction_fee_from_user(schedule_id, schedule, weight_for_dca_execution)?;
+	) -> Result<Option<Weight>, DispatchError> {
+		let deferred_fee_weight = Self::take_transaction_fee(schedule_id, schedule, weight_for_dca_execution)?;
 
 		if Self::is_price_unstable(schedule) {
+			Self::take_deferred_transaction_fee_from_user(schedule_id, schedule, deferred_fee_weight)?;
 			Self::deposit_event(Event::TradeFailed {
 				id: schedule_id,
 				who: schedule.owner.clone(),
 				error: Error::<T>::PriceUnstable.into(),
 			});
+			Self::record_error(schedule_id, Error::<T>::PriceUnstable.into());
 			Self::retry_schedule(schedule_id, schedule, current_blocknumber, randomness_generator)?;
 
 			return Err(Error::<T>::PriceUnstable.into());
 		}
 
-		Ok(())
+		if !Self::is_limit_price_reached(schedule)? {
+			Self::take_deferred_transaction_fee_from_user(schedule_id, schedule, deferred_fee_weight)?;
+			let next_execution_block = current_blocknumber
+				.checked_add(&schedule.period)
+				.ok_or(ArithmeticError::Overflow)?;
+			Self::plan_schedule_for_block(&schedule.owner, next_execution_block, schedule_id, randomness_generator)?;
+
+			return Err(Error::<T>::LimitPriceNotReached.into());
+		}
+
+		Ok(deferred_fee_weight)
 	}
 
 	#[transactional]
 	pub fn execute_trade(
 		schedule_id: ScheduleId,
 		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+		deferred_fee_weight: Option<Weight>,
 	) -> Result<AmountInAndOut<Balance>, DispatchError> {
-		let origin: OriginFor<T> = Origin::<T>::Signed(schedule.owner.clone()).into();
-
-		match &schedule.order {
+		let amounts = match &schedule.order {
 			Order::Sell {
 				asset_in,
 				asset_out,
 				amount_in,
 				min_amount_out,
 				..
-			} => {
-				let route = &schedule.order.get_route_or_default::<T::RouteProvider>();
-				let remaining_amount =
-					RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
-				let amount_to_sell = min(remaining_amount, *amount_in);
-
-				Self::unallocate_amount(schedule_id, schedule, amount_to_sell)?;
-
-				let route_for_slippage = inverse_route(route.to_vec());
-				let (estimated_amount_out, slippage_amount) =
-					Self::calculate_last_block_slippage(&route_for_slippage, amount_to_sell, schedule.slippage)?;
-				let last_block_slippage_min_limit = estimated_amount_out
-					.checked_sub(slippage_amount)
-					.ok_or(ArithmeticError::Overflow)?;
-
-				let trade_amounts = T::RouteExecutor::calculate_sell_trade_amounts(route, amount_to_sell)?;
-				let last_trade = trade_amounts.last().defensive_ok_or(Error::<T>::InvalidState)?;
-				let amount_out = last_trade.amount_out;
-
-				if *min_amount_out > last_block_slippage_min_limit {
-					ensure!(amount_out >= *min_amount_out, Error::<T>::TradeLimitReached);
-				} else {
-					ensure!(
-						amount_out >= last_block_slippage_min_limit,
-						Error::<T>::SlippageLimitReached
-					);
-				};
-
-				T::RouteExecutor::sell(
-					origin,
-					*asset_in,
-					*asset_out,
-					amount_to_sell,
-					amount_out,
-					route.to_vec(),
-				)?;
-
-				Ok(AmountInAndOut {
-					amount_in: amount_to_sell,
-					amount_out,
-				})
-			}
+			} => Self::execute_sell(
+				schedule_id,
+				schedule,
+				*asset_in,
+				*asset_out,
+				*amount_in,
+				&Self::get_route_or_default(&schedule.order),
+				|_| Ok(*min_amount_out),
+			),
+			Order::LimitSell {
+				asset_in,
+				asset_out,
+				amount_in,
+				limit_price,
+				..
+			} => Self::execute_sell(
+				schedule_id,
+				schedule,
+				*asset_in,
+				*asset_out,
+				*amount_in,
+				&Self::get_route_or_default(&schedule.order),
+				|amount_to_sell| {
+					multiply_by_rational_with_rounding(
+						amount_to_sell,
+						FixedU128::DIV,
+						limit_price.into_inner(),
+						Rounding::Down,
+					)
+					.ok_or_else(|| ArithmeticError::Overflow.into())
+				},
+			),
 			Order::Buy {
 				asset_in,
 				asset_out,
 				amount_out,
 				max_amount_in,
 				..
+			} => Self::execute_buy(
+				schedule_id,
+				schedule,
+				*asset_in,
+				*asset_out,
+				*amount_out,
+				*max_amount_in,
+				&Self::get_route_or_default(&schedule.order),
+			),
+			Order::LimitBuy {
+				asset_in,
+				asset_out,
+				amount_out,
+				limit_price,
+				..
 			} => {
-				let route = schedule.order.get_route_or_default::<T::RouteProvider>();
-				let amount_in = Self::get_amount_in_for_buy(amount_out, &route)?;
+				let max_amount_in = limit_price
+					.checked_mul_int(*amount_out)
+					.ok_or(ArithmeticError::Overflow)?;
+				Self::execute_buy(
+					schedule_id,
+					schedule,
+					*asset_in,
+					*asset_out,
+					*amount_out,
+					max_amount_in,
+					&Self::get_route_or_default(&schedule.order),
+				)
+			}
+			Order::Portfolio {
+				asset_in,
+				amount_in,
+				orders,
+			} => Self::execute_portfolio(schedule_id, schedule, *asset_in, *amount_in, orders),
+		}?;
 
-				Self::unallocate_amount(schedule_id, schedule, amount_in)?;
+		if let Some(weight) = deferred_fee_weight {
+			Self::take_transaction_fee_from_bought_amount(schedule, weight, amounts.amount_out)?;
+		}
 
-				let (estimated_amount_in, slippage_amount) =
-					Self::calculate_last_block_slippage(&route, *amount_out, schedule.slippage)?;
-				let last_block_slippage_max_limit = estimated_amount_in
-					.checked_add(slippage_amount)
-					.ok_or(ArithmeticError::Overflow)?;
+		Ok(amounts)
+	}
 
-				if *max_amount_in < last_block_slippage_max_limit {
-					ensure!(amount_in <= *max_amount_in, Error::<T>::TradeLimitReached);
-				} else {
-					ensure!(
-						amount_in <= last_block_slippage_max_limit,
-						Error::<T>::SlippageLimitReached
-					);
-				};
+	fn execute_portfolio(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+		asset_in: T::AssetId,
+		amount_in: Balance,
+		orders: &[PortfolioOrder<T::AssetId>],
+	) -> Result<AmountInAndOut<Balance>, DispatchError> {
+		let remaining_amount = RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
+		let amount_to_sell = min(remaining_amount, amount_in);
+		let routes = schedule.order.get_routes_or_default::<T::RouteProvider>();
+
+		let mut total_amount_in: Balance = 0;
+		for (order, route) in orders.iter().zip(routes) {
+			let amounts = Self::execute_sell(
+				schedule_id,
+				schedule,
+				asset_in,
+				order.asset_out,
+				order.weight.mul_floor(amount_to_sell),
+				&route,
+				|_| Ok(order.min_amount_out),
+			)?;
 
-				T::RouteExecutor::buy(origin, *asset_in, *asset_out, *amount_out, amount_in, route.to_vec())?;
+			Self::deposit_event(Event::PortfolioTradeExecuted {
+				id: schedule_id,
+				who: schedule.owner.clone(),
+				asset_out: order.asset_out,
+				amount_in: amounts.amount_in,
+				amount_out: amounts.amount_out,
+			});
 
-				Ok(AmountInAndOut {
-					amount_in,
-					amount_out: *amount_out,
-				})
-			}
+			total_amount_in = total_amount_in
+				.checked_add(amounts.amount_in)
+				.ok_or(ArithmeticError::Overflow)?;
 		}
+
+		// The bought amounts are in different assets, so only the sold amount is aggregated
+		Ok(AmountInAndOut {
+			amount_in: total_amount_in,
+			amount_out: Balance::zero(),
+		})
+	}
+
+	fn execute_sell(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		route: &[Trade<T::AssetId>],
+		min_amount_out_for: impl FnOnce(Balance) -> Result<Balance, DispatchError>,
+	) -> Result<AmountInAndOut<Balance>, DispatchError> {
+		let origin: OriginFor<T> = Origin::<T>::Signed(schedule.owner.clone()).into();
+
+		let remaining_amount = RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
+		let amount_to_sell = min(remaining_amount, amount_in);
+		let min_amount_out = min_amount_out_for(amount_to_sell)?;
+
+		Self::unallocate_amount(schedule_id, schedule, amount_to_sell)?;
+
+		let route_for_slippage = inverse_route(route.to_vec());
+		let (estimated_amount_out, slippage_amount) =
+			Self::calculate_last_block_slippage(&route_for_slippage, amount_to_sell, schedule.slippage)?;
+		let last_block_slippage_min_limit = estimated_amount_out
+			.checked_sub(slippage_amount)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		let trade_amounts = T::RouteExecutor::calculate_sell_trade_amounts(route, amount_to_sell)?;
+		let last_trade = trade_amounts.last().defensive_ok_or(Error::<T>::InvalidState)?;
+		let amount_out = last_trade.amount_out;
+
+		if min_amount_out > last_block_slippage_min_limit {
+			ensure!(amount_out >= min_amount_out, Error::<T>::TradeLimitReached);
+		} else {
+			ensure!(
+				amount_out >= last_block_slippage_min_limit,
+				Error::<T>::SlippageLimitReached
+			);
+		};
+
+		T::RouteExecutor::sell(origin, asset_in, asset_out, amount_to_sell, amount_out, route.to_vec())?;
+
+		Ok(AmountInAndOut {
+			amount_in: amount_to_sell,
+			amount_out,
+		})
+	}
+
+	fn execute_buy(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+		max_amount_in: Balance,
+		route: &[Trade<T::AssetId>],
+	) -> Result<AmountInAndOut<Balance>, DispatchError> {
+		let origin: OriginFor<T> = Origin::<T>::Signed(schedule.owner.clone()).into();
+
+		let amount_in = Self::get_amount_in_for_buy(&amount_out, route)?;
+
+		Self::unallocate_amount(schedule_id, schedule, amount_in)?;
+
+		let (estimated_amount_in, slippage_amount) =
+			Self::calculate_last_block_slippage(route, amount_out, schedule.slippage)?;
+		let last_block_slippage_max_limit = estimated_amount_in
+			.checked_add(slippage_amount)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		if max_amount_in < last_block_slippage_max_limit {
+			ensure!(amount_in <= max_amount_in, Error::<T>::TradeLimitReached);
+		} else {
+			ensure!(
+				amount_in <= last_block_slippage_max_limit,
+				Error::<T>::SlippageLimitReached
+			);
+		};
+
+		T::RouteExecutor::buy(origin, asset_in, asset_out, amount_out, amount_in, route.to_vec())?;
+
+		Ok(AmountInAndOut { amount_in, amount_out })
 	}
 
 	fn replan_or_complete(
@@ -731,11 +1383,20 @@ impl<T: Config> Pallet<T> {
 		amounts: AmountInAndOut<Balance>,
 		randomness_generator: &mut StdRng,
 	) -> DispatchResult {
-		Self::deposit_event(Event::TradeExecuted {
-			id: schedule_id,
-			who: schedule.owner.clone(),
-			amount_in: amounts.amount_in,
-			amount_out: amounts.amount_out,
+		//The trades of portfolio orders are already emitted in `PortfolioTradeExecuted` events
+		if !matches!(schedule.order, Order::Portfolio { .. }) {
+			Self::deposit_event(Event::TradeExecuted {
+				id: schedule_id,
+				who: schedule.owner.clone(),
+				amount_in: amounts.amount_in,
+				amount_out: amounts.amount_out,
+			});
+		}
+
+		ScheduleHistories::<T>::mutate(schedule_id, |maybe_history| {
+			maybe_history
+				.get_or_insert_with(ScheduleHistory::default)
+				.record_trade(&amounts, current_blocknumber);
 		});
 
 		RetriesOnError::<T>::remove(schedule_id);
@@ -750,8 +1411,8 @@ impl<T: Config> Pallet<T> {
 		}
 
 		//In buy we complete with returning leftover, in sell we sell the leftover in the next trade
-		if let Order::Buy { amount_out, .. } = &schedule.order {
-			let route = schedule.order.get_route_or_default::<T::RouteProvider>();
+		if let Order::Buy { amount_out, .. } | Order::LimitBuy { amount_out, .. } = &schedule.order {
+			let route = Self::get_route_or_default(&schedule.order);
 			let amount_to_unreserve: Balance = Self::get_amount_in_for_buy(amount_out, &route)?;
 
 			let amount_for_next_trade: Balance = amount_to_unreserve
@@ -764,6 +1425,19 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
+		//In portfolio we complete when the share of any order in the next trade is below the trading limit
+		if let Order::Portfolio { amount_in, orders, .. } = &schedule.order {
+			let amount_for_next_trade = min(remaining_amount.saturating_sub(transaction_fee), *amount_in);
+			let is_any_share_below_limit = orders
+				.iter()
+				.any(|order| order.weight.mul_floor(amount_for_next_trade) < T::MinimumTradingLimit::get());
+
+			if is_any_share_below_limit {
+				Self::complete_schedule(schedule_id, schedule);
+				return Ok(());
+			}
+		}
+
 		let next_execution_block = current_blocknumber
 			.checked_add(&schedule.period)
 			.ok_or(ArithmeticError::Overflow)?;
@@ -805,8 +1479,18 @@ impl<T: Config> Pallet<T> {
 	}
 
 	fn is_price_unstable(schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>) -> bool {
-		let route = &schedule.order.get_route_or_default::<T::RouteProvider>();
+		let max_allowed_diff = schedule
+			.stability_threshold
+			.unwrap_or_else(T::MaxPriceDifferenceBetweenBlocks::get);
+
+		schedule
+			.order
+			.get_routes_or_default::<T::RouteProvider>()
+			.iter()
+			.any(|route| Self::is_route_price_unstable(route, max_allowed_diff))
+	}
 
+	fn is_route_price_unstable(route: &[Trade<T::AssetId>], max_allowed_diff: Permill) -> bool {
 		let Ok(last_block_price) = Self::get_price_from_last_block_oracle(route) else {
 			return true;
 		};
@@ -815,10 +1499,6 @@ impl<T: Config> Pallet<T> {
    			return true;
 		};
 
-		let max_allowed_diff = schedule
-			.stability_threshold
-			.unwrap_or_else(T::MaxPriceDifferenceBetweenBlocks::get);
-
 		let max_allowed = FixedU128::from(max_allowed_diff);
 
 		let Some(price_sum) = last_block_price
@@ -848,6 +1528,67 @@ impl<T: Config> Pallet<T> {
 		diff > max_allowed_difference
 	}
 
+	fn is_expired(
+		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+		current_blocknumber: T::BlockNumber,
+	) -> bool {
+		matches!(
+			schedule.order.get_limit(),
+			Some((_, Some(expires_at))) if current_blocknumber > expires_at
+		)
+	}
+
+	fn is_limit_price_reached(
+		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+	) -> Result<bool, DispatchError> {
+		let Some((limit_price, _)) = schedule.order.get_limit() else {
+			return Ok(true);
+		};
+
+		let route = Self::get_route_or_default(&schedule.order);
+		let price = Self::get_price_from_last_block_oracle(&route)?;
+
+		Ok(price <= limit_price)
+	}
+
+	fn validate_portfolio(
+		asset_in: T::AssetId,
+		amount_in: Balance,
+		orders: &[PortfolioOrder<T::AssetId>],
+	) -> DispatchResult {
+		ensure!(!orders.is_empty(), Error::<T>::InvalidPortfolio);
+
+		let mut total_weight: u32 = 0;
+		for (index, order) in orders.iter().enumerate() {
+			ensure!(
+				!order.weight.is_zero()
+					&& order.asset_out != asset_in
+					&& !orders[..index].iter().any(|o| o.asset_out == order.asset_out),
+				Error::<T>::InvalidPortfolio
+			);
+			ensure!(
+				order.weight.mul_floor(amount_in) >= T::MinimumTradingLimit::get(),
+				Error::<T>::MinTradeAmountNotReached
+			);
+			total_weight = total_weight.saturating_add(order.weight.deconstruct());
+		}
+		ensure!(
+			total_weight == Permill::one().deconstruct(),
+			Error::<T>::InvalidPortfolio
+		);
+
+		Ok(())
+	}
+
+	/// Returns the route of orders executing a single trade.
+	fn get_route_or_default(order: &Order<T::AssetId, BlockNumberFor<T>>) -> Vec<Trade<T::AssetId>> {
+		order
+			.get_routes_or_default::<T::RouteProvider>()
+			.into_iter()
+			.next()
+			.unwrap_or_default()
+	}
+
 	fn get_amount_in_for_buy(amount_out: &Balance, route: &[Trade<T::AssetId>]) -> Result<Balance, DispatchError> {
 		let trade_amounts = T::RouteExecutor::calculate_buy_trade_amounts(route, *amount_out)?;
 
@@ -856,7 +1597,7 @@ impl<T: Config> Pallet<T> {
 		Ok(first_trade.amount_in)
 	}
 
-	pub fn get_transaction_fee(order: &Order<T::AssetId>) -> Result<Balance, DispatchError> {
+	pub fn get_transaction_fee(order: &Order<T::AssetId, BlockNumberFor<T>>) -> Result<Balance, DispatchError> {
 		Self::convert_weight_to_fee(Self::get_trade_weight(order), order.get_asset_in())
 	}
 
@@ -892,6 +1633,97 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Takes the execution fee from the sponsor of the schedule, or from the owner if the sponsorship doesn't cover it.
+	///
+	/// Returns the weight to charge if the fee is to be taken from the bought asset after the trade.
+	fn take_transaction_fee(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+		weight_to_charge: Weight,
+	) -> Result<Option<Weight>, DispatchError> {
+		if Self::take_transaction_fee_from_sponsor(schedule_id, weight_to_charge)? {
+			return Ok(None);
+		}
+
+		match FeeCurrencies::<T>::get(schedule_id) {
+			FeeCurrency::SoldAsset => {
+				Self::take_transaction_fee_from_user(schedule_id, schedule, weight_to_charge)?;
+				Ok(None)
+			}
+			FeeCurrency::BoughtAsset => Ok(Some(weight_to_charge)),
+		}
+	}
+
+	#[transactional]
+	fn take_transaction_fee_from_sponsor(
+		schedule_id: ScheduleId,
+		weight_to_charge: Weight,
+	) -> Result<bool, DispatchError> {
+		Sponsorships::<T>::try_mutate(schedule_id, |maybe_sponsorship| -> Result<bool, DispatchError> {
+			let Some(sponsorship) = maybe_sponsorship else {
+				return Ok(false);
+			};
+
+			let fee_amount_in_native = Self::weight_to_fee(weight_to_charge);
+			let Some(remaining) = sponsorship.remaining.checked_sub(fee_amount_in_native) else {
+				return Ok(false);
+			};
+			sponsorship.remaining = remaining;
+
+			let native_asset = T::NativeAssetId::get();
+			let remaining_amount_if_insufficient_balance = T::Currencies::unreserve_named(
+				&T::NamedReserveId::get(),
+				native_asset,
+				&sponsorship.sponsor,
+				fee_amount_in_native,
+			);
+			ensure!(remaining_amount_if_insufficient_balance == 0, Error::<T>::InvalidState);
+
+			T::Currencies::transfer(
+				native_asset,
+				&sponsorship.sponsor,
+				&T::FeeReceiver::get(),
+				fee_amount_in_native,
+			)?;
+
+			Ok(true)
+		})
+	}
+
+	fn take_transaction_fee_from_bought_amount(
+		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+		weight_to_charge: Weight,
+		amount_out: Balance,
+	) -> DispatchResult {
+		let fee_currency = schedule
+			.order
+			.get_asset_out()
+			.ok_or(Error::<T>::FeeCurrencyNotSupported)?;
+		let fee_amount_in_bought_asset = Self::convert_weight_to_fee(weight_to_charge, fee_currency)?;
+		ensure!(
+			fee_amount_in_bought_asset <= amount_out,
+			Error::<T>::FeeExceedsBoughtAmount
+		);
+
+		T::Currencies::transfer(
+			fee_currency,
+			&schedule.owner,
+			&T::FeeReceiver::get(),
+			fee_amount_in_bought_asset,
+		)
+	}
+
+	fn take_deferred_transaction_fee_from_user(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, T::BlockNumber>,
+		deferred_fee_weight: Option<Weight>,
+	) -> DispatchResult {
+		match deferred_fee_weight {
+			Some(weight) => Self::take_transaction_fee_from_user(schedule_id, schedule, weight),
+			None => Ok(()),
+		}
+	}
+
 	#[transactional]
 	fn take_transaction_fee_from_user(
 		schedule_id: ScheduleId,
@@ -920,6 +1752,7 @@ impl<T: Config> Pallet<T> {
 	) {
 		Self::try_unreserve_all(schedule_id, schedule);
 
+		Self::record_error(schedule_id, error);
 		Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
 
 		Self::deposit_event(Event::Terminated {
@@ -1033,15 +1866,20 @@ impl<T: Config> Pallet<T> {
 		Ok(fee_amount_in_sold_asset)
 	}
 
-	// returns DCA overhead weight + router execution weight
-	fn get_trade_weight(order: &Order<T::AssetId>) -> Weight {
-		let route = &order.get_route_or_default::<T::RouteProvider>();
-		match order {
-			Order::Sell { .. } => <T as Config>::WeightInfo::on_initialize_with_sell_trade()
-				.saturating_add(T::AmmTradeWeights::sell_and_calculate_sell_trade_amounts_weight(route)),
-			Order::Buy { .. } => <T as Config>::WeightInfo::on_initialize_with_buy_trade()
-				.saturating_add(T::AmmTradeWeights::buy_and_calculate_buy_trade_amounts_weight(route)),
-		}
+	// returns DCA overhead weight + router execution weight for each trade of the order
+	fn get_trade_weight(order: &Order<T::AssetId, BlockNumberFor<T>>) -> Weight {
+		let routes = order.get_routes_or_default::<T::RouteProvider>();
+		routes.iter().fold(Weight::zero(), |weight, route| {
+			let trade_weight = match order {
+				Order::Sell { .. } | Order::LimitSell { .. } | Order::Portfolio { .. } => {
+					<T as Config>::WeightInfo::on_initialize_with_sell_trade()
+						.saturating_add(T::AmmTradeWeights::sell_and_calculate_sell_trade_amounts_weight(route))
+				}
+				Order::Buy { .. } | Order::LimitBuy { .. } => <T as Config>::WeightInfo::on_initialize_with_buy_trade()
+					.saturating_add(T::AmmTradeWeights::buy_and_calculate_buy_trade_amounts_weight(route)),
+			};
+			weight.saturating_add(trade_weight)
+		})
 	}
 
 	fn convert_native_amount_to_currency(
@@ -1085,6 +1923,84 @@ impl<T: Config> Pallet<T> {
 		ScheduleOwnership::<T>::remove(owner, schedule_id);
 		RemainingAmounts::<T>::remove(schedule_id);
 		RetriesOnError::<T>::remove(schedule_id);
+		PausedSchedules::<T>::remove(schedule_id);
+		FeeCurrencies::<T>::remove(schedule_id);
+		ApprovedSponsors::<T>::remove(schedule_id);
+		Self::refund_sponsorship(schedule_id);
+		Self::retain_history(schedule_id);
+	}
+
+	/// Returns the unspent sponsored fees to the sponsor and removes the sponsorship.
+	fn refund_sponsorship(schedule_id: ScheduleId) {
+		let Some(sponsorship) = Sponsorships::<T>::take(schedule_id) else {
+			return;
+		};
+
+		T::Currencies::unreserve_named(
+			&T::NamedReserveId::get(),
+			T::NativeAssetId::get(),
+			&sponsorship.sponsor,
+			sponsorship.remaining,
+		);
+
+		Self::deposit_event(Event::SponsorshipRefunded {
+			id: schedule_id,
+			sponsor: sponsorship.sponsor,
+			amount: sponsorship.remaining,
+		});
+	}
+
+	fn record_error(schedule_id: ScheduleId, error: DispatchError) {
+		ScheduleHistories::<T>::mutate(schedule_id, |maybe_history| {
+			maybe_history.get_or_insert_with(ScheduleHistory::default).last_error = Some(error);
+		});
+	}
+
+	/// Marks the history of a removed schedule to be removed after `HistoryRetentionPeriod`.
+	fn retain_history(schedule_id: ScheduleId) {
+		let current_block_number = frame_system::Pallet::<T>::current_block_number();
+		ScheduleHistories::<T>::mutate(schedule_id, |maybe_history| {
+			maybe_history.get_or_insert_with(ScheduleHistory::default).removed_at = Some(current_block_number);
+		});
+
+		let expiration_block = current_block_number.saturating_add(T::HistoryRetentionPeriod::get());
+		HistoryExpirations::<T>::insert(expiration_block, schedule_id, ());
+	}
+
+	fn remove_expired_histories(current_blocknumber: T::BlockNumber) -> Weight {
+		let mut removed: u64 = 0;
+		for (schedule_id, ()) in HistoryExpirations::<T>::drain_prefix(current_blocknumber) {
+			ScheduleHistories::<T>::remove(schedule_id);
+			removed.saturating_inc();
+		}
+
+		T::DbWeight::get().reads_writes(removed.saturating_add(1), removed.saturating_mul(2))
+	}
+
+	/// Returns the schedules of `owner` which are not completed or terminated yet, including the paused ones.
+	pub fn active_schedules(
+		owner: &T::AccountId,
+	) -> Vec<(ScheduleId, Schedule<T::AccountId, T::AssetId, T::BlockNumber>)> {
+		ScheduleOwnership::<T>::iter_key_prefix(owner)
+			.filter_map(|schedule_id| Schedules::<T>::get(schedule_id).map(|schedule| (schedule_id, schedule)))
+			.collect()
+	}
+
+	fn remove_schedule_id_from_block(schedule_id: ScheduleId, blocknumber: T::BlockNumber) -> DispatchResult {
+		ScheduleIdsPerBlock::<T>::try_mutate_exists(blocknumber, |maybe_schedule_ids| -> DispatchResult {
+			let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+
+			let index = schedule_ids
+				.binary_search(&schedule_id)
+				.map_err(|_| Error::<T>::ScheduleNotFound)?;
+
+			schedule_ids.remove(index);
+
+			if schedule_ids.is_empty() {
+				*maybe_schedule_ids = None;
+			}
+			Ok(())
+		})
 	}
 }
 
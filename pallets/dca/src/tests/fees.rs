@@ -0,0 +1,571 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::*;
+use crate::{
+	assert_balance, assert_number_of_executed_buy_trades, assert_that_schedule_has_been_removed_from_storages, Error,
+	Event, FeeCurrencies, FeeCurrency, Order, PortfolioOrder, Sponsorship,
+};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{PoolType, Trade};
+use orml_traits::MultiReservableCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::{BoundedVec, Permill};
+
+fn buy_order(max_amount_in: Balance) -> Order<AssetId, BlockNumber> {
+	Order::Buy {
+		asset_in: DAI,
+		asset_out: BTC,
+		amount_out: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+		max_amount_in,
+		route: create_bounded_vec(vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: DAI,
+			asset_out: BTC,
+		}]),
+	}
+}
+
+#[test]
+fn execution_fee_should_be_taken_from_bought_asset_when_fee_currency_is_bought_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 5000 * ONE), (ALICE, DAI, 5000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let budget = 1000 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_total_amount(budget)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_order(buy_order(50 * ONE))
+				.build();
+
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			assert_ok!(DCA::set_fee_currency(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				FeeCurrency::BoughtAsset
+			));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(1);
+			assert_balance!(TreasuryAccount::get(), DAI, 0);
+			assert_balance!(TreasuryAccount::get(), BTC, BUY_DCA_FEE_IN_DAI);
+			assert_balance!(ALICE, BTC, CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY - BUY_DCA_FEE_IN_DAI);
+			assert_eq!(
+				Currencies::reserved_balance(DAI, &ALICE),
+				budget - CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY
+			);
+		});
+}
+
+#[test]
+fn execution_fee_should_be_taken_from_budget_when_trade_fails_and_fee_currency_is_bought_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 5000 * ONE), (ALICE, DAI, 5000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let budget = 1000 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_total_amount(budget)
+				.with_order(buy_order(5 * ONE))
+				.build();
+
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			assert_ok!(DCA::set_fee_currency(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				FeeCurrency::BoughtAsset
+			));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(0);
+			assert_balance!(TreasuryAccount::get(), BTC, 0);
+			assert_balance!(TreasuryAccount::get(), DAI, BUY_DCA_FEE_IN_DAI);
+			assert_eq!(Currencies::reserved_balance(DAI, &ALICE), budget - BUY_DCA_FEE_IN_DAI);
+		});
+}
+
+#[test]
+fn execution_fee_should_be_taken_from_budget_when_schedule_is_terminated_and_fee_currency_is_bought_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 5000 * ONE), (ALICE, FORBIDDEN_ASSET, 5000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_total_amount(1000 * ONE)
+				.with_order(Order::Sell {
+					asset_in: FORBIDDEN_ASSET,
+					asset_out: BTC,
+					amount_in: ONE,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: PoolType::Omnipool,
+						asset_in: FORBIDDEN_ASSET,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			assert_ok!(DCA::set_fee_currency(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				FeeCurrency::BoughtAsset
+			));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+			assert_balance!(TreasuryAccount::get(), BTC, 0);
+			assert_balance!(TreasuryAccount::get(), FORBIDDEN_ASSET, SELL_DCA_FEE_IN_DAI);
+			assert_balance!(ALICE, FORBIDDEN_ASSET, 5000 * ONE - SELL_DCA_FEE_IN_DAI);
+			assert_eq!(Currencies::reserved_balance(FORBIDDEN_ASSET, &ALICE), 0);
+		});
+}
+
+#[test]
+fn set_fee_currency_should_fail_for_portfolio_order() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			System::set_block_number(500);
+			let schedule = ScheduleBuilder::new()
+				.with_order(Order::Portfolio {
+					asset_in: HDX,
+					amount_in: ONE,
+					orders: BoundedVec::truncate_from(vec![PortfolioOrder {
+						asset_out: BTC,
+						weight: Permill::from_percent(100),
+						min_amount_out: ONE,
+						route: create_bounded_vec(vec![]),
+					}]),
+				})
+				.build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act and assert
+			assert_noop!(
+				DCA::set_fee_currency(RuntimeOrigin::signed(ALICE), schedule_id, FeeCurrency::BoughtAsset),
+				Error::<Test>::FeeCurrencyNotSupported
+			);
+		});
+}
+
+#[test]
+fn set_fee_currency_should_fail_when_bought_asset_has_no_native_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			System::set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			let asset_out = DCA::schedules(schedule_id).unwrap().order.get_asset_out().unwrap();
+			ASSETS_WITHOUT_NATIVE_PRICE.with(|v| v.borrow_mut().push(asset_out));
+
+			//Act and assert
+			assert_noop!(
+				DCA::set_fee_currency(RuntimeOrigin::signed(ALICE), schedule_id, FeeCurrency::BoughtAsset),
+				Error::<Test>::CalculatingPriceError
+			);
+		});
+}
+
+#[test]
+fn set_fee_currency_should_fail_when_called_by_non_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			System::set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act and assert
+			assert_noop!(
+				DCA::set_fee_currency(RuntimeOrigin::signed(BOB), schedule_id, FeeCurrency::BoughtAsset),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn set_fee_currency_should_be_removed_when_sold_asset_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			System::set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			assert_ok!(DCA::set_fee_currency(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				FeeCurrency::BoughtAsset
+			));
+
+			//Act
+			assert_ok!(DCA::set_fee_currency(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				FeeCurrency::SoldAsset
+			));
+
+			//Assert
+			assert!(!FeeCurrencies::<Test>::contains_key(schedule_id));
+			expect_events(vec![Event::FeeCurrencySet {
+				id: schedule_id,
+				who: ALICE,
+				fee_currency: FeeCurrency::SoldAsset,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn execution_fee_should_be_taken_from_sponsor_when_schedule_is_sponsored() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 5000 * ONE),
+			(ALICE, DAI, 5000 * ONE),
+			(BOB, HDX, 5000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let budget = 1000 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_total_amount(budget)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_order(buy_order(50 * ONE))
+				.build();
+
+			let schedule_id = 0;
+			let sponsored_amount = 10 * ONE;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			assert_ok!(DCA::approve_sponsor(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				Some(BOB)
+			));
+			assert_ok!(DCA::sponsor_fees(
+				RuntimeOrigin::signed(BOB),
+				schedule_id,
+				sponsored_amount
+			));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_number_of_executed_buy_trades!(1);
+			assert_balance!(TreasuryAccount::get(), DAI, 0);
+			assert_balance!(TreasuryAccount::get(), HDX, BUY_DCA_FEE_IN_NATIVE);
+			assert_eq!(
+				Currencies::reserved_balance(DAI, &ALICE),
+				budget - CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY
+			);
+			assert_eq!(
+				Currencies::reserved_balance(HDX, &BOB),
+				sponsored_amount - BUY_DCA_FEE_IN_NATIVE
+			);
+			assert_eq!(
+				DCA::sponsorship(schedule_id),
+				Some(Sponsorship {
+					sponsor: BOB,
+					remaining: sponsored_amount - BUY_DCA_FEE_IN_NATIVE,
+				})
+			);
+		});
+}
+
+#[test]
+fn execution_fee_should_be_taken_from_owner_when_sponsorship_does_not_cover_it() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 5000 * ONE),
+			(ALICE, DAI, 5000 * ONE),
+			(BOB, HDX, 5000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let budget = 1000 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_total_amount(budget)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_order(buy_order(50 * ONE))
+				.build();
+
+			let schedule_id = 0;
+			let sponsored_amount = BUY_DCA_FEE_IN_NATIVE - 1;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			assert_ok!(DCA::approve_sponsor(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				Some(BOB)
+			));
+			assert_ok!(DCA::sponsor_fees(
+				RuntimeOrigin::signed(BOB),
+				schedule_id,
+				sponsored_amount
+			));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			assert_balance!(TreasuryAccount::get(), DAI, BUY_DCA_FEE_IN_DAI);
+			assert_eq!(Currencies::reserved_balance(HDX, &BOB), sponsored_amount);
+			assert_eq!(
+				Currencies::reserved_balance(DAI, &ALICE),
+				budget - CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY - BUY_DCA_FEE_IN_DAI
+			);
+		});
+}
+
+#[test]
+fn sponsored_fees_should_be_refunded_when_schedule_is_terminated() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE), (BOB, HDX, 5000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			System::set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			let sponsored_amount = 10 * ONE;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			assert_ok!(DCA::approve_sponsor(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				Some(BOB)
+			));
+			assert_ok!(DCA::sponsor_fees(
+				RuntimeOrigin::signed(BOB),
+				schedule_id,
+				sponsored_amount
+			));
+
+			//Act
+			assert_ok!(DCA::terminate(RuntimeOrigin::signed(ALICE), schedule_id, None));
+
+			//Assert
+			assert_eq!(Currencies::reserved_balance(HDX, &BOB), 0);
+			assert_balance!(BOB, HDX, 5000 * ONE);
+			assert_eq!(DCA::sponsorship(schedule_id), None);
+			System::assert_has_event(
+				Event::SponsorshipRefunded {
+					id: schedule_id,
+					sponsor: BOB,
+					amount: sponsored_amount,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn withdraw_sponsorship_should_refund_sponsor() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE), (BOB, HDX, 5000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			System::set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			let sponsored_amount = 10 * ONE;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			assert_ok!(DCA::approve_sponsor(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				Some(BOB)
+			));
+			assert_ok!(DCA::sponsor_fees(
+				RuntimeOrigin::signed(BOB),
+				schedule_id,
+				sponsored_amount
+			));
+
+			//Act
+			assert_ok!(DCA::withdraw_sponsorship(RuntimeOrigin::signed(BOB), schedule_id));
+
+			//Assert
+			assert_eq!(Currencies::reserved_balance(HDX, &BOB), 0);
+			assert_eq!(DCA::sponsorship(schedule_id), None);
+			expect_events(vec![Event::SponsorshipRefunded {
+				id: schedule_id,
+				sponsor: BOB,
+				amount: sponsored_amount,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn withdraw_sponsorship_should_fail_when_called_by_other_account() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE), (BOB, HDX, 5000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			System::set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			assert_ok!(DCA::approve_sponsor(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				Some(BOB)
+			));
+			assert_ok!(DCA::sponsor_fees(RuntimeOrigin::signed(BOB), schedule_id, 10 * ONE));
+
+			//Act and assert
+			assert_noop!(
+				DCA::withdraw_sponsorship(RuntimeOrigin::signed(ALICE), schedule_id),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn sponsor_fees_should_fail_when_sponsor_is_not_approved() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE), (BOB, HDX, 5000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			System::set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act and assert
+			assert_noop!(
+				DCA::sponsor_fees(RuntimeOrigin::signed(BOB), schedule_id, 10 * ONE),
+				Error::<Test>::SponsorNotApproved
+			);
+		});
+}
+
+#[test]
+fn approve_sponsor_should_refund_sponsorship_of_previously_approved_sponsor() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 10000 * ONE),
+			(BOB, HDX, 5000 * ONE),
+			(CHARLIE, HDX, 5000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			System::set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			assert_ok!(DCA::approve_sponsor(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				Some(BOB)
+			));
+			assert_ok!(DCA::sponsor_fees(RuntimeOrigin::signed(BOB), schedule_id, 1));
+
+			//Act
+			assert_ok!(DCA::approve_sponsor(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				Some(CHARLIE)
+			));
+
+			//Assert
+			assert_eq!(Currencies::reserved_balance(HDX, &BOB), 0);
+			assert_eq!(DCA::approved_sponsor(schedule_id), Some(CHARLIE));
+			assert_noop!(
+				DCA::sponsor_fees(RuntimeOrigin::signed(BOB), schedule_id, 10 * ONE),
+				Error::<Test>::SponsorNotApproved
+			);
+			assert_ok!(DCA::sponsor_fees(RuntimeOrigin::signed(CHARLIE), schedule_id, 10 * ONE));
+			assert_eq!(
+				DCA::sponsorship(schedule_id),
+				Some(Sponsorship {
+					sponsor: CHARLIE,
+					remaining: 10 * ONE,
+				})
+			);
+		});
+}
+
+#[test]
+fn approve_sponsor_should_fail_when_called_by_non_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			System::set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act and assert
+			assert_noop!(
+				DCA::approve_sponsor(RuntimeOrigin::signed(BOB), schedule_id, Some(BOB)),
+				Error::<Test>::Forbidden
+			);
+		});
+}
@@ -0,0 +1,193 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::*;
+use crate::{Error, HistoryExpirations, Order, ScheduleHistory};
+use frame_support::assert_ok;
+use hydradx_traits::router::{PoolType, Trade};
+use pretty_assertions::assert_eq;
+use sp_runtime::{FixedU128, Permill};
+
+fn buy_order(amount_out: Balance) -> Order<AssetId, BlockNumber> {
+	Order::Buy {
+		asset_in: HDX,
+		asset_out: BTC,
+		amount_out,
+		max_amount_in: 50 * ONE,
+		route: create_bounded_vec(vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: BTC,
+		}]),
+	}
+}
+
+#[test]
+fn history_should_be_recorded_when_trades_are_executed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_order(buy_order(ONE))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+			set_to_blocknumber(601);
+
+			//Assert
+			let schedule_id = 0;
+			assert_eq!(
+				DCA::schedule_history(schedule_id),
+				Some(ScheduleHistory {
+					executions: 2,
+					total_amount_in: 2 * CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+					total_amount_out: 2 * ONE,
+					average_price: Some(FixedU128::from(10)),
+					last_error: None,
+					last_execution_block: Some(601),
+					removed_at: None,
+				})
+			);
+		});
+}
+
+#[test]
+fn history_should_be_kept_for_retention_period_when_schedule_is_terminated() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_order(buy_order(ONE))
+				.build();
+
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			set_to_blocknumber(501);
+
+			//Act
+			assert_ok!(DCA::terminate(RuntimeOrigin::root(), schedule_id, Some(601)));
+
+			//Assert
+			let history = DCA::schedule_history(schedule_id).unwrap();
+			assert_eq!(history.executions, 1);
+			assert_eq!(history.last_error, Some(Error::<Test>::ManuallyTerminated.into()));
+			assert_eq!(history.removed_at, Some(501));
+			assert_eq!(HistoryExpirations::<Test>::get(601, schedule_id), Some(()));
+
+			set_to_blocknumber(600);
+			assert!(DCA::schedule_history(schedule_id).is_some());
+
+			set_to_blocknumber(601);
+			assert_eq!(DCA::schedule_history(schedule_id), None);
+			assert_eq!(HistoryExpirations::<Test>::get(601, schedule_id), None);
+		});
+}
+
+#[test]
+fn history_should_be_kept_when_schedule_is_completed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = *AMOUNT_OUT_FOR_OMNIPOOL_SELL * 3 / 2;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![Trade {
+						pool: PoolType::Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+			set_to_blocknumber(601);
+
+			//Assert
+			assert!(DCA::schedules(schedule_id).is_none());
+			let history = DCA::schedule_history(schedule_id).unwrap();
+			assert_eq!(history.executions, 2);
+			assert_eq!(history.total_amount_out, 2 * *AMOUNT_OUT_FOR_OMNIPOOL_SELL);
+			assert_eq!(history.last_error, None);
+			assert_eq!(history.last_execution_block, Some(601));
+			assert_eq!(history.removed_at, Some(601));
+		});
+}
+
+#[test]
+fn active_schedules_should_return_only_schedules_of_the_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE), (BOB, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			System::set_block_number(500);
+
+			let alice_schedule = ScheduleBuilder::new().build();
+			let bob_schedule = ScheduleBuilder::new().with_owner(BOB).build();
+
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				alice_schedule.clone(),
+				Option::None
+			));
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(BOB), bob_schedule, Option::None));
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				alice_schedule.clone(),
+				Option::None
+			));
+
+			//Act
+			assert_ok!(DCA::terminate(RuntimeOrigin::root(), 2, None));
+
+			//Assert
+			assert_eq!(DCA::active_schedules(&ALICE), vec![(0, alice_schedule)]);
+		});
+}
@@ -0,0 +1,252 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::schedule::set_block_number;
+use crate::tests::*;
+use crate::{
+	assert_balance, assert_number_of_executed_buy_trades, assert_number_of_executed_sell_trades, assert_scheduled_ids,
+	assert_that_schedule_has_been_removed_from_storages, Error, Event as DcaEvent, Order, Permill,
+};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{PoolType, Trade};
+use orml_traits::MultiReservableCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+fn limit_sell_order(limit_price: FixedU128, expires_at: Option<BlockNumber>) -> Order<AssetId, BlockNumber> {
+	Order::LimitSell {
+		asset_in: HDX,
+		asset_out: BTC,
+		amount_in: ONE,
+		limit_price,
+		expires_at,
+		route: create_bounded_vec(vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: BTC,
+		}]),
+	}
+}
+
+#[test]
+fn limit_sell_should_be_executed_when_oracle_price_is_below_limit_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(limit_sell_order(FixedU128::from(1), None))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			let schedule_id = 0;
+			expect_events(vec![
+				DcaEvent::TradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					amount_in: ONE,
+					amount_out: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 601,
+				}
+				.into(),
+			]);
+			assert_number_of_executed_sell_trades!(1);
+		});
+}
+
+#[test]
+fn limit_sell_should_be_replanned_without_trade_when_oracle_price_is_above_limit_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(limit_sell_order(FixedU128::from_rational(1, 2), None))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			let schedule_id = 0;
+			expect_events(vec![DcaEvent::ExecutionPlanned {
+				id: schedule_id,
+				who: ALICE,
+				block: 601,
+			}
+			.into()]);
+			assert_number_of_executed_sell_trades!(0);
+			assert_scheduled_ids!(601, vec![schedule_id]);
+			assert_eq!(
+				DCA::remaining_amounts(schedule_id).unwrap(),
+				total_amount - SELL_DCA_FEE_IN_NATIVE
+			);
+		});
+}
+
+#[test]
+fn limit_buy_should_be_executed_when_oracle_price_is_below_limit_price() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let amount_to_buy = 10 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(50 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_slippage(Some(Permill::from_percent(20)))
+				.with_order(Order::LimitBuy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: amount_to_buy,
+					limit_price: FixedU128::from(2),
+					expires_at: None,
+					route: create_bounded_vec(vec![Trade {
+						pool: PoolType::Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			let schedule_id = 0;
+			expect_events(vec![
+				DcaEvent::TradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					amount_in: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+					amount_out: amount_to_buy,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 601,
+				}
+				.into(),
+			]);
+			assert_number_of_executed_buy_trades!(1);
+		});
+}
+
+#[test]
+fn limit_order_should_be_terminated_when_expired() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(5 * ONE)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(limit_sell_order(FixedU128::from(1), Some(550)))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Some(600)));
+
+			//Act
+			set_to_blocknumber(600);
+
+			//Assert
+			let schedule_id = 0;
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+			expect_events(vec![DcaEvent::Terminated {
+				id: schedule_id,
+				who: ALICE,
+				error: Error::<Test>::ScheduleExpired.into(),
+			}
+			.into()]);
+			assert_number_of_executed_sell_trades!(0);
+			assert_eq!(Currencies::reserved_balance(HDX, &ALICE), 0);
+			assert_balance!(ALICE, HDX, 10000 * ONE);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_limit_price_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let schedule = ScheduleBuilder::new()
+				.with_order(limit_sell_order(FixedU128::from(0), None))
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidLimitPrice
+			);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_expiry_block_is_not_in_future() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_order(limit_sell_order(FixedU128::from(1), Some(500)))
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::BlockNumberIsNotInFuture
+			);
+		});
+}
@@ -56,10 +56,10 @@ pub type BlockNumber = u64;
 pub type AssetId = u32;
 type NamedReserveIdentifier = [u8; 8];
 
-pub const BUY_DCA_FEE_IN_NATIVE: Balance = 1332564000;
-pub const BUY_DCA_FEE_IN_DAI: Balance = 1172656320;
-pub const SELL_DCA_FEE_IN_NATIVE: Balance = 1334571000;
-pub const SELL_DCA_FEE_IN_DAI: Balance = 1174422480;
+pub const BUY_DCA_FEE_IN_NATIVE: Balance = 1507564000;
+pub const BUY_DCA_FEE_IN_DAI: Balance = 1326656320;
+pub const SELL_DCA_FEE_IN_NATIVE: Balance = 1509571000;
+pub const SELL_DCA_FEE_IN_DAI: Balance = 1328422480;
 
 pub const HDX: AssetId = 0;
 pub const LRNA: AssetId = 1;
@@ -116,6 +116,7 @@ thread_local! {
 	pub static WITHDRAWAL_ADJUSTMENT: RefCell<(u32,u32, bool)> = RefCell::new((0u32,0u32, false));
 	pub static CALCULATED_AMOUNT_OUT_FOR_SELL: RefCell<Balance> = RefCell::new(*AMOUNT_OUT_FOR_OMNIPOOL_SELL);
 	pub static USE_PROD_RANDOMNESS: RefCell<bool> = RefCell::new(false);
+	pub static ASSETS_WITHOUT_NATIVE_PRICE: RefCell<Vec<AssetId>> = RefCell::new(vec![]);
 	pub static PARENT_HASH: RefCell<Option<Hash>> = RefCell::new(Some([
 			14, 87, 81, 192, 38, 229, 67, 178, 232, 171, 46, 176, 96, 153, 218, 161, 209, 229, 223, 71, 119, 143, 119,
 			135, 250, 171, 69, 205, 241, 47, 227, 168,
@@ -152,9 +153,12 @@ parameter_types! {
 impl pallet_ema_oracle::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type OracleWhitelist = Everything;
 	type BlockNumberProvider = MockBlockNumberProvider;
 	type SupportedPeriods = SupportedPeriods;
 	type MaxUniqueEntries = ConstU32<20>;
+	type MaxPriceSnapshots = ConstU32<10>;
 }
 
 impl BlockNumberProvider for MockBlockNumberProvider {
@@ -247,6 +251,8 @@ impl pallet_omnipool::Config for Test {
 	type PositionItemId = u32;
 	type Currency = Currencies;
 	type HubAssetId = LRNAAssetId;
+	type ImbalanceBuybackAccount = TreasuryAccount;
+	type ImbalanceBuybackAssetId = HDXAssetId;
 	type WeightInfo = ();
 	type HdxAssetId = HDXAssetId;
 	type NFTCollectionId = PosiitionCollectionId;
@@ -357,6 +363,7 @@ impl pallet_route_executor::Config for Test {
 	type NativeAssetId = NativeCurrencyId;
 	type Currency = FungibleCurrencies<Test>;
 	type AMM = Pools;
+	type Pools = ();
 	type WeightInfo = ();
 }
 
@@ -613,6 +620,7 @@ parameter_types! {
 	pub OmnipoolMaxAllowedPriceDifference: Permill = MAX_PRICE_DIFFERENCE.with(|v| *v.borrow());
 	pub NamedReserveId: NamedReserveIdentifier = *b"dcaorder";
 	pub MaxNumberOfRetriesOnError: u8 = 3;
+	pub HistoryRetentionPeriod: BlockNumber = 100;
 }
 
 pub struct RandomnessProviderMock {}
@@ -645,6 +653,7 @@ impl Config for Test {
 	type RouteProvider = DefaultRouteProvider;
 	type MaxPriceDifferenceBetweenBlocks = OmnipoolMaxAllowedPriceDifference;
 	type NamedReserveId = NamedReserveId;
+	type HistoryRetentionPeriod = HistoryRetentionPeriod;
 	type MaxNumberOfRetriesOnError = MaxNumberOfRetriesOnError;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
 	type RelayChainBlockHashProvider = ParentHashGetterMock;
@@ -656,7 +665,10 @@ impl Config for Test {
 pub struct NativePriceOracleMock;
 
 impl NativePriceOracle<AssetId, EmaPrice> for NativePriceOracleMock {
-	fn price(_: AssetId) -> Option<EmaPrice> {
+	fn price(asset_id: AssetId) -> Option<EmaPrice> {
+		if ASSETS_WITHOUT_NATIVE_PRICE.with(|v| v.borrow().contains(&asset_id)) {
+			return None;
+		}
 		Some(EmaPrice::from((88, 100)))
 	}
 }
@@ -765,6 +777,7 @@ pub type AccountId = u64;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
 
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(u64, AssetId, Balance)>,
@@ -840,6 +853,10 @@ impl ExtBuilder {
 			*v.borrow_mut() = self.min_trading_limit;
 		});
 
+		ASSETS_WITHOUT_NATIVE_PRICE.with(|v| {
+			v.borrow_mut().clear();
+		});
+
 		let mut initial_native_accounts: Vec<(AccountId, Balance)> = vec![(ASSET_PAIR_ACCOUNT, 10000 * ONE)];
 		let additional_accounts: Vec<(AccountId, Balance)> = self
 			.endowed_accounts
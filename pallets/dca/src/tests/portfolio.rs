@@ -0,0 +1,276 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::on_initialize::{proceed_to_blocknumber, set_to_blocknumber};
+use crate::tests::*;
+use crate::{
+	assert_balance, assert_executed_sell_trades, assert_scheduled_ids,
+	assert_that_schedule_has_been_removed_from_storages, Error, Event as DcaEvent, Order, Permill, PortfolioOrder,
+};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiReservableCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::BoundedVec;
+
+fn portfolio_order(asset_out: AssetId, weight: Permill, min_amount_out: Balance) -> PortfolioOrder<AssetId> {
+	PortfolioOrder {
+		asset_out,
+		weight,
+		min_amount_out,
+		route: create_bounded_vec(vec![]),
+	}
+}
+
+fn portfolio(orders: Vec<PortfolioOrder<AssetId>>) -> Order<AssetId, BlockNumber> {
+	Order::Portfolio {
+		asset_in: HDX,
+		amount_in: ONE,
+		orders: BoundedVec::truncate_from(orders),
+	}
+}
+
+#[test]
+fn portfolio_should_execute_all_orders_in_the_same_block() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(portfolio(vec![
+					portfolio_order(DAI, Permill::from_percent(60), ONE),
+					portfolio_order(BTC, Permill::from_percent(40), ONE),
+				]))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			let schedule_id = 0;
+			System::assert_has_event(
+				DcaEvent::PortfolioTradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					asset_out: DAI,
+					amount_in: 6 * ONE / 10,
+					amount_out: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				}
+				.into(),
+			);
+			expect_events(vec![
+				DcaEvent::PortfolioTradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					asset_out: BTC,
+					amount_in: 4 * ONE / 10,
+					amount_out: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 601,
+				}
+				.into(),
+			]);
+			assert_executed_sell_trades!(vec![
+				SellExecution {
+					asset_in: HDX,
+					asset_out: DAI,
+					amount_in: 6 * ONE / 10,
+					min_buy_amount: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				},
+				SellExecution {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: 4 * ONE / 10,
+					min_buy_amount: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				},
+			]);
+			assert_balance!(ALICE, DAI, *AMOUNT_OUT_FOR_OMNIPOOL_SELL);
+			assert_balance!(ALICE, BTC, *AMOUNT_OUT_FOR_OMNIPOOL_SELL);
+			assert_eq!(
+				DCA::remaining_amounts(schedule_id).unwrap(),
+				total_amount - ONE - 2 * SELL_DCA_FEE_IN_NATIVE
+			);
+		});
+}
+
+#[test]
+fn portfolio_should_be_completed_when_share_of_any_order_in_next_trade_is_below_min_trading_limit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.with_min_trading_limit(ONE / 20)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let leftover = ONE / 4;
+			let total_amount = ONE + 2 * SELL_DCA_FEE_IN_NATIVE + leftover;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(portfolio(vec![
+					portfolio_order(DAI, Permill::from_percent(90), ONE),
+					portfolio_order(BTC, Permill::from_percent(10), ONE),
+				]))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			let schedule_id = 0;
+			assert_executed_sell_trades!(vec![
+				SellExecution {
+					asset_in: HDX,
+					asset_out: DAI,
+					amount_in: 9 * ONE / 10,
+					min_buy_amount: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				},
+				SellExecution {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE / 10,
+					min_buy_amount: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				},
+			]);
+			expect_events(vec![DcaEvent::Completed {
+				id: schedule_id,
+				who: ALICE,
+			}
+			.into()]);
+			assert_that_schedule_has_been_removed_from_storages!(ALICE, schedule_id);
+			assert_eq!(Currencies::reserved_balance(HDX, &ALICE), 0);
+		});
+}
+
+#[test]
+fn portfolio_should_not_execute_any_order_when_one_of_them_fails() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(portfolio(vec![
+					portfolio_order(DAI, Permill::from_percent(50), ONE),
+					portfolio_order(BTC, Permill::from_percent(50), 100 * ONE),
+				]))
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(501);
+
+			//Assert
+			let schedule_id = 0;
+			expect_events(vec![
+				DcaEvent::TradeFailed {
+					id: schedule_id,
+					who: ALICE,
+					error: Error::<Test>::TradeLimitReached.into(),
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 511,
+				}
+				.into(),
+			]);
+			assert_balance!(ALICE, DAI, 0);
+			assert_balance!(ALICE, BTC, 0);
+			assert_scheduled_ids!(511, vec![schedule_id]);
+			assert_eq!(
+				DCA::remaining_amounts(schedule_id).unwrap(),
+				total_amount - 2 * SELL_DCA_FEE_IN_NATIVE
+			);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_portfolio_weights_do_not_sum_up_to_100_percent() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			let schedule = ScheduleBuilder::new()
+				.with_order(portfolio(vec![
+					portfolio_order(DAI, Permill::from_percent(60), ONE),
+					portfolio_order(BTC, Permill::from_percent(30), ONE),
+				]))
+				.build();
+
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidPortfolio
+			);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_portfolio_is_empty() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			let schedule = ScheduleBuilder::new().with_order(portfolio(vec![])).build();
+
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidPortfolio
+			);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_portfolio_contains_same_asset_out_twice() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			let schedule = ScheduleBuilder::new()
+				.with_order(portfolio(vec![
+					portfolio_order(DAI, Permill::from_percent(50), ONE),
+					portfolio_order(DAI, Permill::from_percent(50), ONE),
+				]))
+				.build();
+
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidPortfolio
+			);
+		});
+}
@@ -0,0 +1,123 @@
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::tests::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn top_up_should_increase_remaining_amount_and_reserve() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let total_amount = 100 * ONE;
+			let top_up_amount = 50 * ONE;
+			let schedule = ScheduleBuilder::new().with_total_amount(total_amount).build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act
+			assert_ok!(DCA::top_up(RuntimeOrigin::signed(ALICE), schedule_id, top_up_amount));
+
+			//Assert
+			assert_eq!(
+				DCA::remaining_amounts(schedule_id).unwrap(),
+				total_amount + top_up_amount
+			);
+			assert_eq!(
+				DCA::schedules(schedule_id).unwrap().total_amount,
+				total_amount + top_up_amount
+			);
+			assert_eq!(
+				total_amount + top_up_amount,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+
+			expect_events(vec![Event::ToppedUp {
+				id: schedule_id,
+				who: ALICE,
+				amount: top_up_amount,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn top_up_should_fail_when_called_by_not_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE), (BOB, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::top_up(RuntimeOrigin::signed(BOB), schedule_id, ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn top_up_should_fail_when_amount_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::top_up(RuntimeOrigin::signed(ALICE), schedule_id, 0),
+				Error::<Test>::InvalidAmount
+			);
+		});
+}
+
+#[test]
+fn top_up_should_fail_when_balance_is_not_sufficient() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().with_total_amount(9000 * ONE).build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert!(DCA::top_up(RuntimeOrigin::signed(ALICE), schedule_id, 2000 * ONE).is_err());
+			assert_eq!(DCA::remaining_amounts(schedule_id).unwrap(), 9000 * ONE);
+		});
+}
+
+pub fn set_block_number(n: u64) {
+	System::set_block_number(n);
+}
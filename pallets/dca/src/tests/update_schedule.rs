@@ -0,0 +1,122 @@
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::tests::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use sp_runtime::Permill;
+
+#[test]
+fn update_schedule_should_update_specified_parameters() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new()
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_slippage(Some(Permill::from_percent(5)))
+				.with_max_retries(Some(3))
+				.build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::Some(600)
+			));
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				Some(50),
+				None,
+				Some(Permill::from_percent(10)),
+				None,
+			));
+
+			//Assert
+			let updated_schedule = DCA::schedules(schedule_id).unwrap();
+			assert_eq!(updated_schedule.period, 50);
+			assert_eq!(updated_schedule.slippage, Some(Permill::from_percent(5)));
+			assert_eq!(updated_schedule.stability_threshold, Some(Permill::from_percent(10)));
+			assert_eq!(updated_schedule.max_retries, Some(3));
+			assert_eq!(updated_schedule.order, schedule.order);
+
+			expect_events(vec![Event::ScheduleUpdated {
+				id: schedule_id,
+				who: ALICE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_called_by_not_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(RuntimeOrigin::signed(BOB), schedule_id, Some(50), None, None, None),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_period_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(RuntimeOrigin::signed(ALICE), schedule_id, Some(0), None, None, None),
+				Error::<Test>::InvalidPeriod
+			);
+		});
+}
+
+#[test]
+fn update_schedule_should_fail_when_schedule_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			DCA::update_schedule(RuntimeOrigin::signed(ALICE), 999, Some(50), None, None, None),
+			Error::<Test>::ScheduleNotFound
+		);
+	});
+}
+
+pub fn set_block_number(n: u64) {
+	System::set_block_number(n);
+}
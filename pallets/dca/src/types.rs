@@ -1,8 +1,9 @@
 use codec::{Decode, Encode, MaxEncodedLen};
-use hydradx_traits::router::{AssetPair, RouteProvider, Trade};
+use hydradx_traits::router::{AmountInAndOut, AssetPair, RouteProvider, Trade};
 use scale_info::TypeInfo;
 use sp_runtime::traits::ConstU32;
-use sp_runtime::{BoundedVec, Permill};
+use sp_runtime::{BoundedVec, DispatchError, FixedPointNumber, FixedU128, Permill};
+use sp_std::vec;
 use sp_std::vec::Vec;
 
 pub type Balance = u128;
@@ -10,6 +11,7 @@ pub type ScheduleId = u32;
 pub type NamedReserveIdentifier = [u8; 8];
 
 const MAX_NUMBER_OF_TRADES: u32 = 5;
+const MAX_NUMBER_OF_PORTFOLIO_ORDERS: u32 = 5;
 
 /// DCA schedule containing information to execute repeating orders.
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
@@ -31,11 +33,63 @@ pub struct Schedule<AccountId, AssetId, BlockNumber> {
 	/// The slippage limit used to calculate the `min_amount_out` and `max_amount_in` trade limits.
 	pub slippage: Option<Permill>,
 	/// The order containing information to execute a specific trade by the router.
-	pub order: Order<AssetId>,
+	pub order: Order<AssetId, BlockNumber>,
+}
+
+/// The currency in which the execution fee of a DCA schedule is paid by its owner.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, Copy, Default, TypeInfo, MaxEncodedLen)]
+pub enum FeeCurrency {
+	/// The fee is taken from the reserved budget of the schedule.
+	#[default]
+	SoldAsset,
+	/// The fee is taken from the amount bought by the trade.
+	/// If no trade is executed, the fee is taken from the reserved budget of the schedule.
+	BoughtAsset,
+}
+
+/// Execution fees of a DCA schedule pre-funded by a sponsor account.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct Sponsorship<AccountId> {
+	/// The account which funds the fees.
+	pub sponsor: AccountId,
+	/// The amount in native currency reserved from the sponsor and not yet spent on fees.
+	pub remaining: Balance,
+}
+
+/// Summary of the executions of a DCA schedule.
+///
+/// It is kept for `HistoryRetentionPeriod` blocks after the schedule is completed or terminated.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, Default, TypeInfo, MaxEncodedLen)]
+pub struct ScheduleHistory<BlockNumber> {
+	/// The number of successfully executed trades.
+	pub executions: u32,
+	/// The total amount sold by the executed trades.
+	pub total_amount_in: Balance,
+	/// The total amount bought by the executed trades.
+	/// Portfolio orders buy several assets, so it is not tracked for them.
+	pub total_amount_out: Balance,
+	/// The average price of the bought asset denominated in the sold asset.
+	pub average_price: Option<FixedU128>,
+	/// The error of the last failed trade or of the termination of the schedule.
+	pub last_error: Option<DispatchError>,
+	/// The block of the last successfully executed trade.
+	pub last_execution_block: Option<BlockNumber>,
+	/// The block in which the schedule was completed or terminated.
+	pub removed_at: Option<BlockNumber>,
+}
+
+impl<BlockNumber> ScheduleHistory<BlockNumber> {
+	pub fn record_trade(&mut self, amounts: &AmountInAndOut<Balance>, block: BlockNumber) {
+		self.executions = self.executions.saturating_add(1);
+		self.total_amount_in = self.total_amount_in.saturating_add(amounts.amount_in);
+		self.total_amount_out = self.total_amount_out.saturating_add(amounts.amount_out);
+		self.average_price = FixedU128::checked_from_rational(self.total_amount_in, self.total_amount_out);
+		self.last_execution_block = Some(block);
+	}
 }
 
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
-pub enum Order<AssetId> {
+pub enum Order<AssetId, BlockNumber> {
 	Sell {
 		asset_in: AssetId,
 		asset_out: AssetId,
@@ -50,37 +104,135 @@ pub enum Order<AssetId> {
 		max_amount_in: Balance,
 		route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
 	},
+	/// Sell order executed only when the price of `asset_out` denominated in `asset_in`
+	/// is lower than or equal to `limit_price`.
+	LimitSell {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		limit_price: FixedU128,
+		/// The block number after which the order is not executed anymore.
+		expires_at: Option<BlockNumber>,
+		route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+	},
+	/// Buy order executed only when the price of `asset_out` denominated in `asset_in`
+	/// is lower than or equal to `limit_price`.
+	LimitBuy {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		limit_price: FixedU128,
+		/// The block number after which the order is not executed anymore.
+		expires_at: Option<BlockNumber>,
+		route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+	},
+	/// Sells `amount_in` of `asset_in` split among several sell orders according to their weights.
+	/// All orders are executed in the same block.
+	Portfolio {
+		asset_in: AssetId,
+		amount_in: Balance,
+		orders: BoundedVec<PortfolioOrder<AssetId>, ConstU32<MAX_NUMBER_OF_PORTFOLIO_ORDERS>>,
+	},
 }
 
-impl<AssetId> Order<AssetId>
+/// Sell order of a portfolio, selling its `weight` share of the amount sold in each execution.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct PortfolioOrder<AssetId> {
+	pub asset_out: AssetId,
+	pub weight: Permill,
+	pub min_amount_out: Balance,
+	pub route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+}
+
+impl<AssetId, BlockNumber> Order<AssetId, BlockNumber>
 where
 	AssetId: Copy,
+	BlockNumber: Copy,
 {
 	pub fn get_asset_in(&self) -> AssetId {
 		let asset_in = match &self {
 			Order::Sell { asset_in, .. } => asset_in,
 			Order::Buy { asset_in, .. } => asset_in,
+			Order::LimitSell { asset_in, .. } => asset_in,
+			Order::LimitBuy { asset_in, .. } => asset_in,
+			Order::Portfolio { asset_in, .. } => asset_in,
 		};
 		*asset_in
 	}
 
-	pub fn get_asset_out(&self) -> AssetId {
-		let asset_out = match &self {
-			Order::Sell { asset_out, .. } => asset_out,
-			Order::Buy { asset_out, .. } => asset_out,
-		};
-		*asset_out
+	/// Returns the bought asset of the order, `None` for portfolio orders which buy several assets.
+	pub fn get_asset_out(&self) -> Option<AssetId> {
+		match &self {
+			Order::Sell { asset_out, .. }
+			| Order::Buy { asset_out, .. }
+			| Order::LimitSell { asset_out, .. }
+			| Order::LimitBuy { asset_out, .. } => Some(*asset_out),
+			Order::Portfolio { .. } => None,
+		}
 	}
 
-	pub fn get_route_or_default<Provider: RouteProvider<AssetId>>(&self) -> Vec<Trade<AssetId>> {
-		let route = match &self {
-			Order::Sell { route, .. } => route,
-			Order::Buy { route, .. } => route,
-		};
-		if route.is_empty() {
-			Provider::get_route(AssetPair::new(self.get_asset_in(), self.get_asset_out()))
-		} else {
-			route.to_vec()
+	/// Returns the limit price and the expiry block of limit orders, `None` for regular orders.
+	pub fn get_limit(&self) -> Option<(FixedU128, Option<BlockNumber>)> {
+		match &self {
+			Order::LimitSell {
+				limit_price,
+				expires_at,
+				..
+			} => Some((*limit_price, *expires_at)),
+			Order::LimitBuy {
+				limit_price,
+				expires_at,
+				..
+			} => Some((*limit_price, *expires_at)),
+			_ => None,
+		}
+	}
+
+	/// Returns the routes of all trades executed in a single execution of the order.
+	///
+	/// Portfolio orders have a route for each of their orders, other orders have a single route.
+	pub fn get_routes_or_default<Provider: RouteProvider<AssetId>>(&self) -> Vec<Vec<Trade<AssetId>>> {
+		match &self {
+			Order::Sell {
+				asset_in,
+				asset_out,
+				route,
+				..
+			}
+			| Order::Buy {
+				asset_in,
+				asset_out,
+				route,
+				..
+			}
+			| Order::LimitSell {
+				asset_in,
+				asset_out,
+				route,
+				..
+			}
+			| Order::LimitBuy {
+				asset_in,
+				asset_out,
+				route,
+				..
+			} => vec![route_or_default::<AssetId, Provider>(route, *asset_in, *asset_out)],
+			Order::Portfolio { asset_in, orders, .. } => orders
+				.iter()
+				.map(|order| route_or_default::<AssetId, Provider>(&order.route, *asset_in, order.asset_out))
+				.collect(),
 		}
 	}
 }
+
+fn route_or_default<AssetId: Copy, Provider: RouteProvider<AssetId>>(
+	route: &[Trade<AssetId>],
+	asset_in: AssetId,
+	asset_out: AssetId,
+) -> Vec<Trade<AssetId>> {
+	if route.is_empty() {
+		Provider::get_route(AssetPair::new(asset_in, asset_out))
+	} else {
+		route.to_vec()
+	}
+}
@@ -53,6 +53,14 @@ pub trait WeightInfo {
 	fn on_initialize_with_empty_block() -> Weight;
 	fn schedule() -> Weight;
 	fn terminate() -> Weight;
+	fn pause() -> Weight;
+	fn resume() -> Weight;
+	fn update_schedule() -> Weight;
+	fn top_up() -> Weight;
+	fn set_fee_currency() -> Weight;
+	fn sponsor_fees() -> Weight;
+	fn withdraw_sponsorship() -> Weight;
+	fn approve_sponsor() -> Weight;
 }
 
 /// Weights for pallet_dca using the hydraDX node and recommended hardware.
@@ -71,11 +79,17 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: DCA RetriesOnError (r:0 w:1)
 	// Proof: DCA RetriesOnError (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleHistories (r:1 w:1)
+	// Proof: DCA ScheduleHistories (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	// Storage: DCA Sponsorships (r:1 w:0)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: DCA FeeCurrencies (r:1 w:0)
+	// Proof: DCA FeeCurrencies (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
 	fn on_initialize_with_buy_trade() -> Weight {
-		// Minimum execution time: 205_628 nanoseconds.
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
 		Weight::from_ref_time(207_564_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(17 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().reads(20 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: DCA ScheduleIdsPerBlock (r:12 w:2)
 	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
@@ -89,11 +103,17 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: DCA RetriesOnError (r:0 w:1)
 	// Proof: DCA RetriesOnError (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleHistories (r:1 w:1)
+	// Proof: DCA ScheduleHistories (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	// Storage: DCA Sponsorships (r:1 w:0)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: DCA FeeCurrencies (r:1 w:0)
+	// Proof: DCA FeeCurrencies (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
 	fn on_initialize_with_sell_trade() -> Weight {
-		// Minimum execution time: 205_148 nanoseconds.
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
 		Weight::from_ref_time(209_571_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(17 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().reads(20 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: DCA ScheduleIdsPerBlock (r:1 w:0)
 	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
@@ -139,15 +159,133 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: DCA ScheduleIdsPerBlock (r:1 w:1)
 	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	// Storage: DCA RetriesOnError (r:0 w:1)
 	// Proof: DCA RetriesOnError (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
 	// Storage: DCA ScheduleOwnership (r:0 w:1)
 	// Proof: DCA ScheduleOwnership (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleHistories (r:1 w:1)
+	// Proof: DCA ScheduleHistories (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	// Storage: DCA HistoryExpirations (r:0 w:1)
+	// Proof: DCA HistoryExpirations (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: DCA Sponsorships (r:1 w:1)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: DCA FeeCurrencies (r:0 w:1)
+	// Proof: DCA FeeCurrencies (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	// Storage: DCA ApprovedSponsors (r:0 w:1)
+	// Proof: DCA ApprovedSponsors (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn terminate() -> Weight {
-		// Minimum execution time: 75_390 nanoseconds.
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
 		Weight::from_ref_time(77_755_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().writes(13 as u64))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleIdsPerBlock (r:1 w:1)
+	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn pause() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(38_412_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleIdsPerBlock (r:11 w:1)
+	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn resume() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(61_870_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: DCA Schedules (r:1 w:1)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	fn update_schedule() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(27_314_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: DCA Schedules (r:1 w:1)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA RemainingAmounts (r:1 w:1)
+	// Proof: DCA RemainingAmounts (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn top_up() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(58_906_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: MultiTransactionPayment AcceptedCurrencies (r:1 w:0)
+	// Proof: MultiTransactionPayment AcceptedCurrencies (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Router Routes (r:1 w:0)
+	// Proof: Router Routes (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	// Storage: EmaOracle Oracles (r:2 w:0)
+	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	// Storage: DCA FeeCurrencies (r:0 w:1)
+	// Proof: DCA FeeCurrencies (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	fn set_fee_currency() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(25_637_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA Sponsorships (r:1 w:1)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: DCA ApprovedSponsors (r:1 w:0)
+	// Proof: DCA ApprovedSponsors (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn sponsor_fees() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(53_719_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: DCA Sponsorships (r:1 w:1)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_sponsorship() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(50_931_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA Sponsorships (r:1 w:1)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: DCA ApprovedSponsors (r:0 w:1)
+	// Proof: DCA ApprovedSponsors (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn approve_sponsor() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(56_394_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 }
 
@@ -165,11 +303,17 @@ impl WeightInfo for () {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: DCA RetriesOnError (r:0 w:1)
 	// Proof: DCA RetriesOnError (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleHistories (r:1 w:1)
+	// Proof: DCA ScheduleHistories (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	// Storage: DCA Sponsorships (r:1 w:0)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: DCA FeeCurrencies (r:1 w:0)
+	// Proof: DCA FeeCurrencies (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
 	fn on_initialize_with_buy_trade() -> Weight {
-		// Minimum execution time: 205_628 nanoseconds.
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
 		Weight::from_ref_time(207_564_000)
-			.saturating_add(RocksDbWeight::get().reads(17))
-			.saturating_add(RocksDbWeight::get().writes(7))
+			.saturating_add(RocksDbWeight::get().reads(20))
+			.saturating_add(RocksDbWeight::get().writes(8))
 	}
 	// Storage: DCA ScheduleIdsPerBlock (r:12 w:2)
 	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
@@ -183,11 +327,17 @@ impl WeightInfo for () {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: DCA RetriesOnError (r:0 w:1)
 	// Proof: DCA RetriesOnError (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleHistories (r:1 w:1)
+	// Proof: DCA ScheduleHistories (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	// Storage: DCA Sponsorships (r:1 w:0)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: DCA FeeCurrencies (r:1 w:0)
+	// Proof: DCA FeeCurrencies (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
 	fn on_initialize_with_sell_trade() -> Weight {
-		// Minimum execution time: 205_148 nanoseconds.
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
 		Weight::from_ref_time(209_571_000)
-			.saturating_add(RocksDbWeight::get().reads(17))
-			.saturating_add(RocksDbWeight::get().writes(7))
+			.saturating_add(RocksDbWeight::get().reads(20))
+			.saturating_add(RocksDbWeight::get().writes(8))
 	}
 	// Storage: DCA ScheduleIdsPerBlock (r:1 w:0)
 	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
@@ -233,14 +383,132 @@ impl WeightInfo for () {
 	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
 	// Storage: DCA ScheduleIdsPerBlock (r:1 w:1)
 	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
 	// Storage: DCA RetriesOnError (r:0 w:1)
 	// Proof: DCA RetriesOnError (max_values: None, max_size: Some(21), added: 2496, mode: MaxEncodedLen)
 	// Storage: DCA ScheduleOwnership (r:0 w:1)
 	// Proof: DCA ScheduleOwnership (max_values: None, max_size: Some(60), added: 2535, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleHistories (r:1 w:1)
+	// Proof: DCA ScheduleHistories (max_values: None, max_size: Some(92), added: 2567, mode: MaxEncodedLen)
+	// Storage: DCA HistoryExpirations (r:0 w:1)
+	// Proof: DCA HistoryExpirations (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: DCA Sponsorships (r:1 w:1)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: DCA FeeCurrencies (r:0 w:1)
+	// Proof: DCA FeeCurrencies (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	// Storage: DCA ApprovedSponsors (r:0 w:1)
+	// Proof: DCA ApprovedSponsors (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	fn terminate() -> Weight {
-		// Minimum execution time: 75_390 nanoseconds.
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
 		Weight::from_ref_time(77_755_000)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(13))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleIdsPerBlock (r:1 w:1)
+	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn pause() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(38_412_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA PausedSchedules (r:1 w:1)
+	// Proof: DCA PausedSchedules (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	// Storage: DCA ScheduleIdsPerBlock (r:11 w:1)
+	// Proof: DCA ScheduleIdsPerBlock (max_values: None, max_size: Some(101), added: 2576, mode: MaxEncodedLen)
+	fn resume() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(61_870_000)
+			.saturating_add(RocksDbWeight::get().reads(13))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: DCA Schedules (r:1 w:1)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	fn update_schedule() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(27_314_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: DCA Schedules (r:1 w:1)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA RemainingAmounts (r:1 w:1)
+	// Proof: DCA RemainingAmounts (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn top_up() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(58_906_000)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: MultiTransactionPayment AcceptedCurrencies (r:1 w:0)
+	// Proof: MultiTransactionPayment AcceptedCurrencies (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Router Routes (r:1 w:0)
+	// Proof: Router Routes (max_values: None, max_size: Some(90), added: 2565, mode: MaxEncodedLen)
+	// Storage: EmaOracle Oracles (r:2 w:0)
+	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	// Storage: DCA FeeCurrencies (r:0 w:1)
+	// Proof: DCA FeeCurrencies (max_values: None, max_size: Some(17), added: 2492, mode: MaxEncodedLen)
+	fn set_fee_currency() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(25_637_000)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA Sponsorships (r:1 w:1)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: DCA ApprovedSponsors (r:1 w:0)
+	// Proof: DCA ApprovedSponsors (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn sponsor_fees() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(53_719_000)
 			.saturating_add(RocksDbWeight::get().reads(5))
-			.saturating_add(RocksDbWeight::get().writes(7))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	// Storage: DCA Sponsorships (r:1 w:1)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn withdraw_sponsorship() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(50_931_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	// Storage: DCA Schedules (r:1 w:0)
+	// Proof: DCA Schedules (max_values: None, max_size: Some(191), added: 2666, mode: MaxEncodedLen)
+	// Storage: DCA Sponsorships (r:1 w:1)
+	// Proof: DCA Sponsorships (max_values: None, max_size: Some(68), added: 2543, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: DCA ApprovedSponsors (r:0 w:1)
+	// Proof: DCA ApprovedSponsors (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn approve_sponsor() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(56_394_000)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
 	}
 }
@@ -0,0 +1,57 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::benchmarks_instance_pallet;
+use frame_system::RawOrigin;
+use sp_runtime::traits::One;
+use sp_runtime::FixedU128;
+
+fn fee_params<T: Config<I>, I: 'static>() -> FeeParams<T::Fee> {
+	FeeParams {
+		min_fee: T::Fee::from_percent(1),
+		max_fee: T::Fee::from_percent(10),
+		decay: FixedU128::from_rational(5, 1_000_000),
+		amplification: FixedU128::one(),
+	}
+}
+
+benchmarks_instance_pallet! {
+	where_clause { where T::AssetId: From<u32> }
+
+	set_asset_fee_params {
+		let asset_id: T::AssetId = 1u32.into();
+		let params = fee_params::<T, I>();
+	}: _(RawOrigin::Root, asset_id, params, params)
+	verify {
+		assert!(AssetFeeParameters::<T, I>::contains_key(asset_id));
+	}
+
+	remove_asset_fee_params {
+		let asset_id: T::AssetId = 1u32.into();
+		let params = fee_params::<T, I>();
+		crate::Pallet::<T, I>::set_asset_fee_params(RawOrigin::Root.into(), asset_id, params, params)?;
+	}: _(RawOrigin::Root, asset_id)
+	verify {
+		assert!(!AssetFeeParameters::<T, I>::contains_key(asset_id));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -25,6 +25,9 @@
 //!
 //! and integrate provided [`UpdateAndRetrieveFees`]().
 //!
+//! The pallet is instantiable, so fees can be tracked separately for different kind of keys - e.g. assets of Omnipool
+//! and pools of XYK or Stableswap - each instance with its own oracle and fee parameters.
+//!
 //! ### Terminology
 //!
 //! * **Fee:** The type representing a fee. Must implement PerThing.
@@ -40,6 +43,9 @@
 //! The module stores last calculated fees as tuple of `(Fee, Fee, Block number)` where the first item is asset fee,
 //! the second one is protocol fee and the third one is block number indicating when the two fees were updated.
 //!
+//! Fee parameters of an asset can be overridden by `AuthorityOrigin`. Parameters provided as runtime constants
+//! are used for assets without an override.
+//!
 //! ## Interface
 //!
 //! ### Update and retrieve fee
@@ -49,6 +55,14 @@
 //!
 //! On first retrieve call in a block, the asset fee as well as the protocol are updated and new fees are returned.
 //!
+//! `RetrieveFees` returns the same fees as `UpdateAndRetrieveFees` would, but does not update the storage. It can be
+//! used where state must not be modified, e.g. to quote trades.
+//!
+//! ### Dispatchable functions
+//!
+//! * `set_asset_fee_params` - sets fee parameters override of an asset.
+//! * `remove_asset_fee_params` - removes fee parameters override of an asset.
+//!
 //! ### Prerequisites
 //!
 //! An oracle which provides volume in and out of an asset and liquidity.
@@ -57,18 +71,22 @@
 
 use frame_support::traits::Get;
 use orml_traits::GetByKey;
-use sp_runtime::traits::{BlockNumberProvider, Saturating};
+use sp_runtime::traits::{BlockNumberProvider, Saturating, Zero};
 use sp_runtime::{FixedPointOperand, PerThing, SaturatedConversion};
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarks;
 #[cfg(test)]
 mod tests;
 pub mod traits;
 pub mod types;
+pub mod weights;
 
 pub use pallet::*;
 
 use crate::traits::{Volume, VolumeProvider};
-use crate::types::{FeeEntry, FeeParams};
+use crate::types::{AssetFeeParams, FeeEntry, FeeParams};
+use crate::weights::WeightInfo;
 use hydra_dx_math::dynamic_fees::types::OracleEntry;
 use hydra_dx_math::dynamic_fees::{recalculate_asset_fee, recalculate_protocol_fee};
 
@@ -80,23 +98,30 @@ pub mod pallet {
 	use crate::traits::VolumeProvider;
 	use crate::types::FeeEntry;
 	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::BlockNumberFor;
+	use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
 	use sp_runtime::traits::{BlockNumberProvider, Zero};
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
-	pub struct Pallet<T>(_);
+	pub struct Pallet<T, I = ()>(_);
 
 	#[pallet::storage]
 	#[pallet::getter(fn current_fees)]
 	/// Stores last calculated fee of an asset and block number in which it was changed..
 	/// Stored as (Asset fee, Protocol fee, Block number)
-	pub type AssetFee<T: Config> =
+	pub type AssetFee<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Twox64Concat, T::AssetId, FeeEntry<T::Fee, T::BlockNumber>, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn asset_fee_params)]
+	/// Fee parameters override of an asset.
+	/// `AssetFeeParameters` and `ProtocolFeeParameters` are used if not set.
+	pub type AssetFeeParameters<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AssetId, AssetFeeParams<T::Fee>, OptionQuery>;
+
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
-		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		type RuntimeEvent: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Provider for the current block number.
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
@@ -104,7 +129,7 @@ pub mod pallet {
 		/// Fee PerThing type
 		type Fee: Parameter + MaybeSerializeDeserialize + MaxEncodedLen + PerThing;
 
-		/// Asset id type
+		/// Asset id type. Identifies a pool when fees are tracked per pool.
 		type AssetId: Parameter + Member + Copy + MaybeSerializeDeserialize + MaxEncodedLen;
 
 		/// Volume provider implementation
@@ -115,19 +140,116 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type ProtocolFeeParameters: Get<FeeParams<Self::Fee>>;
+
+		/// Origin which can set fee parameters of an asset.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::event]
-	pub enum Event<T: Config> {}
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// Fees of an asset have been recalculated.
+		FeeUpdated {
+			asset_id: T::AssetId,
+			asset_fee: T::Fee,
+			protocol_fee: T::Fee,
+		},
+		/// Fee parameters of an asset have been set.
+		AssetFeeParamsSet {
+			asset_id: T::AssetId,
+			asset_fee_params: FeeParams<T::Fee>,
+			protocol_fee_params: FeeParams<T::Fee>,
+		},
+		/// Fee parameters of an asset have been removed.
+		AssetFeeParamsRemoved { asset_id: T::AssetId },
+	}
 
 	#[pallet::error]
-	pub enum Error<T> {}
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T, I = ()> {
+		/// Minimum fee is greater than maximum fee or amplification is zero.
+		InvalidFeeParams,
+		/// Fee parameters of an asset are not set.
+		AssetFeeParamsNotFound,
+	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Set fee parameters of an asset.
+		///
+		/// Fee parameters override `AssetFeeParameters` and `ProtocolFeeParameters` for given asset.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_id`: asset id
+		/// - `asset_fee_params`: asset fee parameters
+		/// - `protocol_fee_params`: protocol fee parameters
+		///
+		/// Emits `AssetFeeParamsSet` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_asset_fee_params())]
+		pub fn set_asset_fee_params(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			asset_fee_params: FeeParams<T::Fee>,
+			protocol_fee_params: FeeParams<T::Fee>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Self::is_valid(&asset_fee_params) && Self::is_valid(&protocol_fee_params),
+				Error::<T, I>::InvalidFeeParams
+			);
+
+			AssetFeeParameters::<T, I>::insert(
+				asset_id,
+				AssetFeeParams {
+					asset_fee: asset_fee_params,
+					protocol_fee: protocol_fee_params,
+				},
+			);
+
+			Self::deposit_event(Event::AssetFeeParamsSet {
+				asset_id,
+				asset_fee_params,
+				protocol_fee_params,
+			});
+
+			Ok(())
+		}
+
+		/// Remove fee parameters of an asset.
+		///
+		/// `AssetFeeParameters` and `ProtocolFeeParameters` are used for the asset afterwards.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_id`: asset id
+		///
+		/// Emits `AssetFeeParamsRemoved` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::remove_asset_fee_params())]
+		pub fn remove_asset_fee_params(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				AssetFeeParameters::<T, I>::contains_key(asset_id),
+				Error::<T, I>::AssetFeeParamsNotFound
+			);
+
+			AssetFeeParameters::<T, I>::remove(asset_id);
+
+			Self::deposit_event(Event::AssetFeeParamsRemoved { asset_id });
+
+			Ok(())
+		}
+	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
 		fn integrity_test() {
 			let asset_fee_params = T::AssetFeeParameters::get();
 			let protocol_fee_params = T::ProtocolFeeParameters::get();
@@ -151,15 +273,53 @@ pub mod pallet {
 	}
 }
 
-impl<T: Config> Pallet<T>
+impl<T: Config<I>, I: 'static> Pallet<T, I>
 where
 	<T::Fee as PerThing>::Inner: FixedPointOperand,
 {
 	fn update_fee(asset_id: T::AssetId) -> (T::Fee, T::Fee) {
+		let (current_fee_entry, maybe_fee_entry) = Self::recalculate_fees(asset_id);
+
+		let Some(fee_entry) = maybe_fee_entry else {
+			return (current_fee_entry.asset_fee, current_fee_entry.protocol_fee);
+		};
+
+		AssetFee::<T, I>::insert(asset_id, fee_entry);
+
+		if fee_entry.asset_fee != current_fee_entry.asset_fee
+			|| fee_entry.protocol_fee != current_fee_entry.protocol_fee
+		{
+			Self::deposit_event(Event::FeeUpdated {
+				asset_id,
+				asset_fee: fee_entry.asset_fee,
+				protocol_fee: fee_entry.protocol_fee,
+			});
+		}
+
+		(fee_entry.asset_fee, fee_entry.protocol_fee)
+	}
+
+	/// Returns fees of an asset in the current block without updating the storage.
+	fn retrieve_fee(asset_id: T::AssetId) -> (T::Fee, T::Fee) {
+		let (current_fee_entry, maybe_fee_entry) = Self::recalculate_fees(asset_id);
+		let fee_entry = maybe_fee_entry.unwrap_or(current_fee_entry);
+
+		(fee_entry.asset_fee, fee_entry.protocol_fee)
+	}
+
+	/// Returns last stored fee entry of an asset and fee entry recalculated for the current block.
+	/// Recalculated entry is `None` if fees were already updated in the current block or oracle
+	/// data are not available.
+	#[allow(clippy::type_complexity)]
+	fn recalculate_fees(
+		asset_id: T::AssetId,
+	) -> (
+		FeeEntry<T::Fee, T::BlockNumber>,
+		Option<FeeEntry<T::Fee, T::BlockNumber>>,
+	) {
 		let block_number = T::BlockNumberProvider::current_block_number();
 
-		let asset_fee_params = T::AssetFeeParameters::get();
-		let protocol_fee_params = T::ProtocolFeeParameters::get();
+		let (asset_fee_params, protocol_fee_params) = Self::fee_params(asset_id);
 
 		let current_fee_entry = Self::current_fees(asset_id).unwrap_or(FeeEntry {
 			asset_fee: asset_fee_params.min_fee,
@@ -169,7 +329,7 @@ where
 
 		// Update only if it has not yet been updated this block
 		if block_number == current_fee_entry.timestamp {
-			return (current_fee_entry.asset_fee, current_fee_entry.protocol_fee);
+			return (current_fee_entry, None);
 		}
 
 		let delta_blocks: u128 = block_number
@@ -177,11 +337,11 @@ where
 			.saturated_into();
 
 		let Some(volume) = T::Oracle::asset_volume(asset_id) else {
-            return (current_fee_entry.asset_fee, current_fee_entry.protocol_fee);
-        };
+			return (current_fee_entry, None);
+		};
 		let Some(liquidity) = T::Oracle::asset_liquidity(asset_id) else {
-            return (current_fee_entry.asset_fee, current_fee_entry.protocol_fee);
-        };
+			return (current_fee_entry, None);
+		};
 
 		let asset_fee = recalculate_asset_fee(
 			OracleEntry {
@@ -204,25 +364,51 @@ where
 			protocol_fee_params.into(),
 		);
 
-		AssetFee::<T>::insert(
-			asset_id,
-			FeeEntry {
+		(
+			current_fee_entry,
+			Some(FeeEntry {
 				asset_fee,
 				protocol_fee,
 				timestamp: block_number,
-			},
-		);
-		(asset_fee, protocol_fee)
+			}),
+		)
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Returns asset and protocol fee parameters of an asset.
+	/// Parameters override is used if set, runtime constants otherwise.
+	pub fn fee_params(asset_id: T::AssetId) -> (FeeParams<T::Fee>, FeeParams<T::Fee>) {
+		match Self::asset_fee_params(asset_id) {
+			Some(params) => (params.asset_fee, params.protocol_fee),
+			None => (T::AssetFeeParameters::get(), T::ProtocolFeeParameters::get()),
+		}
+	}
+
+	fn is_valid(params: &FeeParams<T::Fee>) -> bool {
+		params.min_fee <= params.max_fee && !params.amplification.is_zero()
+	}
+}
+
+pub struct UpdateAndRetrieveFees<T: Config<I>, I: 'static = ()>(sp_std::marker::PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> GetByKey<T::AssetId, (T::Fee, T::Fee)> for UpdateAndRetrieveFees<T, I>
+where
+	<T::Fee as PerThing>::Inner: FixedPointOperand,
+{
+	fn get(k: &T::AssetId) -> (T::Fee, T::Fee) {
+		Pallet::<T, I>::update_fee(*k)
 	}
 }
 
-pub struct UpdateAndRetrieveFees<T: Config>(sp_std::marker::PhantomData<T>);
+/// Retrieves fees of the current block without updating the storage, e.g. to quote trades.
+pub struct RetrieveFees<T: Config<I>, I: 'static = ()>(sp_std::marker::PhantomData<(T, I)>);
 
-impl<T: Config> GetByKey<T::AssetId, (T::Fee, T::Fee)> for UpdateAndRetrieveFees<T>
+impl<T: Config<I>, I: 'static> GetByKey<T::AssetId, (T::Fee, T::Fee)> for RetrieveFees<T, I>
 where
 	<T::Fee as PerThing>::Inner: FixedPointOperand,
 {
 	fn get(k: &T::AssetId) -> (T::Fee, T::Fee) {
-		Pallet::<T>::update_fee(*k)
+		Pallet::<T, I>::retrieve_fee(*k)
 	}
 }
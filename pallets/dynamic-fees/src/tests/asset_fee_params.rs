@@ -0,0 +1,193 @@
+use crate::tests::mock::*;
+use crate::tests::oracle::SingleValueOracle;
+use crate::types::FeeParams;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use sp_runtime::traits::{One, Zero};
+use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::FixedU128;
+
+fn asset_params() -> FeeParams<Fee> {
+	FeeParams {
+		min_fee: Fee::from_percent(5),
+		max_fee: Fee::from_percent(30),
+		decay: FixedU128::zero(),
+		amplification: FixedU128::one(),
+	}
+}
+
+fn protocol_params() -> FeeParams<Fee> {
+	FeeParams {
+		min_fee: Fee::from_percent(2),
+		max_fee: Fee::from_percent(10),
+		decay: FixedU128::zero(),
+		amplification: FixedU128::one(),
+	}
+}
+
+#[test]
+fn set_asset_fee_params_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(DynamicFees::set_asset_fee_params(
+			RuntimeOrigin::root(),
+			HDX,
+			asset_params(),
+			protocol_params()
+		));
+
+		assert_eq!(
+			DynamicFees::asset_fee_params(HDX),
+			Some(crate::types::AssetFeeParams {
+				asset_fee: asset_params(),
+				protocol_fee: protocol_params(),
+			})
+		);
+		assert_eq!(DynamicFees::fee_params(HDX), (asset_params(), protocol_params()));
+
+		System::assert_last_event(
+			Event::AssetFeeParamsSet {
+				asset_id: HDX,
+				asset_fee_params: asset_params(),
+				protocol_fee_params: protocol_params(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_params_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DynamicFees::set_asset_fee_params(RuntimeOrigin::signed(1), HDX, asset_params(), protocol_params()),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_params_should_fail_when_min_fee_is_greater_than_max_fee() {
+	ExtBuilder::default().build().execute_with(|| {
+		let invalid = FeeParams {
+			min_fee: Fee::from_percent(20),
+			max_fee: Fee::from_percent(10),
+			..asset_params()
+		};
+
+		assert_noop!(
+			DynamicFees::set_asset_fee_params(RuntimeOrigin::root(), HDX, invalid, protocol_params()),
+			Error::<Test>::InvalidFeeParams
+		);
+		assert_noop!(
+			DynamicFees::set_asset_fee_params(RuntimeOrigin::root(), HDX, asset_params(), invalid),
+			Error::<Test>::InvalidFeeParams
+		);
+	});
+}
+
+#[test]
+fn set_asset_fee_params_should_fail_when_amplification_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		let invalid = FeeParams {
+			amplification: FixedU128::zero(),
+			..asset_params()
+		};
+
+		assert_noop!(
+			DynamicFees::set_asset_fee_params(RuntimeOrigin::root(), HDX, invalid, protocol_params()),
+			Error::<Test>::InvalidFeeParams
+		);
+	});
+}
+
+#[test]
+fn remove_asset_fee_params_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(DynamicFees::set_asset_fee_params(
+			RuntimeOrigin::root(),
+			HDX,
+			asset_params(),
+			protocol_params()
+		));
+
+		assert_ok!(DynamicFees::remove_asset_fee_params(RuntimeOrigin::root(), HDX));
+
+		assert_eq!(DynamicFees::asset_fee_params(HDX), None);
+		assert_eq!(
+			DynamicFees::fee_params(HDX),
+			(AssetFeeParams::get(), ProtocolFeeParams::get())
+		);
+
+		System::assert_last_event(Event::AssetFeeParamsRemoved { asset_id: HDX }.into());
+	});
+}
+
+#[test]
+fn remove_asset_fee_params_should_fail_when_not_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DynamicFees::remove_asset_fee_params(RuntimeOrigin::root(), HDX),
+			Error::<Test>::AssetFeeParamsNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_asset_fee_params_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			DynamicFees::remove_asset_fee_params(RuntimeOrigin::signed(1), HDX),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn fee_should_be_within_asset_fee_params_override() {
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, 2 * ONE, 50 * ONE))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(DynamicFees::set_asset_fee_params(
+				RuntimeOrigin::root(),
+				HDX,
+				asset_params(),
+				protocol_params()
+			));
+
+			let fee = retrieve_fee_entry(HDX);
+
+			assert!(fee.0 >= Fee::from_percent(5) && fee.0 <= Fee::from_percent(30));
+			assert!(fee.1 >= Fee::from_percent(2) && fee.1 <= Fee::from_percent(10));
+		});
+}
+
+#[test]
+fn fee_updated_event_should_be_emitted_when_fee_changes() {
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, 2 * ONE, 50 * ONE))
+		.with_initial_fees(Fee::from_percent(2), Fee::zero(), 0)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let fee = retrieve_fee_entry(HDX);
+
+			assert_ne!(fee.0, Fee::from_percent(2));
+
+			System::assert_last_event(
+				Event::FeeUpdated {
+					asset_id: HDX,
+					asset_fee: fee.0,
+					protocol_fee: fee.1,
+				}
+				.into(),
+			);
+		});
+}
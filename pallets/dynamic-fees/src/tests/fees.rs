@@ -299,3 +299,32 @@ fn fees_should_be_recalculated_correctly_for_last_block_change_when_nothing_in_s
 			assert_eq!(protocol_fee, Fee::from_percent(2));
 		});
 }
+
+#[test]
+fn retrieve_fees_should_return_recalculated_fees_without_updating_storage() {
+	let initial_fee = Fee::from_percent(2);
+
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, 2 * ONE, 50 * ONE))
+		.with_initial_fees(initial_fee, Fee::zero(), 0)
+		.with_asset_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let fee = retrieve_fee_entry_without_update(HDX);
+
+			assert_eq!(fee.0, Fee::from_percent(4));
+			let stored_fee = DynamicFees::current_fees(HDX).unwrap();
+			assert_eq!(stored_fee.asset_fee, initial_fee);
+			assert_eq!(stored_fee.timestamp, 0);
+			assert!(System::events().is_empty());
+
+			assert_eq!(retrieve_fee_entry(HDX), fee);
+		});
+}
@@ -20,12 +20,13 @@
 use sp_std::prelude::*;
 use std::cell::RefCell;
 
-use crate::{Config, UpdateAndRetrieveFees, Volume, VolumeProvider};
+use crate::{Config, RetrieveFees, UpdateAndRetrieveFees, Volume, VolumeProvider};
 
 use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
+use frame_system::EnsureRoot;
 use orml_traits::GetByKey;
 pub use orml_traits::MultiCurrency;
 use sp_core::H256;
@@ -118,6 +119,8 @@ impl Config for Test {
 	type Oracle = OracleProvider;
 	type AssetFeeParameters = AssetFeeParams;
 	type ProtocolFeeParameters = ProtocolFeeParams;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
 }
 
 pub struct ExtBuilder {
@@ -252,6 +255,10 @@ pub(crate) fn retrieve_fee_entry(asset_id: AssetId) -> (Fee, Fee) {
 	<UpdateAndRetrieveFees<Test> as GetByKey<AssetId, (Fee, Fee)>>::get(&asset_id)
 }
 
+pub(crate) fn retrieve_fee_entry_without_update(asset_id: AssetId) -> (Fee, Fee) {
+	<RetrieveFees<Test> as GetByKey<AssetId, (Fee, Fee)>>::get(&asset_id)
+}
+
 pub(crate) fn get_oracle_entry(asset_id: AssetId, block_number: u64) -> AssetVolume {
 	ORACLE.with(|v| v.borrow().volume(asset_id, block_number as usize))
 }
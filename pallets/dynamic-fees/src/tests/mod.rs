@@ -1,3 +1,4 @@
+mod asset_fee_params;
 mod decay;
 mod fees;
 mod limits;
@@ -7,7 +7,7 @@ use hydra_dx_math::dynamic_fees::types::FeeParams as MathFeeParams;
 
 use scale_info::TypeInfo;
 
-#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct FeeParams<Fee> {
 	pub min_fee: Fee,
@@ -16,6 +16,14 @@ pub struct FeeParams<Fee> {
 	pub amplification: FixedU128,
 }
 
+/// Fee parameters override of an asset.
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct AssetFeeParams<Fee> {
+	pub asset_fee: FeeParams<Fee>,
+	pub protocol_fee: FeeParams<Fee>,
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct FeeEntry<Fee, Block> {
@@ -0,0 +1,165 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for querying the EMA oracle.
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+pub use pallet_ema_oracle_runtime_api::EmaOracleApi as EmaOracleRuntimeApi;
+use pallet_ema_oracle_runtime_api::{AggregatedEntry, OraclePeriod, Source};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+#[rpc(client, server)]
+pub trait EmaOracleApi<BlockHash, AssetId, Balance, BlockNumber, Price> {
+	/// Returns the oracle entry of `assets.0` denominated in `assets.1` aggregated over `period`.
+	#[method(name = "emaOracle_getEntry")]
+	fn get_entry(
+		&self,
+		source: Source,
+		assets: (AssetId, AssetId),
+		period: OraclePeriod,
+		at: Option<BlockHash>,
+	) -> RpcResult<AggregatedEntry<Balance, BlockNumber, Price>>;
+
+	/// Returns the time-weighted average price of `assets.0` denominated in `assets.1` between
+	/// `from_block` and `to_block`.
+	#[method(name = "emaOracle_getTwap")]
+	fn get_twap(
+		&self,
+		source: Source,
+		assets: (AssetId, AssetId),
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Price>;
+
+	/// Returns all combinations of source and asset pair that have an oracle stored.
+	#[method(name = "emaOracle_trackedPairs")]
+	fn tracked_pairs(&self, at: Option<BlockHash>) -> RpcResult<Vec<(Source, (AssetId, AssetId))>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The oracle value could not be provided.
+	OracleError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::OracleError => 2,
+		}
+	}
+}
+
+/// Provides RPC methods to query the EMA oracle.
+pub struct EmaOracle<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> EmaOracle<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl ToString) -> CallError {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		message,
+		Some(e.to_string()),
+	))
+}
+
+fn oracle_error(e: impl Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::OracleError.into(),
+		"Oracle value not available.",
+		Some(format!("{e:?}")),
+	))
+	.into()
+}
+
+impl<C, Block, AssetId, Balance, BlockNumber, Price, OracleError>
+	EmaOracleApiServer<<Block as BlockT>::Hash, AssetId, Balance, BlockNumber, Price> for EmaOracle<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: EmaOracleRuntimeApi<Block, AssetId, Balance, BlockNumber, Price, OracleError>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Price: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	OracleError: Codec + Debug,
+{
+	fn get_entry(
+		&self,
+		source: Source,
+		assets: (AssetId, AssetId),
+		period: OraclePeriod,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<AggregatedEntry<Balance, BlockNumber, Price>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_entry(at, source, assets, period)
+			.map_err(|e| runtime_error("Unable to query oracle entry.", e))?
+			.map_err(oracle_error)
+	}
+
+	fn get_twap(
+		&self,
+		source: Source,
+		assets: (AssetId, AssetId),
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Price> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_twap(at, source, assets, from_block, to_block)
+			.map_err(|e| runtime_error("Unable to query time-weighted average price.", e))?
+			.map_err(oracle_error)
+	}
+
+	fn tracked_pairs(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<(Source, (AssetId, AssetId))>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.tracked_pairs(at)
+			.map_err(|e| runtime_error("Unable to query tracked oracle pairs.", e).into())
+	}
+}
@@ -0,0 +1,57 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for querying the EMA oracle.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use hydradx_traits::{AggregatedEntry, OraclePeriod, Source};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query prices tracked by the EMA oracle.
+	pub trait EmaOracleApi<AssetId, Balance, BlockNumber, Price, Error> where
+		AssetId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+		Price: Codec,
+		Error: Codec,
+	{
+		/// Returns the time-weighted average price of `assets.0` denominated in `assets.1` between
+		/// `from_block` and `to_block`.
+		///
+		/// Returns the price at `from_block` if `from_block` equals `to_block`.
+		fn get_twap(
+			source: Source,
+			assets: (AssetId, AssetId),
+			from_block: BlockNumber,
+			to_block: BlockNumber,
+		) -> Result<Price, Error>;
+
+		/// Returns the oracle entry of `assets.0` denominated in `assets.1` aggregated over `period`,
+		/// updated to the parent block.
+		fn get_entry(
+			source: Source,
+			assets: (AssetId, AssetId),
+			period: OraclePeriod,
+		) -> Result<AggregatedEntry<Balance, BlockNumber, Price>, Error>;
+
+		/// Returns all combinations of source and asset pair that have an oracle stored.
+		fn tracked_pairs() -> Vec<(Source, (AssetId, AssetId))>;
+	}
+}
@@ -24,14 +24,32 @@ pub const DOT: AssetId = 2_000;
 
 use frame_benchmarking::benchmarks;
 use frame_support::{assert_ok, traits::Hooks};
+use frame_system::RawOrigin;
 
 #[cfg(test)]
 use pretty_assertions::assert_eq;
 
 use crate::Pallet as EmaOracle;
 
-/// Default oracle source.
-const SOURCE: Source = *b"dummysrc";
+/// Oracle source which is not whitelisted by `OracleWhitelist`.
+/// Its pairs are whitelisted in `WhitelistedAssets`, so tracking them includes reading that storage.
+const SOURCE: Source = *b"benchsrc";
+
+/// Whitelist `(SOURCE, (i * 1_000, i * 1_000 + 500))` for `i` in `0..=n` so that their data is tracked.
+fn whitelist_pairs<T: Config>(n: u32) {
+	WhitelistedAssets::<T>::mutate(|list| {
+		for i in 0..=n {
+			let asset_a = i * 1_000;
+			let asset_b = asset_a + 500;
+			assert_ok!(list.try_insert((SOURCE, ordered_pair(asset_a, asset_b))));
+		}
+	});
+}
+
+/// Whitelist `(SOURCE, (HDX, DOT))` so that its data is tracked.
+fn whitelist_hdx_dot<T: Config>() {
+	WhitelistedAssets::<T>::mutate(|list| assert_ok!(list.try_insert((SOURCE, ordered_pair(HDX, DOT)))));
+}
 
 benchmarks! {
 	on_finalize_no_entry {
@@ -45,6 +63,8 @@ benchmarks! {
 		let block_num: T::BlockNumber = 5u32.into();
 		let prev_block = block_num.saturating_sub(One::one());
 
+		whitelist_hdx_dot::<T>();
+
 		frame_system::Pallet::<T>::set_block_number(prev_block);
 		EmaOracle::<T>::on_initialize(prev_block);
 		EmaOracle::<T>::on_finalize(prev_block);
@@ -78,6 +98,8 @@ benchmarks! {
 		// higher update time difference might make exponentiation more expensive
 		let block_num = initial_data_block.saturating_add(1_000_000u32.into());
 
+		whitelist_hdx_dot::<T>();
+
 		frame_system::Pallet::<T>::set_block_number(initial_data_block);
 		EmaOracle::<T>::on_initialize(initial_data_block);
 		let (amount_in, amount_out) = (1_000_000_000_000, 2_000_000_000_000);
@@ -111,6 +133,8 @@ benchmarks! {
 	on_finalize_multiple_tokens {
 		let b in 1 .. (T::MaxUniqueEntries::get() - 1);
 
+		whitelist_pairs::<T>(b);
+
 		let initial_data_block: T::BlockNumber = 5u32.into();
 		let block_num = initial_data_block.saturating_add(1_000_000u32.into());
 
@@ -155,6 +179,8 @@ benchmarks! {
 	on_trade_multiple_tokens {
 		let b in 1 .. (T::MaxUniqueEntries::get() - 1);
 
+		whitelist_pairs::<T>(b);
+
 		let initial_data_block: T::BlockNumber = 5u32.into();
 		let block_num = initial_data_block.saturating_add(1_000_000u32.into());
 
@@ -211,6 +237,8 @@ benchmarks! {
 	on_liquidity_changed_multiple_tokens {
 		let b in 1 .. (T::MaxUniqueEntries::get() - 1);
 
+		whitelist_pairs::<T>(b);
+
 		let initial_data_block: T::BlockNumber = 5u32.into();
 		let block_num = initial_data_block.saturating_add(1_000_000u32.into());
 
@@ -275,6 +303,8 @@ benchmarks! {
 		let oracle_age: T::BlockNumber = 999_999u32.into();
 		let block_num = initial_data_block.saturating_add(oracle_age.saturating_add(One::one()));
 
+		whitelist_pairs::<T>(1);
+
 		frame_system::Pallet::<T>::set_block_number(initial_data_block);
 		EmaOracle::<T>::on_initialize(initial_data_block);
 		let (amount_in, amount_out) = (1_000_000_000_000, 2_000_000_000_000);
@@ -304,5 +334,46 @@ benchmarks! {
 		}));
 	}
 
+	add_oracle {
+		let max_entries = T::MaxUniqueEntries::get();
+		whitelist_pairs::<T>(max_entries - 2);
+
+		let asset_a = max_entries * 1_000;
+		let asset_b = asset_a + 500;
+	}: _(RawOrigin::Root, SOURCE, (asset_a, asset_b))
+	verify {
+		assert!(WhitelistedAssets::<T>::get().contains(&(SOURCE, (asset_a, asset_b))));
+	}
+
+	remove_oracle {
+		let max_entries = T::MaxUniqueEntries::get();
+		whitelist_pairs::<T>(max_entries - 1);
+
+		let block_num: T::BlockNumber = 5u32.into();
+		frame_system::Pallet::<T>::set_block_number(block_num);
+		EmaOracle::<T>::on_initialize(block_num);
+		let (amount_in, amount_out) = (1_000_000_000_000, 2_000_000_000_000);
+		let (liquidity_asset_in, liquidity_asset_out) = (1_000_000_000_000_000, 2_000_000_000_000_000);
+		let (asset_a, asset_b) = (1_000, 1_500);
+		assert_ok!(OnActivityHandler::<T>::on_trade(
+			SOURCE, asset_a, asset_b, amount_in, amount_out, liquidity_asset_in, liquidity_asset_out,
+			Price::new(liquidity_asset_in, liquidity_asset_out)));
+		EmaOracle::<T>::on_finalize(block_num);
+		assert!(Oracles::<T>::get((SOURCE, (asset_a, asset_b), LastBlock)).is_some());
+
+		// fill the ring buffer of price snapshots
+		let max_snapshots = T::MaxPriceSnapshots::get();
+		let snapshot = PriceSnapshots::<T>::get((SOURCE, (asset_a, asset_b), 0)).unwrap();
+		for i in 1..max_snapshots {
+			PriceSnapshots::<T>::insert((SOURCE, (asset_a, asset_b), i), snapshot.clone());
+		}
+		SnapshotCursor::<T>::insert(SOURCE, (asset_a, asset_b), (max_snapshots - 1, max_snapshots));
+	}: _(RawOrigin::Root, SOURCE, (asset_a, asset_b))
+	verify {
+		assert!(!WhitelistedAssets::<T>::get().contains(&(SOURCE, (asset_a, asset_b))));
+		assert!(Oracles::<T>::get((SOURCE, (asset_a, asset_b), LastBlock)).is_none());
+		assert!(EmaOracle::<T>::price_snapshots(SOURCE, (asset_a, asset_b)).is_empty());
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -63,11 +63,29 @@
 //! and the price and liquidity to be constant) to the last block. Note: The most recent oracle
 //! values are always from the last block. This avoids e.g. sandwiching risks. If you want current
 //! prices you should use a spot price or similar.
+//!
+//! ### Whitelist
+//!
+//! Only entries of whitelisted `(source, asset_pair)` combinations are tracked. Combinations are
+//! whitelisted either statically via `OracleWhitelist` or by `AuthorityOrigin` via `add_oracle`.
+//! Data of other combinations is ignored.
+//!
+//! `remove_oracle` removes the combination from the whitelist and removes its oracles from storage.
+//!
+//! ### Time-weighted average price
+//!
+//! Apart from the EMA oracles, a snapshot of the cumulative price is taken at the end of every block
+//! in which an entry of a `(source, asset_pair)` combination was updated. The last
+//! `MaxPriceSnapshots` snapshots are kept in a ring buffer. `get_twap` uses the snapshots to
+//! calculate the time-weighted average price between two blocks or the price at a given block.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::pallet_prelude::*;
-use frame_support::sp_runtime::traits::{BlockNumberProvider, One, Zero};
+use frame_support::sp_runtime::traits::{BlockNumberProvider, One, SaturatedConversion, Zero};
+use frame_support::traits::Contains;
+use frame_system::pallet_prelude::OriginFor;
+use hydra_dx_math::ema::time_weighted_average_price;
 use hydradx_traits::{
 	AggregatedEntry, AggregatedOracle, AggregatedPriceOracle, Liquidity, OnCreatePoolHandler,
 	OnLiquidityChangedHandler, OnTradeHandler,
@@ -75,6 +93,7 @@ use hydradx_traits::{
 	Volume,
 };
 use sp_arithmetic::traits::Saturating;
+use sp_std::collections::btree_set::BTreeSet;
 use sp_std::marker::PhantomData;
 use sp_std::prelude::*;
 
@@ -90,6 +109,8 @@ use weights::WeightInfo;
 
 mod benchmarking;
 
+pub mod migration;
+
 /// The maximum number of periods that could have corresponding oracles.
 pub const MAX_PERIODS: u32 = OraclePeriod::all_periods().len() as u32;
 
@@ -102,10 +123,14 @@ pub use pallet::*;
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::BoundedBTreeMap;
+	use frame_support::{BoundedBTreeMap, BoundedBTreeSet};
 	use frame_system::pallet_prelude::BlockNumberFor;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -115,6 +140,12 @@ pub mod pallet {
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 
+		/// Origin that can add and remove oracles.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Combinations of source and asset pair which are always tracked.
+		type OracleWhitelist: Contains<(Source, (AssetId, AssetId))>;
+
 		/// Provider for the current block number.
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = Self::BlockNumber>;
 
@@ -124,16 +155,31 @@ pub mod pallet {
 		/// Maximum number of unique oracle entries expected in one block.
 		#[pallet::constant]
 		type MaxUniqueEntries: Get<u32>;
+
+		/// Number of price snapshots kept per combination of source and asset pair.
+		#[pallet::constant]
+		type MaxPriceSnapshots: Get<u32>;
 	}
 
 	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
 	pub enum Error<T> {
 		TooManyUniqueEntries,
 		OnTradeValueZero,
+		/// Oracle is neither whitelisted nor stored.
+		OracleNotFound,
 	}
 
 	#[pallet::event]
-	pub enum Event<T: Config> {}
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Oracle was added to the whitelist.
+		OracleAdded { source: Source, assets: (AssetId, AssetId) },
+		/// Oracle was removed from the whitelist and its entries were removed from storage.
+		OracleRemoved { source: Source, assets: (AssetId, AssetId) },
+		/// Oracle was initialized with its first entry.
+		OracleCreated { source: Source, assets: (AssetId, AssetId) },
+	}
 
 	/// Accumulator for oracle data in current block that will be recorded at the end of the block.
 	#[pallet::storage]
@@ -160,6 +206,33 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Ring buffer of price snapshots keyed by data source, involved asset ids and the position in
+	/// the buffer.
+	#[pallet::storage]
+	pub type PriceSnapshots<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, Source>,
+			NMapKey<Twox64Concat, (AssetId, AssetId)>,
+			NMapKey<Twox64Concat, u32>,
+		),
+		PriceSnapshot<T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Position of the most recent snapshot in `PriceSnapshots` and the number of stored snapshots
+	/// keyed by data source and involved asset ids.
+	#[pallet::storage]
+	#[pallet::getter(fn snapshot_cursor)]
+	pub type SnapshotCursor<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, Source, Twox64Concat, (AssetId, AssetId), (u32, u32), OptionQuery>;
+
+	/// Combinations of source and asset pair whitelisted by `AuthorityOrigin`.
+	#[pallet::storage]
+	#[pallet::getter(fn whitelisted_assets)]
+	pub type WhitelistedAssets<T: Config> =
+		StorageValue<_, BoundedBTreeSet<(Source, (AssetId, AssetId)), T::MaxUniqueEntries>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(Default)]
 	pub struct GenesisConfig {
@@ -207,11 +280,72 @@ pub mod pallet {
 				T::MaxUniqueEntries::get() > 0,
 				"At least one trade should be possible per block."
 			);
+			assert!(
+				T::MaxPriceSnapshots::get() > 0,
+				"At least one price snapshot should be kept."
+			);
 		}
 	}
 
 	#[pallet::call]
-	impl<T: Config> Pallet<T> {}
+	impl<T: Config> Pallet<T> {
+		/// Add an oracle to the whitelist so that data of the `source` and `assets` combination is tracked.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `source`: source of the data
+		/// - `assets`: asset pair
+		///
+		/// Emits `OracleAdded` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_oracle())]
+		pub fn add_oracle(origin: OriginFor<T>, source: Source, assets: (AssetId, AssetId)) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let assets = ordered_pair(assets.0, assets.1);
+
+			WhitelistedAssets::<T>::try_mutate(|list| {
+				list.try_insert((source, assets))
+					.map_err(|_| Error::<T>::TooManyUniqueEntries)
+			})?;
+
+			Self::deposit_event(Event::OracleAdded { source, assets });
+
+			Ok(())
+		}
+
+		/// Remove an oracle from the whitelist and remove its entries for all periods from storage.
+		///
+		/// Note that oracles of combinations whitelisted by `OracleWhitelist` are created again on
+		/// next trade or liquidity change.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `source`: source of the data
+		/// - `assets`: asset pair
+		///
+		/// Emits `OracleRemoved` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_oracle())]
+		pub fn remove_oracle(origin: OriginFor<T>, source: Source, assets: (AssetId, AssetId)) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let assets = ordered_pair(assets.0, assets.1);
+
+			let whitelisted = WhitelistedAssets::<T>::mutate(|list| list.remove(&(source, assets)));
+			let stored = Oracles::<T>::contains_prefix((source, assets));
+			ensure!(whitelisted || stored, Error::<T>::OracleNotFound);
+
+			let _ = Oracles::<T>::clear_prefix((source, assets), MAX_PERIODS, None);
+			let _ = PriceSnapshots::<T>::clear_prefix((source, assets), T::MaxPriceSnapshots::get(), None);
+			SnapshotCursor::<T>::remove(source, assets);
+			Accumulator::<T>::mutate(|accumulator| accumulator.remove(&(source, assets)));
+
+			Self::deposit_event(Event::OracleRemoved { source, assets });
+
+			Ok(())
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -222,6 +356,10 @@ impl<T: Config> Pallet<T> {
 		assets: (AssetId, AssetId),
 		oracle_entry: OracleEntry<T::BlockNumber>,
 	) -> Result<(), ()> {
+		if !Self::is_whitelisted(src, assets) {
+			return Ok(());
+		}
+
 		Accumulator::<T>::mutate(|accumulator| {
 			if let Some(entry) = accumulator.get_mut(&(src, assets)) {
 				entry.accumulate_volume_and_update_from(&oracle_entry);
@@ -235,6 +373,20 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Return `true` if the combination of `src` and `assets` is tracked.
+	pub fn is_whitelisted(src: Source, assets: (AssetId, AssetId)) -> bool {
+		T::OracleWhitelist::contains(&(src, assets)) || Self::whitelisted_assets().contains(&(src, assets))
+	}
+
+	/// Return all combinations of source and assets that have an oracle stored, in key order.
+	pub fn tracked_pairs() -> Vec<(Source, (AssetId, AssetId))> {
+		Oracles::<T>::iter_keys()
+			.map(|(src, assets, _period)| (src, assets))
+			.collect::<BTreeSet<_>>()
+			.into_iter()
+			.collect()
+	}
+
 	/// Insert or update data in the accumulator from received entry. Aggregates volume and
 	/// takes the most recent data for the rest.
 	pub(crate) fn on_trade(
@@ -287,7 +439,99 @@ impl<T: Config> Pallet<T> {
 			// As we use (the old value of) the `LastBlock` entry to update the other oracles it
 			// gets updated last.
 			Self::update_oracle(src, assets, LastBlock, oracle_entry.clone());
+			Self::record_price_snapshot(src, assets, oracle_entry.price);
+		}
+	}
+
+	/// Store a snapshot of the cumulative price in the current block, overwriting the oldest snapshot
+	/// if the ring buffer is full.
+	fn record_price_snapshot(src: Source, assets: (AssetId, AssetId), price: Price) {
+		let block = T::BlockNumberProvider::current_block_number();
+		let max_snapshots = T::MaxPriceSnapshots::get();
+
+		let previous = Self::snapshot_cursor(src, assets).and_then(|(index, len)| {
+			PriceSnapshots::<T>::get((src, assets, index)).map(|snapshot| (index, len, snapshot))
+		});
+
+		let (index, len, snapshot) = match previous {
+			Some((index, len, previous_snapshot)) => (
+				index.saturating_add(1) % max_snapshots,
+				len.saturating_add(1).min(max_snapshots),
+				previous_snapshot.next(price, block),
+			),
+			None => (0, 1, PriceSnapshot::new(price, block)),
+		};
+
+		PriceSnapshots::<T>::insert((src, assets, index), snapshot);
+		SnapshotCursor::<T>::insert(src, assets, (index, len));
+	}
+
+	/// Return the stored price snapshots of the given source and assets, most recent first.
+	pub fn price_snapshots(src: Source, assets: (AssetId, AssetId)) -> Vec<PriceSnapshot<T::BlockNumber>> {
+		let (index, len) = match Self::snapshot_cursor(src, assets) {
+			Some(cursor) => cursor,
+			None => return Vec::new(),
+		};
+		let max_snapshots = T::MaxPriceSnapshots::get();
+		(0..len)
+			.filter_map(|i| {
+				let position = index.saturating_add(max_snapshots).saturating_sub(i) % max_snapshots;
+				PriceSnapshots::<T>::get((src, assets, position))
+			})
+			.collect()
+	}
+
+	/// Return the time-weighted average price of `asset_a` denominated in `asset_b` between
+	/// `from_block` and `to_block`.
+	///
+	/// Returns the price at `from_block` if `from_block` equals `to_block`.
+	///
+	/// The price is assumed to stay constant between snapshots. Fails if `from_block` is older than
+	/// the oldest stored snapshot or `to_block` is in the future.
+	pub fn get_twap(
+		source: Source,
+		(asset_a, asset_b): (AssetId, AssetId),
+		from_block: T::BlockNumber,
+		to_block: T::BlockNumber,
+	) -> Result<Price, OracleError> {
+		if asset_a == asset_b {
+			return Err(OracleError::SameAsset);
 		}
+		if from_block > to_block || to_block > T::BlockNumberProvider::current_block_number() {
+			return Err(OracleError::InvalidBlockRange);
+		}
+
+		let assets = ordered_pair(asset_a, asset_b);
+		let inverted = assets != (asset_a, asset_b);
+		let snapshots = Self::price_snapshots(source, assets);
+
+		let snapshot_at = |block: T::BlockNumber| {
+			snapshots
+				.iter()
+				.find(|snapshot| snapshot.block <= block)
+				.ok_or(OracleError::NotPresent)
+		};
+
+		let start_snapshot = snapshot_at(from_block)?;
+		if from_block == to_block {
+			return Ok(if inverted {
+				start_snapshot.price.inverted()
+			} else {
+				start_snapshot.price
+			});
+		}
+		let end_snapshot = snapshot_at(to_block)?;
+
+		let (start, start_inverted) = start_snapshot.cumulative_prices_at(from_block);
+		let (end, end_inverted) = end_snapshot.cumulative_prices_at(to_block);
+		let blocks = to_block.saturating_sub(from_block).saturated_into();
+
+		if inverted {
+			time_weighted_average_price(start_inverted, end_inverted, blocks)
+		} else {
+			time_weighted_average_price(start, end, blocks)
+		}
+		.ok_or(OracleError::NotPresent)
 	}
 
 	/// Update the oracle of the given source, assets and period with `oracle_entry`.
@@ -301,6 +545,9 @@ impl<T: Config> Pallet<T> {
 			// initialize the oracle entry if it doesn't exist
 			if oracle.is_none() {
 				*oracle = Some((incoming_entry.clone(), T::BlockNumberProvider::current_block_number()));
+				if period == LastBlock {
+					Self::deposit_event(Event::OracleCreated { source: src, assets });
+				}
 				return;
 			}
 			if let Some((prev_entry, _)) = oracle.as_mut() {
@@ -511,6 +758,8 @@ pub enum OracleError {
 	NotPresent,
 	/// The oracle is not defined if the asset ids are the same.
 	SameAsset,
+	/// The start of the block range is after its end or the end is in the future.
+	InvalidBlockRange,
 }
 
 impl<T: Config> AggregatedOracle<AssetId, Balance, T::BlockNumber, Price> for Pallet<T> {
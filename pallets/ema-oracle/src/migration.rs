@@ -0,0 +1,90 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Config, Oracles, Pallet, PriceSnapshots, SnapshotCursor, LOG_TARGET, MAX_PERIODS};
+use frame_support::{
+	log,
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+use sp_std::collections::btree_set::BTreeSet;
+
+/// Oracles of `(source, asset_pair)` combinations which are not whitelisted are not updated
+/// anymore, so they are removed from storage together with their price snapshots.
+pub mod v1 {
+	use super::*;
+
+	pub fn pre_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+		log::info!(target: LOG_TARGET, "EMA oracle migration: PRE checks successful!");
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			log::warn!(
+				target: LOG_TARGET,
+				"Attempted to apply migration to v1 but storage version is already v1"
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(target: LOG_TARGET, "Running migration to v1 for EMA oracle");
+
+		let mut reads: u64 = 0;
+		let untracked: BTreeSet<_> = Oracles::<T>::iter_keys()
+			.inspect(|_| reads += 1)
+			.map(|(source, assets, _period)| (source, assets))
+			.filter(|&(source, assets)| !Pallet::<T>::is_whitelisted(source, assets))
+			.collect();
+
+		let mut writes: u64 = 0;
+		for &(source, assets) in untracked.iter() {
+			let oracles = Oracles::<T>::clear_prefix((source, assets), MAX_PERIODS, None);
+			let snapshots = PriceSnapshots::<T>::clear_prefix((source, assets), T::MaxPriceSnapshots::get(), None);
+			SnapshotCursor::<T>::remove(source, assets);
+			writes = writes
+				.saturating_add(oracles.unique.into())
+				.saturating_add(snapshots.unique.into())
+				.saturating_add(1);
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		log::info!(
+			target: LOG_TARGET,
+			"Removed oracles of {:?} untracked asset pairs",
+			untracked.len()
+		);
+
+		//storage version read and write and whitelist read included
+		T::DbWeight::get().reads_writes(reads.saturating_add(2), writes.saturating_add(1))
+	}
+
+	pub fn post_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+		for (source, assets, _period) in Oracles::<T>::iter_keys() {
+			assert!(
+				Pallet::<T>::is_whitelisted(source, assets),
+				"Oracle of untracked asset pair not removed."
+			);
+		}
+
+		log::info!(target: LOG_TARGET, "EMA oracle migration: POST checks successful!");
+	}
+}
@@ -25,8 +25,9 @@ use frame_support::sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 };
-use frame_support::traits::{Everything, GenesisBuild};
+use frame_support::traits::{Contains, Everything, GenesisBuild};
 use frame_support::BoundedVec;
+use frame_system::EnsureRoot;
 use hydradx_traits::OraclePeriod::{self, *};
 use hydradx_traits::{AssetPairAccountIdFor, Liquidity, Volume};
 use sp_core::H256;
@@ -128,12 +129,23 @@ parameter_types! {
 	pub SupportedPeriods: BoundedVec<OraclePeriod, ConstU32<MAX_PERIODS>> = bounded_vec![LastBlock, TenMinutes, Day, Week];
 }
 
+pub struct SourceWhitelist;
+
+impl Contains<(Source, (AssetId, AssetId))> for SourceWhitelist {
+	fn contains(t: &(Source, (AssetId, AssetId))) -> bool {
+		t.0 == super::SOURCE
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type AuthorityOrigin = EnsureRoot<u64>;
+	type OracleWhitelist = SourceWhitelist;
 	type BlockNumberProvider = System;
 	type SupportedPeriods = SupportedPeriods;
 	type MaxUniqueEntries = ConstU32<45>;
+	type MaxPriceSnapshots = ConstU32<5>;
 }
 
 pub type InitialDataEntry = (Source, (AssetId, AssetId), Price, Liquidity<Balance>);
@@ -17,6 +17,8 @@
 
 mod invariants;
 mod mock;
+mod twap;
+mod whitelist;
 
 use super::*;
 pub use mock::{
@@ -662,6 +664,22 @@ fn get_entry_works() {
 	});
 }
 
+#[test]
+fn tracked_pairs_should_list_each_stored_oracle_once() {
+	ExtBuilder::default()
+		.with_initial_data(vec![
+			(SOURCE, (ACA, DOT), (1_000_000, 1).into(), Liquidity::new(2_000_000, 2)),
+			(SOURCE, (HDX, DOT), (1_000_000, 1).into(), Liquidity::new(2_000_000, 2)),
+		])
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				EmaOracle::tracked_pairs(),
+				vec![(SOURCE, (HDX, DOT)), (SOURCE, (DOT, ACA))]
+			);
+		});
+}
+
 #[test]
 fn get_price_returns_updated_price() {
 	ExtBuilder::default()
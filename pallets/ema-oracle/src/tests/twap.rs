@@ -0,0 +1,196 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use sp_arithmetic::FixedU128;
+
+const ONE: u128 = 1_000_000_000_000_000_000;
+
+fn trade_and_finalize(block: BlockNumber, price: Price) {
+	System::set_block_number(block);
+	assert_ok!(EmaOracle::on_trade(
+		SOURCE,
+		(HDX, DOT),
+		OracleEntry {
+			price,
+			updated_at: block,
+			..ORACLE_ENTRY_1
+		}
+	));
+	EmaOracle::on_finalize(block);
+}
+
+#[test]
+fn price_snapshots_should_be_recorded_on_finalize() {
+	new_test_ext().execute_with(|| {
+		trade_and_finalize(5, Price::new(2_000, 1_000));
+		trade_and_finalize(7, Price::new(4_000, 4_000));
+
+		assert_eq!(
+			EmaOracle::price_snapshots(SOURCE, (HDX, DOT)),
+			vec![
+				PriceSnapshot {
+					price: Price::new(4_000, 4_000),
+					cumulative_price: FixedU128::from(4),
+					cumulative_inverted_price: FixedU128::from(1),
+					block: 7,
+				},
+				PriceSnapshot {
+					price: Price::new(2_000, 1_000),
+					cumulative_price: FixedU128::from(0),
+					cumulative_inverted_price: FixedU128::from(0),
+					block: 5,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn oldest_price_snapshot_should_be_overwritten_when_ring_buffer_is_full() {
+	new_test_ext().execute_with(|| {
+		let max_snapshots = <Test as crate::Config>::MaxPriceSnapshots::get() as u64;
+		for block in 1..=max_snapshots + 1 {
+			trade_and_finalize(block, Price::new(2_000, 1_000));
+		}
+
+		let snapshots = EmaOracle::price_snapshots(SOURCE, (HDX, DOT));
+		assert_eq!(snapshots.len() as u64, max_snapshots);
+		assert_eq!(snapshots.first().map(|s| s.block), Some(max_snapshots + 1));
+		assert_eq!(snapshots.last().map(|s| s.block), Some(2));
+
+		System::set_block_number(max_snapshots + 2);
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, DOT), 1, max_snapshots + 1),
+			Err(OracleError::NotPresent)
+		);
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, DOT), 2, max_snapshots + 1),
+			Ok(Price::new(2 * ONE, ONE))
+		);
+	});
+}
+
+#[test]
+fn get_twap_should_return_time_weighted_average_price() {
+	new_test_ext().execute_with(|| {
+		trade_and_finalize(5, Price::new(2_000, 1_000));
+		trade_and_finalize(7, Price::new(4_000, 4_000));
+		System::set_block_number(10);
+
+		// price 2 for 2 blocks and price 1 for 2 blocks
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, DOT), 5, 9),
+			Ok(Price::new(3 * ONE / 2, ONE))
+		);
+		// price 0.5 for 2 blocks and price 1 for 2 blocks
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (DOT, HDX), 5, 9),
+			Ok(Price::new(3 * ONE / 4, ONE))
+		);
+		// price stays constant after the last snapshot
+		assert_eq!(EmaOracle::get_twap(SOURCE, (HDX, DOT), 7, 10), Ok(Price::new(ONE, ONE)));
+	});
+}
+
+#[test]
+fn get_twap_should_return_correct_price_when_cumulative_price_overflows() {
+	new_test_ext().execute_with(|| {
+		// each block adds 10^38 to the cumulative price, which overflows after the fourth block
+		let price = Price::new(10u128.pow(20), 1);
+		trade_and_finalize(1, price);
+		trade_and_finalize(3, price);
+		trade_and_finalize(5, price);
+		System::set_block_number(6);
+
+		let snapshots = EmaOracle::price_snapshots(SOURCE, (HDX, DOT));
+		assert!(snapshots[0].cumulative_price < snapshots[1].cumulative_price);
+
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, DOT), 3, 5),
+			Ok(Price::new(10u128.pow(20) * ONE, ONE))
+		);
+	});
+}
+
+#[test]
+fn get_twap_should_return_price_at_block_when_block_range_is_empty() {
+	new_test_ext().execute_with(|| {
+		trade_and_finalize(5, Price::new(2_000, 1_000));
+		trade_and_finalize(7, Price::new(4_000, 4_000));
+		System::set_block_number(10);
+
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, DOT), 6, 6),
+			Ok(Price::new(2_000, 1_000))
+		);
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (DOT, HDX), 6, 6),
+			Ok(Price::new(1_000, 2_000))
+		);
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, DOT), 8, 8),
+			Ok(Price::new(4_000, 4_000))
+		);
+	});
+}
+
+#[test]
+fn get_twap_should_fail_when_input_is_invalid() {
+	new_test_ext().execute_with(|| {
+		trade_and_finalize(5, Price::new(2_000, 1_000));
+		System::set_block_number(10);
+
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, HDX), 5, 9),
+			Err(OracleError::SameAsset)
+		);
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, DOT), 9, 5),
+			Err(OracleError::InvalidBlockRange)
+		);
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, DOT), 5, 11),
+			Err(OracleError::InvalidBlockRange)
+		);
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, DOT), 4, 9),
+			Err(OracleError::NotPresent)
+		);
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, ACA), 5, 9),
+			Err(OracleError::NotPresent)
+		);
+	});
+}
+
+#[test]
+fn remove_oracle_should_remove_price_snapshots() {
+	new_test_ext().execute_with(|| {
+		trade_and_finalize(5, Price::new(2_000, 1_000));
+		trade_and_finalize(6, Price::new(4_000, 4_000));
+
+		assert_ok!(EmaOracle::remove_oracle(RuntimeOrigin::root(), SOURCE, (HDX, DOT)));
+
+		assert!(EmaOracle::price_snapshots(SOURCE, (HDX, DOT)).is_empty());
+		assert_eq!(EmaOracle::snapshot_cursor(SOURCE, (HDX, DOT)), None);
+		assert_eq!(
+			EmaOracle::get_twap(SOURCE, (HDX, DOT), 5, 6),
+			Err(OracleError::NotPresent)
+		);
+	});
+}
@@ -0,0 +1,177 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::sp_runtime::DispatchError::BadOrigin;
+
+/// Source which is not whitelisted by `OracleWhitelist` of the mock.
+const OTHER_SOURCE: Source = *b"othersrc";
+
+#[test]
+fn add_oracle_should_whitelist_assets() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), OTHER_SOURCE, (DOT, HDX)));
+
+		assert!(EmaOracle::whitelisted_assets().contains(&(OTHER_SOURCE, (HDX, DOT))));
+		assert!(EmaOracle::is_whitelisted(OTHER_SOURCE, (HDX, DOT)));
+
+		System::assert_last_event(
+			Event::OracleAdded {
+				source: OTHER_SOURCE,
+				assets: (HDX, DOT),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn add_oracle_should_fail_when_origin_is_not_authority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::add_oracle(RuntimeOrigin::signed(1), OTHER_SOURCE, (HDX, DOT)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn add_oracle_should_fail_when_whitelist_is_full() {
+	new_test_ext().execute_with(|| {
+		let max_entries = <Test as crate::Config>::MaxUniqueEntries::get();
+		for i in 0..max_entries {
+			assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), OTHER_SOURCE, (i, i + 1)));
+		}
+
+		assert_noop!(
+			EmaOracle::add_oracle(RuntimeOrigin::root(), OTHER_SOURCE, (HDX, DOT)),
+			Error::<Test>::TooManyUniqueEntries
+		);
+	});
+}
+
+#[test]
+fn data_should_be_ignored_when_assets_are_not_whitelisted() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(EmaOracle::on_trade(OTHER_SOURCE, (HDX, DOT), ORACLE_ENTRY_1));
+		assert_eq!(get_accumulator_entry(OTHER_SOURCE, (HDX, DOT)), None);
+
+		assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), OTHER_SOURCE, (HDX, DOT)));
+
+		assert_ok!(EmaOracle::on_trade(OTHER_SOURCE, (HDX, DOT), ORACLE_ENTRY_1));
+		assert_eq!(get_accumulator_entry(OTHER_SOURCE, (HDX, DOT)), Some(ORACLE_ENTRY_1));
+	});
+}
+
+#[test]
+fn oracle_created_event_should_be_emitted_when_oracle_is_initialized() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(EmaOracle::on_trade(SOURCE, (HDX, DOT), ORACLE_ENTRY_1));
+		EmaOracle::on_finalize(5);
+
+		System::assert_last_event(
+			Event::OracleCreated {
+				source: SOURCE,
+				assets: (HDX, DOT),
+			}
+			.into(),
+		);
+
+		System::reset_events();
+		System::set_block_number(6);
+		assert_ok!(EmaOracle::on_trade(SOURCE, (HDX, DOT), ORACLE_ENTRY_2));
+		EmaOracle::on_finalize(6);
+
+		assert!(System::events().is_empty());
+	});
+}
+
+#[test]
+fn remove_oracle_should_remove_whitelisted_assets_and_oracles() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), OTHER_SOURCE, (HDX, DOT)));
+		assert_ok!(EmaOracle::on_trade(OTHER_SOURCE, (HDX, DOT), ORACLE_ENTRY_1));
+		EmaOracle::on_finalize(5);
+		for period in supported_periods() {
+			assert!(Oracles::<Test>::get((OTHER_SOURCE, (HDX, DOT), period)).is_some());
+		}
+
+		System::set_block_number(6);
+		assert_ok!(EmaOracle::on_trade(OTHER_SOURCE, (HDX, DOT), ORACLE_ENTRY_2));
+
+		assert_ok!(EmaOracle::remove_oracle(
+			RuntimeOrigin::root(),
+			OTHER_SOURCE,
+			(DOT, HDX)
+		));
+
+		assert!(!EmaOracle::is_whitelisted(OTHER_SOURCE, (HDX, DOT)));
+		for period in supported_periods() {
+			assert_eq!(Oracles::<Test>::get((OTHER_SOURCE, (HDX, DOT), period)), None);
+		}
+		assert_eq!(get_accumulator_entry(OTHER_SOURCE, (HDX, DOT)), None);
+
+		System::assert_last_event(
+			Event::OracleRemoved {
+				source: OTHER_SOURCE,
+				assets: (HDX, DOT),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn remove_oracle_should_remove_oracles_of_statically_whitelisted_assets() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(5);
+		assert_ok!(EmaOracle::on_trade(SOURCE, (HDX, DOT), ORACLE_ENTRY_1));
+		EmaOracle::on_finalize(5);
+		assert!(get_oracle_entry(HDX, DOT, LastBlock).is_some());
+
+		assert_ok!(EmaOracle::remove_oracle(RuntimeOrigin::root(), SOURCE, (HDX, DOT)));
+
+		assert_eq!(get_oracle_entry(HDX, DOT, LastBlock), None);
+	});
+}
+
+#[test]
+fn remove_oracle_should_fail_when_oracle_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::remove_oracle(RuntimeOrigin::root(), OTHER_SOURCE, (HDX, DOT)),
+			Error::<Test>::OracleNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_oracle_should_fail_when_origin_is_not_authority() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), OTHER_SOURCE, (HDX, DOT)));
+
+		assert_noop!(
+			EmaOracle::remove_oracle(RuntimeOrigin::signed(1), OTHER_SOURCE, (HDX, DOT)),
+			BadOrigin
+		);
+	});
+}
@@ -17,11 +17,14 @@
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::RuntimeDebug;
-use hydra_dx_math::ema::{calculate_new_by_integrating_incoming, update_outdated_to_current, EmaPrice};
+use hydra_dx_math::ema::{
+	accumulate_price, calculate_new_by_integrating_incoming, update_outdated_to_current, EmaPrice,
+};
 use hydra_dx_math::types::Fraction;
 use hydradx_traits::{AggregatedEntry, Liquidity, Volume};
 use scale_info::TypeInfo;
-use sp_arithmetic::traits::{AtLeast32BitUnsigned, SaturatedConversion, UniqueSaturatedInto};
+use sp_arithmetic::traits::{AtLeast32BitUnsigned, SaturatedConversion, UniqueSaturatedInto, Zero};
+use sp_arithmetic::FixedU128;
 
 pub use hydradx_traits::{OraclePeriod, Source};
 
@@ -204,6 +207,59 @@ where
 	}
 }
 
+/// A snapshot of the cumulative price of an asset pair taken at the end of a block.
+///
+/// The cumulative prices are sums of the prices weighted by the number of blocks they were valid for.
+/// They wrap around on overflow, only their differences are meaningful.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct PriceSnapshot<BlockNumber> {
+	/// The price at the end of `block`.
+	pub price: Price,
+	/// The cumulative price up to `block`.
+	pub cumulative_price: FixedU128,
+	/// The cumulative inverted price up to `block`.
+	pub cumulative_inverted_price: FixedU128,
+	/// The block in which the snapshot was taken.
+	pub block: BlockNumber,
+}
+
+impl<BlockNumber> PriceSnapshot<BlockNumber>
+where
+	BlockNumber: AtLeast32BitUnsigned + Copy + UniqueSaturatedInto<u64>,
+{
+	/// Construct the first snapshot of an asset pair.
+	pub fn new(price: Price, block: BlockNumber) -> Self {
+		Self {
+			price,
+			cumulative_price: FixedU128::zero(),
+			cumulative_inverted_price: FixedU128::zero(),
+			block,
+		}
+	}
+
+	/// Determine the snapshot following `self` with the new `price` taken in `block`.
+	pub fn next(&self, price: Price, block: BlockNumber) -> Self {
+		let (cumulative_price, cumulative_inverted_price) = self.cumulative_prices_at(block);
+		Self {
+			price,
+			cumulative_price,
+			cumulative_inverted_price,
+			block,
+		}
+	}
+
+	/// Return the cumulative price and the cumulative inverted price at `block`, assuming the price
+	/// stays constant after `self.block`.
+	pub fn cumulative_prices_at(&self, block: BlockNumber) -> (FixedU128, FixedU128) {
+		let blocks = block.saturating_sub(self.block).saturated_into();
+		(
+			accumulate_price(self.cumulative_price, self.price, blocks),
+			accumulate_price(self.cumulative_inverted_price, self.price.inverted(), blocks),
+		)
+	}
+}
+
 /// Convert a given `period` into the smoothing factor used in the weighted average.
 /// See [`check_period_smoothing_factors`] for how the values are generated.
 pub fn into_smoothing(period: OraclePeriod) -> Fraction {
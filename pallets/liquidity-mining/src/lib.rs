@@ -88,6 +88,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::derive_partial_eq_without_eq)]
 
+pub mod shares;
 #[cfg(test)]
 mod tests;
 mod types;
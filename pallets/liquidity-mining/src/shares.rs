@@ -0,0 +1,253 @@
+// This file is part of galacticcouncil/warehouse.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deposit, redeposit, claim and withdraw of fungible LP shares.
+//!
+//! Liquidity mining pallets whose pools issue LP shares as fungible tokens(e.g. stableswap or
+//! xyk) lock the shares in the pallet's account and mint NFT representing the deposit to the LP.
+//! This logic is the same for all of these pallets so it's implemented here as provided methods
+//! of the [`SharesLiquidityMining`] trait. Implementers only provide the pool specific parts:
+//! valuation of the LP shares and mapping of the deposits to the pools.
+
+use crate::{Balance, DepositId, GlobalFarmId, YieldFarmId};
+use frame_support::{
+	ensure,
+	pallet_prelude::DispatchError,
+	sp_runtime::{traits::Zero, RuntimeDebug},
+	traits::{
+		tokens::nonfungibles::{Inspect, Mutate},
+		DefensiveOption, Get,
+	},
+};
+use hydradx_traits::liquidity_mining::Mutate as LiquidityMiningMutate;
+use orml_traits::MultiCurrency;
+
+/// Errors of the shares liquidity mining. Implementer converts them to its own errors.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum SharesError {
+	/// Account is not owner of the deposit.
+	Forbidden,
+
+	/// Account does not have enough LP shares to deposit.
+	InsufficientShares,
+
+	/// Rewards to claim are 0.
+	ZeroClaimedRewards,
+
+	/// Mapping of `deposit_id` to the pool was not found in the storage.
+	MissingDepositPool,
+
+	/// Deposit data not found.
+	DepositDataNotFound,
+}
+
+/// Result of the LP shares withdrawal from the yield farm.
+#[derive(Clone, PartialEq, Eq, RuntimeDebug)]
+pub struct WithdrawnShares<AssetId> {
+	pub global_farm_id: GlobalFarmId,
+	/// Amount of LP shares withdrawn from the yield farm.
+	pub amount: Balance,
+	/// `(reward_currency, claimed)` if non-zero rewards were claimed.
+	pub claimed: Option<(AssetId, Balance)>,
+	/// `true` if this was last withdraw from the deposit and the deposit was destroyed.
+	pub is_destroyed: bool,
+}
+
+pub trait SharesLiquidityMining {
+	type AccountId: Clone + PartialEq;
+	type AssetId: Copy;
+	type BlockNumber;
+	type AmmPoolId: Clone;
+	type CollectionId;
+
+	/// Currency for transfers of the LP shares.
+	type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+	/// NFT collection id for liquidity mining's deposit nfts.
+	type NFTCollectionId: Get<Self::CollectionId>;
+
+	/// Non fungible handling
+	type NFTHandler: Mutate<Self::AccountId>
+		+ Inspect<Self::AccountId, ItemId = DepositId, CollectionId = Self::CollectionId>;
+
+	/// Liquidity mining handler for managing liquidity mining functionalities
+	type LiquidityMiningHandler: LiquidityMiningMutate<
+		Self::AccountId,
+		Self::AssetId,
+		Self::BlockNumber,
+		Error = DispatchError,
+		AmmPoolId = Self::AmmPoolId,
+		Balance = Balance,
+	>;
+
+	/// Implementer's error `SharesError`s are converted into.
+	type Error: From<SharesError> + Into<DispatchError>;
+
+	/// Account ID of the pot holding all the locked LP shares. This account is also owner of the
+	/// NFT collection used to mint liqudity mining's NFTs.
+	fn account_id() -> Self::AccountId;
+
+	/// This function returns value of `shares_amount` of the pool's shares in `incentivized_asset`.
+	fn get_shares_value(
+		incentivized_asset: Self::AssetId,
+		amm_pool_id: Self::AmmPoolId,
+		shares_amount: Balance,
+	) -> Result<Balance, DispatchError>;
+
+	/// Save mapping of the deposit's id to the pool and its share token.
+	fn insert_deposit_pool(deposit_id: DepositId, amm_pool_id: Self::AmmPoolId, share_token: Self::AssetId);
+
+	/// Returns pool and its share token locked in the deposit or `None`.
+	fn get_deposit_pool(deposit_id: DepositId) -> Option<(Self::AmmPoolId, Self::AssetId)>;
+
+	/// Remove mapping of the deposit's id to the pool from the storage.
+	fn remove_deposit_pool(deposit_id: DepositId);
+
+	/// Returns pool and its share token locked in the deposit.
+	fn deposit_pool(deposit_id: DepositId) -> Result<(Self::AmmPoolId, Self::AssetId), DispatchError> {
+		//NOTE: not tested - this should never fail.
+		Self::get_deposit_pool(deposit_id)
+			.defensive_ok_or(SharesError::MissingDepositPool)
+			.map_err(error::<Self>)
+	}
+
+	/// This function returns error if `who` is not owner of the deposit.
+	fn ensure_deposit_owner(who: &Self::AccountId, deposit_id: DepositId) -> Result<(), DispatchError> {
+		let nft_owner = Self::NFTHandler::owner(&Self::NFTCollectionId::get(), &deposit_id)
+			.ok_or_else(|| error::<Self>(SharesError::Forbidden))?;
+
+		ensure!(nft_owner == *who, error::<Self>(SharesError::Forbidden));
+
+		Ok(())
+	}
+
+	/// Deposit `shares_amount` of `share_token` to the yield farm. Shares are transferred from
+	/// `who` to pallet's account and NFT representing the deposit is minted to `who`.
+	///
+	/// Returns: `(DepositId)`
+	fn do_deposit_shares(
+		who: &Self::AccountId,
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+		amm_pool_id: Self::AmmPoolId,
+		share_token: Self::AssetId,
+		shares_amount: Balance,
+	) -> Result<DepositId, DispatchError> {
+		ensure!(
+			Self::Currency::free_balance(share_token, who) >= shares_amount,
+			error::<Self>(SharesError::InsufficientShares)
+		);
+
+		let deposit_id = Self::LiquidityMiningHandler::deposit_lp_shares(
+			global_farm_id,
+			yield_farm_id,
+			amm_pool_id.clone(),
+			shares_amount,
+			Self::get_shares_value,
+		)?;
+
+		Self::Currency::transfer(share_token, who, &Self::account_id(), shares_amount)?;
+
+		//Mapping of the `deposit_id` to the pool and its share token so we know which shares to
+		//unlock when deposit is destroyed.
+		Self::insert_deposit_pool(deposit_id, amm_pool_id, share_token);
+
+		Self::NFTHandler::mint_into(&Self::NFTCollectionId::get(), &deposit_id, who)?;
+
+		Ok(deposit_id)
+	}
+
+	/// Redeposit LP shares already locked in the deposit to another yield farm.
+	///
+	/// Returns: `(redeposited LP shares amount)`
+	fn do_redeposit_shares(
+		global_farm_id: GlobalFarmId,
+		yield_farm_id: YieldFarmId,
+		deposit_id: DepositId,
+	) -> Result<Balance, DispatchError> {
+		let (shares_amount, _) = Self::LiquidityMiningHandler::redeposit_lp_shares(
+			global_farm_id,
+			yield_farm_id,
+			deposit_id,
+			Self::get_shares_value,
+		)?;
+
+		Ok(shares_amount)
+	}
+
+	/// Claim rewards for the deposit. Claiming zero rewards is not allowed.
+	///
+	/// Returns: `(GlobalFarmId, reward currency, claimed amount)`
+	fn do_claim_rewards(
+		who: &Self::AccountId,
+		deposit_id: DepositId,
+		yield_farm_id: YieldFarmId,
+	) -> Result<(GlobalFarmId, Self::AssetId, Balance), DispatchError> {
+		let (global_farm_id, reward_currency, claimed, _) =
+			Self::LiquidityMiningHandler::claim_rewards(who.clone(), deposit_id, yield_farm_id)?;
+
+		ensure!(!claimed.is_zero(), error::<Self>(SharesError::ZeroClaimedRewards));
+
+		Ok((global_farm_id, reward_currency, claimed))
+	}
+
+	/// Claim rewards and withdraw LP shares from the yield farm. If this was last withdraw from
+	/// the deposit, LP shares are transferred back to `who`, deposit's pool mapping is removed and
+	/// deposit's NFT is burned.
+	fn do_withdraw_shares(
+		who: &Self::AccountId,
+		deposit_id: DepositId,
+		yield_farm_id: YieldFarmId,
+	) -> Result<WithdrawnShares<Self::AssetId>, DispatchError> {
+		let (amm_pool_id, share_token) = Self::deposit_pool(deposit_id)?;
+
+		//NOTE: not tested - this should never fail.
+		let global_farm_id = Self::LiquidityMiningHandler::get_global_farm_id(deposit_id, yield_farm_id)
+			.defensive_ok_or(SharesError::DepositDataNotFound)
+			.map_err(error::<Self>)?;
+
+		let (amount, claim_data, is_destroyed) = Self::LiquidityMiningHandler::withdraw_lp_shares(
+			who.clone(),
+			deposit_id,
+			global_farm_id,
+			yield_farm_id,
+			amm_pool_id,
+		)?;
+
+		if is_destroyed {
+			Self::Currency::transfer(share_token, &Self::account_id(), who, amount)?;
+
+			//NOTE: storage clean up
+			Self::remove_deposit_pool(deposit_id);
+
+			Self::NFTHandler::burn(&Self::NFTCollectionId::get(), &deposit_id, Some(who))?;
+		}
+
+		Ok(WithdrawnShares {
+			global_farm_id,
+			amount,
+			claimed: claim_data
+				.filter(|(_, claimed, _)| !claimed.is_zero())
+				.map(|(reward_currency, claimed, _)| (reward_currency, claimed)),
+			is_destroyed,
+		})
+	}
+}
+
+fn error<T: SharesLiquidityMining + ?Sized>(e: SharesError) -> DispatchError {
+	T::Error::from(e).into()
+}
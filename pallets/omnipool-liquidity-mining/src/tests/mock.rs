@@ -249,14 +249,18 @@ parameter_types! {
 impl pallet_ema_oracle::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type OracleWhitelist = Everything;
 	type BlockNumberProvider = MockBlockNumberProvider;
 	type SupportedPeriods = SupportedPeriods;
 	type MaxUniqueEntries = ConstU32<20>;
+	type MaxPriceSnapshots = ConstU32<10>;
 }
 
 parameter_types! {
 	pub const HDXAssetId: AssetId = HDX;
 	pub const LRNAAssetId: AssetId = LRNA;
+	pub const TreasuryAccount: AccountId = 100;
 	pub const PositionCollectionId: CollectionId = OMNIPOOL_COLLECTION_ID;
 
 	pub ProtocolFee: Permill = PROTOCOL_FEE.with(|v| *v.borrow());
@@ -276,6 +280,8 @@ impl pallet_omnipool::Config for Test {
 	type Currency = Tokens;
 	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type HubAssetId = LRNAAssetId;
+	type ImbalanceBuybackAccount = TreasuryAccount;
+	type ImbalanceBuybackAssetId = HDXAssetId;
 	type WeightInfo = ();
 	type HdxAssetId = HDXAssetId;
 	type NFTCollectionId = PositionCollectionId;
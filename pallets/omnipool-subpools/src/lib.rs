@@ -0,0 +1,311 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Omnipool subpools pallet
+//!
+//! ## Overview
+//!
+//! Subpool is a Stableswap pool whose share asset is held by Omnipool as a single Omnipool asset.
+//!
+//! Authority can move several Omnipool assets into a new subpool. Reserves of the migrated assets are
+//! transferred from Omnipool account to the Stableswap pool account and share tokens matching the hub asset
+//! reserves of the migrated assets are minted to Omnipool account. Migrated assets are removed from Omnipool
+//! and the share asset is added instead. Total quantity of hub asset in Omnipool is not changed.
+//!
+//! Existing LP positions of migrated assets are converted to positions of the share asset by position owners
+//! with `migrate_position`. Position keeps its id and NFT. Positions locked in liquidity mining can be
+//! migrated after they are withdrawn from the farms.
+//!
+//! Pallet implements `TradeExecution` for `PoolType::Omnipool`, so router can trade an asset of a subpool for any
+//! Omnipool asset ( or an asset of another subpool ) in one hop. Such trade is executed as a combination of
+//! Omnipool trade and Stableswap liquidity operations.
+//!
+//! ### Terminology
+//!
+//! * **Subpool:** Stableswap pool identified by its share asset id, which is an asset of Omnipool
+//! * **Migrated asset:** asset which was moved from Omnipool to a subpool
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `create_subpool` - Moves given Omnipool assets into a new subpool.
+//! * `migrate_position` - Converts LP position of a migrated asset to position of the subpool share asset.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+pub mod router_execution;
+pub mod types;
+pub mod weights;
+
+use frame_support::{ensure, pallet_prelude::DispatchResult, traits::Get};
+use hydra_dx_math::omnipool_subpools::{
+	calculate_asset_migration_details, convert_position, create_subpool_initial_state,
+};
+use orml_traits::MultiCurrency;
+use pallet_omnipool::types::{AssetState, Balance, Position, Tradability};
+use sp_runtime::traits::Zero;
+use sp_runtime::{ArithmeticError, Permill};
+use sp_std::num::NonZeroU16;
+use sp_std::prelude::*;
+
+use crate::types::AssetDetail;
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+type OmnipoolPallet<T> = pallet_omnipool::Pallet<T>;
+type StableswapPallet<T> = pallet_stableswap::Pallet<T>;
+
+pub type AssetIdOf<T> = <T as pallet_stableswap::Config>::AssetId;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(crate) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_stableswap::Config
+		+ pallet_omnipool::Config<AssetId = <Self as pallet_stableswap::Config>::AssetId>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that can create subpools.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn migrated_asset)]
+	/// Assets migrated from Omnipool to subpools.
+	pub type MigratedAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, AssetDetail<AssetIdOf<T>>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Subpool was created and its share asset was added to Omnipool.
+		SubpoolCreated {
+			pool_id: AssetIdOf<T>,
+			assets: Vec<AssetIdOf<T>>,
+			share_tokens: Balance,
+			hub_reserve: Balance,
+		},
+		/// Asset was moved from Omnipool to a subpool.
+		AssetMigrated {
+			asset_id: AssetIdOf<T>,
+			pool_id: AssetIdOf<T>,
+			amount: Balance,
+			share_tokens: Balance,
+		},
+		/// LP position was converted to position of subpool share asset.
+		PositionMigrated {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			pool_id: AssetIdOf<T>,
+			amount: Balance,
+			shares: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Hub asset and native asset cannot be moved to a subpool.
+		NotAllowed,
+		/// Share asset has already been issued.
+		ShareAssetInUse,
+		/// Asset of the position has not been moved to a subpool.
+		AssetNotMigrated,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Move Omnipool assets into a new subpool.
+		///
+		/// Creates Stableswap pool of given assets and transfers reserves of the assets from Omnipool account
+		/// to the pool account. Share tokens matching hub asset reserves of the assets are minted to Omnipool account
+		/// and share asset is added to Omnipool. Assets are removed from Omnipool.
+		///
+		/// LP positions of the assets can be converted with `migrate_position` afterwards.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `origin`: must pass `AuthorityOrigin` check.
+		/// - `share_asset`: registered asset used as share asset of the subpool. It must not be issued yet.
+		/// - `assets`: Omnipool assets to move into the subpool.
+		/// - `amplification`: amplification of the subpool.
+		/// - `fee`: trade fee of the subpool.
+		/// - `weight_cap`: weight cap of the share asset in Omnipool.
+		///
+		/// Emits `AssetMigrated` event for each asset and `SubpoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_subpool(assets.len() as u32))]
+		pub fn create_subpool(
+			origin: OriginFor<T>,
+			share_asset: AssetIdOf<T>,
+			assets: Vec<AssetIdOf<T>>,
+			amplification: u16,
+			fee: Permill,
+			weight_cap: Permill,
+		) -> DispatchResult {
+			<T as Config>::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!assets.contains(&<T as pallet_omnipool::Config>::HubAssetId::get())
+					&& !assets.contains(&<T as pallet_omnipool::Config>::HdxAssetId::get()),
+				Error::<T>::NotAllowed
+			);
+			ensure!(
+				<T as pallet_omnipool::Config>::Currency::total_issuance(share_asset).is_zero(),
+				Error::<T>::ShareAssetInUse
+			);
+
+			let amplification =
+				NonZeroU16::new(amplification).ok_or(pallet_stableswap::Error::<T>::InvalidAmplification)?;
+
+			// Pool validates the assets - there are at least two unique registered assets.
+			StableswapPallet::<T>::do_create_pool(share_asset, &assets, amplification, fee)?;
+
+			let asset_states = assets
+				.iter()
+				.map(|asset_id| OmnipoolPallet::<T>::load_asset_state(*asset_id))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			let mut subpool_state =
+				create_subpool_initial_state(&(&asset_states[0]).into(), &(&asset_states[1]).into())
+					.ok_or(ArithmeticError::Overflow)?;
+
+			let omnipool_account = OmnipoolPallet::<T>::protocol_account();
+			let pool_account = StableswapPallet::<T>::pool_account(share_asset);
+
+			for (idx, (asset_id, asset_state)) in assets.iter().zip(asset_states.iter()).enumerate() {
+				// Initial state of the subpool already contains first two assets.
+				// Share issuance always equals reserve of the share asset in Omnipool.
+				let (details, state_change) = if idx < 2 {
+					calculate_asset_migration_details(&asset_state.into(), None, Balance::zero())
+				} else {
+					calculate_asset_migration_details(&asset_state.into(), Some(&subpool_state), subpool_state.reserve)
+				}
+				.ok_or(ArithmeticError::Overflow)?;
+
+				if let Some(state_change) = state_change {
+					subpool_state = subpool_state
+						.delta_update(&state_change)
+						.ok_or(ArithmeticError::Overflow)?;
+				}
+
+				<T as pallet_omnipool::Config>::Currency::transfer(
+					*asset_id,
+					&omnipool_account,
+					&pool_account,
+					asset_state.reserve,
+				)?;
+				OmnipoolPallet::<T>::remove_asset(*asset_id)?;
+
+				let share_tokens = details.share_tokens;
+				MigratedAssets::<T>::insert(asset_id, AssetDetail::new(share_asset, details));
+
+				Self::deposit_event(Event::AssetMigrated {
+					asset_id: *asset_id,
+					pool_id: share_asset,
+					amount: asset_state.reserve,
+					share_tokens,
+				});
+			}
+
+			let share_tokens = subpool_state.reserve;
+			let hub_reserve = subpool_state.hub_reserve;
+
+			<T as pallet_omnipool::Config>::Currency::deposit(share_asset, &omnipool_account, share_tokens)?;
+			OmnipoolPallet::<T>::add_asset(
+				share_asset,
+				AssetState::from((subpool_state, weight_cap, Tradability::default())),
+			)?;
+
+			Self::deposit_event(Event::SubpoolCreated {
+				pool_id: share_asset,
+				assets,
+				share_tokens,
+				hub_reserve,
+			});
+
+			Ok(())
+		}
+
+		/// Convert LP position of a migrated asset to position of the subpool share asset.
+		///
+		/// Position keeps its id and NFT.
+		///
+		/// Parameters:
+		/// - `origin`: owner of the position.
+		/// - `position_id`: id of the position to convert.
+		///
+		/// Emits `PositionMigrated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_position())]
+		pub fn migrate_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let position = OmnipoolPallet::<T>::load_position(position_id, who.clone())?;
+			let detail = MigratedAssets::<T>::get(position.asset_id).ok_or(Error::<T>::AssetNotMigrated)?;
+
+			let converted = convert_position((&position).into(), (&detail).into()).ok_or(ArithmeticError::Overflow)?;
+
+			let migrated_position = Position {
+				asset_id: detail.pool_id,
+				amount: converted.amount,
+				shares: converted.shares,
+				price: converted.price,
+			};
+
+			OmnipoolPallet::<T>::set_position(position_id, &migrated_position)?;
+
+			Self::deposit_event(Event::PositionMigrated {
+				position_id,
+				owner: who,
+				asset_id: position.asset_id,
+				pool_id: detail.pool_id,
+				amount: migrated_position.amount,
+				shares: migrated_position.shares,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Returns subpool the asset has been moved to.
+	pub fn subpool_of(asset_id: AssetIdOf<T>) -> Option<AssetIdOf<T>> {
+		MigratedAssets::<T>::get(asset_id).map(|detail| detail.pool_id)
+	}
+}
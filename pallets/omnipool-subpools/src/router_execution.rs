@@ -0,0 +1,211 @@
+use crate::{AssetIdOf, Config, MigratedAssets, Pallet};
+use frame_system::pallet_prelude::OriginFor;
+use hydradx_traits::router::{ExecutorError, PoolType, TradablePairs, Trade, TradeExecution};
+use pallet_omnipool::types::Balance;
+use sp_runtime::DispatchError;
+use sp_std::collections::btree_map::BTreeMap;
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+/// AMMs which execute parts of a trade through subpools.
+type Executor<T> = (pallet_omnipool::Pallet<T>, pallet_stableswap::Pallet<T>);
+
+impl<T: Config> Pallet<T> {
+	/// Splits trade of `asset_in` for `asset_out` into trades executed directly in Omnipool and subpools.
+	///
+	/// Asset of a subpool is traded for the subpool share asset first, share asset is then traded in Omnipool.
+	pub fn trade_route(asset_in: AssetIdOf<T>, asset_out: AssetIdOf<T>) -> Vec<Trade<AssetIdOf<T>>> {
+		let omnipool_trade = |asset_in, asset_out| Trade {
+			pool: PoolType::Omnipool,
+			asset_in,
+			asset_out,
+		};
+		let subpool_trade = |pool_id, asset_in, asset_out| Trade {
+			pool: PoolType::Stableswap(pool_id),
+			asset_in,
+			asset_out,
+		};
+
+		match (Self::subpool_of(asset_in), Self::subpool_of(asset_out)) {
+			(None, None) => vec![omnipool_trade(asset_in, asset_out)],
+			(Some(pool_in), None) if pool_in == asset_out => vec![subpool_trade(pool_in, asset_in, asset_out)],
+			(Some(pool_in), None) => vec![
+				subpool_trade(pool_in, asset_in, pool_in),
+				omnipool_trade(pool_in, asset_out),
+			],
+			(None, Some(pool_out)) if pool_out == asset_in => vec![subpool_trade(pool_out, asset_in, asset_out)],
+			(None, Some(pool_out)) => vec![
+				omnipool_trade(asset_in, pool_out),
+				subpool_trade(pool_out, pool_out, asset_out),
+			],
+			(Some(pool_in), Some(pool_out)) if pool_in == pool_out => {
+				vec![subpool_trade(pool_in, asset_in, asset_out)]
+			}
+			(Some(pool_in), Some(pool_out)) => vec![
+				subpool_trade(pool_in, asset_in, pool_in),
+				omnipool_trade(pool_in, pool_out),
+				subpool_trade(pool_out, pool_out, asset_out),
+			],
+		}
+	}
+
+	/// Number of subpool trades needed to trade `asset_in` for `asset_out`.
+	pub fn subpool_hops(asset_in: AssetIdOf<T>, asset_out: AssetIdOf<T>) -> u64 {
+		Self::trade_route(asset_in, asset_out)
+			.iter()
+			.filter(|trade| matches!(trade.pool, PoolType::Stableswap(_)))
+			.count() as u64
+	}
+}
+
+impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, AssetIdOf<T>, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<AssetIdOf<T>>,
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Omnipool {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		Self::trade_route(asset_in, asset_out)
+			.iter()
+			.try_fold(amount_in, |amount, trade| {
+				Executor::<T>::calculate_sell(trade.pool, trade.asset_in, trade.asset_out, amount)
+			})
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<AssetIdOf<T>>,
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Omnipool {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		Self::trade_route(asset_in, asset_out)
+			.iter()
+			.rev()
+			.try_fold(amount_out, |amount, trade| {
+				Executor::<T>::calculate_buy(trade.pool, trade.asset_in, trade.asset_out, amount)
+			})
+	}
+
+	fn execute_sell(
+		who: OriginFor<T>,
+		pool_type: PoolType<AssetIdOf<T>>,
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Omnipool {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let route = Self::trade_route(asset_in, asset_out);
+		let last_trade_idx = route.len().saturating_sub(1);
+
+		let mut amount = amount_in;
+		for (idx, trade) in route.into_iter().enumerate() {
+			let amount_out = Executor::<T>::calculate_sell(trade.pool, trade.asset_in, trade.asset_out, amount)?;
+			let limit = if idx == last_trade_idx { min_limit } else { amount_out };
+
+			Executor::<T>::execute_sell(who.clone(), trade.pool, trade.asset_in, trade.asset_out, amount, limit)?;
+
+			amount = amount_out;
+		}
+
+		Ok(())
+	}
+
+	fn execute_buy(
+		who: OriginFor<T>,
+		pool_type: PoolType<AssetIdOf<T>>,
+		asset_in: AssetIdOf<T>,
+		asset_out: AssetIdOf<T>,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Omnipool {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let route = Self::trade_route(asset_in, asset_out);
+
+		// amounts[idx] is the amount in of the idx-th trade and amount out of the previous one
+		let mut amounts = vec![amount_out; route.len().saturating_add(1)];
+		for (idx, trade) in route.iter().enumerate().rev() {
+			amounts[idx] = Executor::<T>::calculate_buy(trade.pool, trade.asset_in, trade.asset_out, amounts[idx + 1])?;
+		}
+
+		for (idx, trade) in route.into_iter().enumerate() {
+			let limit = if idx == 0 { max_limit } else { amounts[idx] };
+
+			Executor::<T>::execute_buy(
+				who.clone(),
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				amounts[idx + 1],
+				limit,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<AssetIdOf<T>>,
+		asset_a: AssetIdOf<T>,
+		asset_b: AssetIdOf<T>,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Omnipool {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		match Self::subpool_of(asset_a) {
+			Some(pool_id) => Executor::<T>::get_liquidity_depth(PoolType::Stableswap(pool_id), asset_a, asset_b),
+			None => Executor::<T>::get_liquidity_depth(pool_type, asset_a, asset_b),
+		}
+	}
+}
+
+impl<T: Config> TradablePairs<AssetIdOf<T>> for Pallet<T> {
+	fn tradable_pairs() -> Vec<Trade<AssetIdOf<T>>> {
+		let mut subpool_assets = BTreeMap::<AssetIdOf<T>, Vec<AssetIdOf<T>>>::new();
+		for (asset_id, detail) in MigratedAssets::<T>::iter() {
+			subpool_assets.entry(detail.pool_id).or_default().push(asset_id);
+		}
+
+		// Omnipool asset together with assets of its subpool if the asset is a subpool share asset
+		let with_subpool_assets = |asset_id: AssetIdOf<T>| {
+			let mut assets = vec![asset_id];
+			if let Some(migrated) = subpool_assets.get(&asset_id) {
+				assets.extend(migrated.iter().copied());
+			}
+			assets
+		};
+
+		let mut trades = Vec::new();
+
+		for trade in pallet_omnipool::Pallet::<T>::tradable_pairs() {
+			for asset_in in with_subpool_assets(trade.asset_in) {
+				for asset_out in with_subpool_assets(trade.asset_out) {
+					trades.push(Trade {
+						pool: PoolType::Omnipool,
+						asset_in,
+						asset_out,
+					});
+				}
+			}
+		}
+
+		trades
+	}
+}
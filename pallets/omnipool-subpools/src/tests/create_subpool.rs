@@ -0,0 +1,192 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn create_subpool_should_move_assets_from_omnipool_to_stableswap_pool() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		let omnipool_account = Omnipool::protocol_account();
+		let usdt_state = Omnipool::load_asset_state(USDT).unwrap();
+		let usdc_state = Omnipool::load_asset_state(USDC).unwrap();
+		let hub_liquidity = Tokens::free_balance(LRNA, &omnipool_account);
+
+		create_usd_subpool();
+
+		assert!(!Omnipool::exists(USDT));
+		assert!(!Omnipool::exists(USDC));
+		assert!(pallet_stableswap::Pools::<Test>::contains_key(SHARE));
+
+		let pool_account = Stableswap::pool_account(SHARE);
+		assert_eq!(Tokens::free_balance(USDT, &pool_account), usdt_state.reserve);
+		assert_eq!(Tokens::free_balance(USDC, &pool_account), usdc_state.reserve);
+		assert_eq!(Tokens::free_balance(USDT, &omnipool_account), 0);
+		assert_eq!(Tokens::free_balance(USDC, &omnipool_account), 0);
+
+		let hub_reserve = usdt_state.hub_reserve + usdc_state.hub_reserve;
+		let share_state = Omnipool::load_asset_state(SHARE).unwrap();
+		assert_eq!(share_state.hub_reserve, hub_reserve);
+		assert_eq!(share_state.reserve, hub_reserve);
+		assert_eq!(share_state.shares, hub_reserve);
+		assert_eq!(Tokens::total_issuance(SHARE), hub_reserve);
+		assert_eq!(Tokens::free_balance(LRNA, &omnipool_account), hub_liquidity);
+
+		assert_eq!(
+			OmnipoolSubpools::migrated_asset(USDT),
+			Some(AssetDetail {
+				pool_id: SHARE,
+				price: (usdt_state.hub_reserve, usdt_state.reserve),
+				shares: usdt_state.shares,
+				hub_reserve: usdt_state.hub_reserve,
+				share_tokens: usdt_state.hub_reserve,
+			})
+		);
+
+		assert!(has_event(
+			Event::<Test>::SubpoolCreated {
+				pool_id: SHARE,
+				assets: vec![USDT, USDC],
+				share_tokens: hub_reserve,
+				hub_reserve,
+			}
+			.into()
+		));
+		assert!(has_event(
+			Event::<Test>::AssetMigrated {
+				asset_id: USDC,
+				pool_id: SHARE,
+				amount: usdc_state.reserve,
+				share_tokens: usdc_state.hub_reserve,
+			}
+			.into()
+		));
+	});
+}
+
+#[test]
+fn create_subpool_should_keep_share_issuance_equal_to_reserve_when_more_assets_are_moved() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		let omnipool_account = Omnipool::protocol_account();
+		let hub_reserve = [USDT, USDC, USDD]
+			.iter()
+			.map(|asset_id| Omnipool::load_asset_state(*asset_id).unwrap().hub_reserve)
+			.sum::<Balance>();
+		let hub_liquidity = Tokens::free_balance(LRNA, &omnipool_account);
+
+		assert_ok!(OmnipoolSubpools::create_subpool(
+			RuntimeOrigin::root(),
+			SHARE,
+			vec![USDT, USDC, USDD],
+			100,
+			Permill::zero(),
+			Permill::from_percent(100),
+		));
+
+		assert!(!Omnipool::exists(USDD));
+		assert_eq!(OmnipoolSubpools::subpool_of(USDD), Some(SHARE));
+
+		let share_state = Omnipool::load_asset_state(SHARE).unwrap();
+		assert_eq!(share_state.hub_reserve, hub_reserve);
+		assert_eq!(share_state.reserve, Tokens::total_issuance(SHARE));
+		assert_eq!(Tokens::free_balance(LRNA, &omnipool_account), hub_liquidity);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_origin_is_not_authority() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::signed(LP1),
+				SHARE,
+				vec![USDT, USDC],
+				100,
+				Permill::zero(),
+				Permill::from_percent(100),
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_hub_or_native_asset_is_moved() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE,
+				vec![USDT, LRNA],
+				100,
+				Permill::zero(),
+				Permill::from_percent(100),
+			),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE,
+				vec![HDX, USDT],
+				100,
+				Permill::zero(),
+				Permill::from_percent(100),
+			),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_share_asset_is_issued() {
+	omnipool_with_stablecoins()
+		.add_endowed_accounts((LP1, SHARE, ONE))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE,
+					vec![USDT, USDC],
+					100,
+					Permill::zero(),
+					Permill::from_percent(100),
+				),
+				Error::<Test>::ShareAssetInUse
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_is_not_in_omnipool() {
+	ExtBuilder::default()
+		.with_token(USDT, FixedU128::from(1), LP1, 1_000_000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE,
+					vec![USDT, USDC],
+					100,
+					Permill::zero(),
+					Permill::from_percent(100),
+				),
+				pallet_omnipool::Error::<Test>::AssetNotFound
+			);
+		});
+}
@@ -0,0 +1,107 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+// Positions created by `omnipool_with_stablecoins` - HDX and DAI positions come first.
+const USDT_POSITION: u32 = 2;
+const DOT_POSITION: u32 = 5;
+
+#[test]
+fn migrate_position_should_convert_position_to_share_asset_position() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		let usdt_state = Omnipool::load_asset_state(USDT).unwrap();
+		let position = Omnipool::positions(USDT_POSITION).unwrap();
+
+		create_usd_subpool();
+
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			USDT_POSITION
+		));
+
+		let migrated_position = Omnipool::positions(USDT_POSITION).unwrap();
+		let expected_shares = position.shares * usdt_state.hub_reserve / usdt_state.shares;
+
+		assert_eq!(migrated_position.asset_id, SHARE);
+		assert_eq!(migrated_position.shares, expected_shares);
+		assert_eq!(
+			migrated_position.amount,
+			position.amount * usdt_state.hub_reserve / usdt_state.shares
+		);
+		assert_eq!(last_position_id(), DOT_POSITION + 1);
+
+		assert!(has_event(
+			Event::<Test>::PositionMigrated {
+				position_id: USDT_POSITION,
+				owner: LP1,
+				asset_id: USDT,
+				pool_id: SHARE,
+				amount: migrated_position.amount,
+				shares: expected_shares,
+			}
+			.into()
+		));
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_origin_is_not_position_owner() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		create_usd_subpool();
+
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP2), USDT_POSITION),
+			pallet_omnipool::Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_asset_was_not_migrated() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		create_usd_subpool();
+
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP2), DOT_POSITION),
+			Error::<Test>::AssetNotMigrated
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_return_share_asset_when_position_is_migrated() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		create_usd_subpool();
+
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			USDT_POSITION
+		));
+		let position = Omnipool::positions(USDT_POSITION).unwrap();
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			USDT_POSITION,
+			position.shares
+		));
+
+		assert!(Omnipool::positions(USDT_POSITION).is_none());
+		assert_eq!(Tokens::free_balance(SHARE, &LP1), position.amount);
+		assert_eq!(Tokens::free_balance(LRNA, &LP1), 0);
+	});
+}
@@ -0,0 +1,52 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use mock::*;
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{traits::BadOrigin, FixedU128};
+
+pub fn has_event(event: mock::RuntimeEvent) -> bool {
+	System::events().iter().any(|record| record.event == event)
+}
+
+/// Omnipool with HDX, DAI, DOT and three stablecoins, each stablecoin provided by LP1.
+pub fn omnipool_with_stablecoins() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_token(USDT, FixedU128::from_float(0.9), LP1, 1_000_000 * ONE)
+		.with_token(USDC, FixedU128::from(1), LP1, 2_000_000 * ONE)
+		.with_token(USDD, FixedU128::from_float(1.1), LP1, 500_000 * ONE)
+		.with_token(DOT, FixedU128::from(5), LP2, 100_000 * ONE)
+		.add_endowed_accounts((TRADER, HDX, 1_000_000 * ONE))
+		.add_endowed_accounts((TRADER, USDT, 1_000_000 * ONE))
+}
+
+/// Moves USDT and USDC to a subpool with SHARE share asset.
+pub fn create_usd_subpool() {
+	assert_ok!(OmnipoolSubpools::create_subpool(
+		RuntimeOrigin::root(),
+		SHARE,
+		vec![USDT, USDC],
+		100,
+		Permill::zero(),
+		Permill::from_percent(100),
+	));
+}
+
+pub mod create_subpool;
+pub mod migrate_position;
+pub mod mock;
+pub mod trades;
@@ -0,0 +1,201 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use hydradx_traits::router::{ExecutorError, PoolType, TradablePairs, Trade, TradeExecution};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn trade_route_should_go_through_subpool_when_asset_is_migrated() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		create_usd_subpool();
+
+		assert_eq!(
+			OmnipoolSubpools::trade_route(HDX, USDT),
+			vec![
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: SHARE,
+				},
+				Trade {
+					pool: PoolType::Stableswap(SHARE),
+					asset_in: SHARE,
+					asset_out: USDT,
+				},
+			]
+		);
+		assert_eq!(
+			OmnipoolSubpools::trade_route(USDT, USDC),
+			vec![Trade {
+				pool: PoolType::Stableswap(SHARE),
+				asset_in: USDT,
+				asset_out: USDC,
+			}]
+		);
+		assert_eq!(OmnipoolSubpools::subpool_hops(HDX, DOT), 0);
+		assert_eq!(OmnipoolSubpools::subpool_hops(USDT, DOT), 1);
+	});
+}
+
+#[test]
+fn sell_should_work_when_asset_out_is_in_subpool() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		create_usd_subpool();
+
+		let amount_in = 1_000 * ONE;
+		let expected_out = OmnipoolSubpools::calculate_sell(PoolType::Omnipool, HDX, USDT, amount_in).unwrap();
+		let hdx_balance = Tokens::free_balance(HDX, &TRADER);
+		let usdt_balance = Tokens::free_balance(USDT, &TRADER);
+
+		assert_ok!(OmnipoolSubpools::execute_sell(
+			RuntimeOrigin::signed(TRADER),
+			PoolType::Omnipool,
+			HDX,
+			USDT,
+			amount_in,
+			expected_out,
+		));
+
+		assert!(expected_out > 0);
+		assert_eq!(Tokens::free_balance(HDX, &TRADER), hdx_balance - amount_in);
+		assert_eq!(Tokens::free_balance(USDT, &TRADER), usdt_balance + expected_out);
+		assert_eq!(Tokens::free_balance(SHARE, &TRADER), 0);
+	});
+}
+
+#[test]
+fn sell_should_work_when_asset_in_is_in_subpool() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		create_usd_subpool();
+
+		let amount_in = 1_000 * ONE;
+		let expected_out = OmnipoolSubpools::calculate_sell(PoolType::Omnipool, USDT, DOT, amount_in).unwrap();
+		let usdt_balance = Tokens::free_balance(USDT, &TRADER);
+
+		assert_ok!(OmnipoolSubpools::execute_sell(
+			RuntimeOrigin::signed(TRADER),
+			PoolType::Omnipool,
+			USDT,
+			DOT,
+			amount_in,
+			expected_out,
+		));
+
+		assert!(expected_out > 0);
+		assert_eq!(Tokens::free_balance(USDT, &TRADER), usdt_balance - amount_in);
+		assert_eq!(Tokens::free_balance(DOT, &TRADER), expected_out);
+		assert_eq!(Tokens::free_balance(SHARE, &TRADER), 0);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_limit_is_not_reached() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		create_usd_subpool();
+
+		let amount_in = 1_000 * ONE;
+		let expected_out = OmnipoolSubpools::calculate_sell(PoolType::Omnipool, HDX, USDT, amount_in).unwrap();
+
+		assert!(OmnipoolSubpools::execute_sell(
+			RuntimeOrigin::signed(TRADER),
+			PoolType::Omnipool,
+			HDX,
+			USDT,
+			amount_in,
+			expected_out + 1,
+		)
+		.is_err());
+	});
+}
+
+#[test]
+fn buy_should_work_when_asset_out_is_in_subpool() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		create_usd_subpool();
+
+		let amount_out = 1_000 * ONE;
+		let max_in = OmnipoolSubpools::calculate_buy(PoolType::Omnipool, HDX, USDC, amount_out).unwrap();
+		let hdx_balance = Tokens::free_balance(HDX, &TRADER);
+
+		assert_ok!(OmnipoolSubpools::execute_buy(
+			RuntimeOrigin::signed(TRADER),
+			PoolType::Omnipool,
+			HDX,
+			USDC,
+			amount_out,
+			max_in,
+		));
+
+		assert_eq!(Tokens::free_balance(USDC, &TRADER), amount_out);
+		assert!(Tokens::free_balance(HDX, &TRADER) >= hdx_balance - max_in);
+	});
+}
+
+#[test]
+fn trade_should_be_executed_in_subpool_when_both_assets_are_in_same_subpool() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		create_usd_subpool();
+
+		let amount_in = 1_000 * ONE;
+		let expected_out = Stableswap::calculate_sell(PoolType::Stableswap(SHARE), USDT, USDC, amount_in).unwrap();
+
+		assert_eq!(
+			OmnipoolSubpools::calculate_sell(PoolType::Omnipool, USDT, USDC, amount_in).unwrap(),
+			expected_out
+		);
+
+		assert_ok!(OmnipoolSubpools::execute_sell(
+			RuntimeOrigin::signed(TRADER),
+			PoolType::Omnipool,
+			USDT,
+			USDC,
+			amount_in,
+			expected_out,
+		));
+
+		assert_eq!(Tokens::free_balance(USDC, &TRADER), expected_out);
+	});
+}
+
+#[test]
+fn trade_should_not_be_supported_when_pool_type_is_not_omnipool() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		create_usd_subpool();
+
+		assert_eq!(
+			OmnipoolSubpools::calculate_sell(PoolType::Stableswap(SHARE), USDT, USDC, ONE),
+			Err(ExecutorError::NotSupported)
+		);
+	});
+}
+
+#[test]
+fn tradable_pairs_should_contain_subpool_assets() {
+	omnipool_with_stablecoins().build().execute_with(|| {
+		create_usd_subpool();
+
+		let pairs = OmnipoolSubpools::tradable_pairs();
+
+		for (asset_in, asset_out) in [(HDX, USDT), (USDC, DOT), (SHARE, USDD), (DAI, USDC)] {
+			assert!(pairs.contains(&Trade {
+				pool: PoolType::Omnipool,
+				asset_in,
+				asset_out,
+			}));
+		}
+	});
+}
@@ -0,0 +1,46 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use hydra_dx_math::omnipool_subpools::types::MigrationDetails;
+use pallet_omnipool::types::Balance;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Details of an asset migrated from Omnipool to a subpool.
+///
+/// Stored values are snapshot of the Omnipool asset state at the time of migration and are used to convert
+/// existing LP positions of the asset to positions of the subpool share asset.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AssetDetail<AssetId> {
+	/// Subpool ( share asset ) the asset has been migrated to.
+	pub pool_id: AssetId,
+	/// Price of the asset in hub asset at the time of migration - ( hub reserve, asset reserve ).
+	pub price: (Balance, Balance),
+	/// Quantity of Omnipool LP shares of the asset at the time of migration.
+	pub shares: Balance,
+	/// Quantity of hub asset matching the asset at the time of migration.
+	pub hub_reserve: Balance,
+	/// Quantity of subpool share tokens minted for the asset reserve.
+	pub share_tokens: Balance,
+}
+
+impl<AssetId> AssetDetail<AssetId> {
+	pub fn new(pool_id: AssetId, details: MigrationDetails) -> Self {
+		Self {
+			pool_id,
+			price: details.price,
+			shares: details.shares,
+			hub_reserve: details.hub_reserve,
+			share_tokens: details.share_tokens,
+		}
+	}
+}
+
+impl<AssetId> From<&AssetDetail<AssetId>> for MigrationDetails {
+	fn from(detail: &AssetDetail<AssetId>) -> Self {
+		Self {
+			price: detail.price,
+			shares: detail.shares,
+			hub_reserve: detail.hub_reserve,
+			share_tokens: detail.share_tokens,
+		}
+	}
+}
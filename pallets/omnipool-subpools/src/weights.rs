@@ -0,0 +1,135 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_omnipool_subpools
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-20, STEPS: 10, REPEAT: 30, LOW RANGE: [], HIGH RANGE: []
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// target/release/hydradx
+// benchmark
+// pallet
+// --chain=dev
+// --steps=10
+// --repeat=30
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --template=.maintain/pallet-weight-template.hbs
+// --pallet=pallet-omnipool-subpools
+// --output=pallets/omnipool-subpools/src/weights.rs
+// --extrinsic=*
+
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_omnipool_subpools.
+pub trait WeightInfo {
+	fn create_subpool(n: u32) -> Weight;
+	fn migrate_position() -> Weight;
+}
+
+/// Weights for pallet_omnipool_subpools using the hydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Stableswap Pools (r:1 w:1)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:6 w:0)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:6 w:6)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:11 w:11)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Duster AccountBlacklist (r:0 w:1)
+	// Proof: Duster AccountBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: OmnipoolSubpools MigratedAssets (r:0 w:5)
+	// Proof: OmnipoolSubpools MigratedAssets (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 5]`.
+	fn create_subpool(n: u32) -> Weight {
+		// Minimum execution time: 152_301 nanoseconds.
+		Weight::from_ref_time(98_411_562 as u64) // Standard Error: 48_215
+			.saturating_add(Weight::from_ref_time(28_746_103 as u64).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().reads((4 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().writes((4 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:1 w:1)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: OmnipoolSubpools MigratedAssets (r:1 w:0)
+	// Proof: OmnipoolSubpools MigratedAssets (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	fn migrate_position() -> Weight {
+		// Minimum execution time: 41_873 nanoseconds.
+		Weight::from_ref_time(42_532_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Stableswap Pools (r:1 w:1)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:6 w:0)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:6 w:6)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:11 w:11)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Duster AccountBlacklist (r:0 w:1)
+	// Proof: Duster AccountBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: OmnipoolSubpools MigratedAssets (r:0 w:5)
+	// Proof: OmnipoolSubpools MigratedAssets (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	/// The range of component `n` is `[2, 5]`.
+	fn create_subpool(n: u32) -> Weight {
+		// Minimum execution time: 152_301 nanoseconds.
+		Weight::from_ref_time(98_411_562) // Standard Error: 48_215
+			.saturating_add(Weight::from_ref_time(28_746_103).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(5))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n as u64)))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:1 w:1)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: OmnipoolSubpools MigratedAssets (r:1 w:0)
+	// Proof: OmnipoolSubpools MigratedAssets (max_values: None, max_size: Some(104), added: 2579, mode: MaxEncodedLen)
+	fn migrate_position() -> Weight {
+		// Minimum execution time: 41_873 nanoseconds.
+		Weight::from_ref_time(42_532_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+}
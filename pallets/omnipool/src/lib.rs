@@ -60,7 +60,13 @@
 //! * `set_asset_tradable_state` - Updates state of an asset in the pool to allow/disallow trading.
 //! * `add_token` - Adds token to the pool. Initial liquidity must be transffered to pool account prior to calling add_token.
 //! * `add_liquidity` - Adds liquidity of selected asset to the pool. Mints corresponding position NFT.
+//! * `add_liquidity_with_limit` - Same as `add_liquidity`, fails if received shares are below given limit.
 //! * `remove_liquidity` - Removes liquidity of selected position from the pool. Partial withdrawals are allowed.
+//! * `remove_liquidity_with_limit` - Same as `remove_liquidity`, fails if received amounts are below given limits.
+//! * `merge_positions` - Merges two positions of the same asset into one.
+//! * `split_position` - Splits given amount of shares off a position into a new position.
+//! * `set_tvl_cap` - Updates total value locked cap of Omnipool.
+//! * `set_imbalance_buyback_budget` - Updates per block budget used to buy back and burn hub asset to reduce its imbalance.
 //! * `sell` - Trades an asset in for asset out by selling given amount of asset in.
 //! * `buy` - Trades an asset in for asset out by buying given amount of asset out.
 //! * `set_asset_tradable_state` - Updates asset's tradable asset with new flags. This allows/forbids asset operation such SELL,BUY,ADD or  REMOVE liquidtityy.
@@ -78,7 +84,7 @@ use sp_std::ops::{Add, Sub};
 use sp_std::prelude::*;
 
 use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
-use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate, I129};
+use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate, HubTradeStateChange, I129};
 use hydradx_traits::Registry;
 use orml_traits::{GetByKey, MultiCurrency};
 use scale_info::TypeInfo;
@@ -155,6 +161,13 @@ pub mod pallet {
 		#[pallet::constant]
 		type HubAssetId: Get<Self::AssetId>;
 
+		/// Account whose funds are used to buy back hub asset to reduce its negative imbalance
+		type ImbalanceBuybackAccount: Get<Self::AccountId>;
+
+		/// Asset which is sold to Omnipool to buy back hub asset
+		#[pallet::constant]
+		type ImbalanceBuybackAssetId: Get<Self::AssetId>;
+
 		/// Asset and Protocol Fee for given asset
 		type Fee: GetByKey<Self::AssetId, (Permill, Permill)>;
 
@@ -242,6 +255,18 @@ pub mod pallet {
 	/// Position ids sequencer
 	pub(super) type NextPositionId<T: Config> = StorageValue<_, T::PositionItemId, ValueQuery>;
 
+	#[pallet::storage]
+	/// Stable asset and maximum total value locked in Omnipool expressed in this asset.
+	/// TVL is not capped if not set.
+	#[pallet::getter(fn tvl_cap)]
+	pub(super) type TvlCap<T: Config> = StorageValue<_, (T::AssetId, Balance), OptionQuery>;
+
+	#[pallet::storage]
+	/// Maximum amount of `ImbalanceBuybackAssetId` which is sold per block to buy back hub asset.
+	/// Buyback is disabled if zero.
+	#[pallet::getter(fn imbalance_buyback_budget)]
+	pub(super) type ImbalanceBuybackBudget<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -335,6 +360,19 @@ pub mod pallet {
 
 		/// Asset's weight cap has been updated.
 		AssetWeightCapUpdated { asset_id: T::AssetId, cap: Permill },
+
+		/// Total value locked cap has been updated.
+		TvlCapUpdated { stable_asset: T::AssetId, cap: Balance },
+
+		/// Per block budget of hub asset buyback has been updated.
+		ImbalanceBuybackBudgetUpdated { budget: Balance },
+
+		/// Hub asset has been bought back and burned to reduce imbalance.
+		ImbalanceReduced {
+			asset_id: T::AssetId,
+			amount: Balance,
+			hub_amount_burned: Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -406,6 +444,12 @@ pub mod pallet {
 		StableAssetCannotBeRemoved,
 		/// Calculated amount out from sell trade is zero.
 		ZeroAmountOut,
+		/// Received amount of shares, asset or hub asset is below the provided limit.
+		SlippageLimit,
+		/// Positions of different assets or the same position cannot be merged.
+		PositionsNotMergeable,
+		/// Total value locked cap has been exceeded.
+		TvlCapExceeded,
 	}
 
 	#[pallet::call]
@@ -559,6 +603,33 @@ pub mod pallet {
 		)]
 		#[transactional]
 		pub fn add_liquidity(origin: OriginFor<T>, asset: T::AssetId, amount: Balance) -> DispatchResult {
+			Self::add_liquidity_with_limit(origin, asset, amount, Balance::zero())
+		}
+
+		/// Add liquidity of asset `asset` in quantity `amount` to Omnipool with slippage protection.
+		///
+		/// Same as `add_liquidity`, but fails with `SlippageLimit` error
+		/// if LP would receive less than `min_shares_limit` shares.
+		///
+		/// Parameters:
+		/// - `asset`: The identifier of the new asset added to the pool. Must be already in the pool
+		/// - `amount`: Amount of asset added to omnipool
+		/// - `min_shares_limit`: Minimum amount of shares LP must receive
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		///
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()
+			.saturating_add(T::ExternalPriceOracle::get_price_weight()))
+		)]
+		#[transactional]
+		pub fn add_liquidity_with_limit(
+			origin: OriginFor<T>,
+			asset: T::AssetId,
+			amount: Balance,
+			min_shares_limit: Balance,
+		) -> DispatchResult {
 			//
 			// Preconditions
 			//
@@ -607,6 +678,11 @@ pub mod pallet {
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
+			ensure!(
+				*state_changes.asset.delta_shares >= min_shares_limit,
+				Error::<T>::SlippageLimit
+			);
+
 			let new_asset_state = asset_state
 				.clone()
 				.delta_update(&state_changes.asset)
@@ -625,6 +701,25 @@ pub mod pallet {
 				Error::<T>::AssetWeightCapExceeded
 			);
 
+			if let Some((stable_asset_id, tvl_cap)) = <TvlCap<T>>::get() {
+				let stable_asset = if asset == stable_asset_id {
+					(new_asset_state.reserve, new_asset_state.hub_reserve)
+				} else {
+					// Stable asset could have been removed from the pool after the cap was set.
+					Self::stable_asset(stable_asset_id)?
+				};
+
+				let updated_tvl = hydra_dx_math::omnipool::calculate_tvl(
+					current_hub_asset_liquidity
+						.checked_add(*state_changes.asset.delta_hub_reserve)
+						.ok_or(ArithmeticError::Overflow)?,
+					stable_asset,
+				)
+				.ok_or(ArithmeticError::Overflow)?;
+
+				ensure!(updated_tvl <= tvl_cap, Error::<T>::TvlCapExceeded);
+			}
+
 			//
 			// Post - update states
 			//
@@ -703,6 +798,32 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			position_id: T::PositionItemId,
 			amount: Balance,
+		) -> DispatchResult {
+			Self::remove_liquidity_with_limit(origin, position_id, amount, Balance::zero(), Balance::zero())
+		}
+
+		/// Remove liquidity of asset `asset` in quantity `amount` from Omnipool with slippage protection.
+		///
+		/// Same as `remove_liquidity`, but fails with `SlippageLimit` error if LP would receive
+		/// less than `min_limit` of the asset or less than `min_hub_limit` of hub asset.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which liquidity is removed from.
+		/// - `amount`: Amount of shares removed from omnipool
+		/// - `min_limit`: Minimum amount of asset LP must receive
+		/// - `min_hub_limit`: Minimum amount of hub asset LP must receive
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		///
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity().saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn remove_liquidity_with_limit(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			amount: Balance,
+			min_limit: Balance,
+			min_hub_limit: Balance,
 		) -> DispatchResult {
 			//
 			// Preconditions
@@ -772,6 +893,11 @@ pub mod pallet {
 			)
 			.ok_or(ArithmeticError::Overflow)?;
 
+			ensure!(
+				*state_changes.asset.delta_reserve >= min_limit && state_changes.lp_hub_amount >= min_hub_limit,
+				Error::<T>::SlippageLimit
+			);
+
 			let new_asset_state = asset_state
 				.clone()
 				.delta_update(&state_changes.asset)
@@ -1399,6 +1525,63 @@ pub mod pallet {
 			})
 		}
 
+		/// Update total value locked cap of Omnipool.
+		///
+		/// TVL is expressed in `stable_asset` which must be in the pool. Adding liquidity which results in TVL
+		/// above the cap fails with `TvlCapExceeded` error. Setting cap to `Balance::MAX` removes the cap.
+		///
+		/// Parameters:
+		/// - `origin`: Authority origin.
+		/// - `stable_asset`: asset id of stable asset TVL is expressed in
+		/// - `cap`: new TVL cap
+		///
+		/// Emits `TvlCapUpdated` event when successful.
+		///
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_tvl_cap())]
+		#[transactional]
+		pub fn set_tvl_cap(origin: OriginFor<T>, stable_asset: T::AssetId, cap: Balance) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Assets::<T>::contains_key(stable_asset), Error::<T>::NoStableAssetInPool);
+
+			if cap == Balance::MAX {
+				<TvlCap<T>>::kill();
+			} else {
+				<TvlCap<T>>::put((stable_asset, cap));
+			}
+
+			Self::deposit_event(Event::TvlCapUpdated { stable_asset, cap });
+
+			Ok(())
+		}
+
+		/// Update per block budget used to buy back hub asset and reduce its negative imbalance.
+		///
+		/// In each block with enough idle weight, up to `budget` of `ImbalanceBuybackAssetId` is sold
+		/// from `ImbalanceBuybackAccount` to Omnipool and bought hub asset is burned.
+		///
+		/// Buyback is disabled if budget is zero.
+		///
+		/// Parameters:
+		/// - `origin`: Authority origin.
+		/// - `budget`: new per block budget
+		///
+		/// Emits `ImbalanceBuybackBudgetUpdated` event when successful.
+		///
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_imbalance_buyback_budget())]
+		#[transactional]
+		pub fn set_imbalance_buyback_budget(origin: OriginFor<T>, budget: Balance) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			<ImbalanceBuybackBudget<T>>::put(budget);
+
+			Self::deposit_event(Event::ImbalanceBuybackBudgetUpdated { budget });
+
+			Ok(())
+		}
+
 		#[pallet::call_index(11)]
 		#[pallet::weight(<T as Config>::WeightInfo::withdraw_protocol_liquidity())]
 		#[transactional]
@@ -1524,6 +1707,136 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Merge two LP positions of the same asset into one.
+		///
+		/// Shares and amounts of both positions are added to position `position_id`. Price of the merged position
+		/// is average of positions' prices weighted by provided amounts.
+		///
+		/// Position `other_position_id` is destroyed and its NFT instance burned.
+		///
+		/// Only owner of both positions can perform this action. Positions deposited in liquidity mining
+		/// are owned by the liquidity mining pallet and are rejected with `Forbidden`. They have to be
+		/// withdrawn first.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which is kept.
+		/// - `other_position_id`: The identifier of position merged into `position_id`.
+		///
+		/// Emits `PositionUpdated` and `PositionDestroyed` events when successful.
+		///
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge_positions())]
+		#[transactional]
+		pub fn merge_positions(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			other_position_id: T::PositionItemId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(position_id != other_position_id, Error::<T>::PositionsNotMergeable);
+
+			let position = Self::load_position(position_id, who.clone())?;
+			let other_position = Self::load_position(other_position_id, who.clone())?;
+
+			ensure!(
+				position.asset_id == other_position.asset_id,
+				Error::<T>::PositionsNotMergeable
+			);
+
+			let merged_position = position.merge(&other_position).ok_or(ArithmeticError::Overflow)?;
+			let price = merged_position
+				.price_from_rational()
+				.ok_or(ArithmeticError::DivisionByZero)?;
+
+			<Positions<T>>::insert(position_id, &merged_position);
+			<Positions<T>>::remove(other_position_id);
+			T::NFTHandler::burn(&T::NFTCollectionId::get(), &other_position_id, Some(&who))?;
+
+			Self::deposit_event(Event::PositionDestroyed {
+				position_id: other_position_id,
+				owner: who.clone(),
+			});
+
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who,
+				asset: merged_position.asset_id,
+				amount: merged_position.amount,
+				shares: merged_position.shares,
+				price,
+			});
+
+			Ok(())
+		}
+
+		/// Split given amount of shares off LP position into a new position.
+		///
+		/// Amount of the position is split proportionally to shares. Both positions keep the original price.
+		///
+		/// New NFT instance is minted to the owner of the position. It can be transferred independently of
+		/// the original position.
+		///
+		/// Amounts of both positions must be at least `MinimumPoolLiquidity`.
+		///
+		/// Only owner of position can perform this action. Positions deposited in liquidity mining
+		/// are owned by the liquidity mining pallet and are rejected with `Forbidden`. They have to be
+		/// withdrawn first.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which is split.
+		/// - `shares`: Amount of shares moved to the new position. Must be less than shares of the position.
+		///
+		/// Emits `PositionUpdated` and `PositionCreated` events when successful.
+		///
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::split_position())]
+		#[transactional]
+		pub fn split_position(origin: OriginFor<T>, position_id: T::PositionItemId, shares: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(shares > Balance::zero(), Error::<T>::InvalidSharesAmount);
+
+			let position = Self::load_position(position_id, who.clone())?;
+
+			ensure!(position.shares > shares, Error::<T>::InsufficientShares);
+
+			let (updated_position, new_position) = position.split(shares).ok_or(ArithmeticError::Overflow)?;
+
+			ensure!(
+				updated_position.amount >= T::MinimumPoolLiquidity::get()
+					&& new_position.amount >= T::MinimumPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidity
+			);
+
+			let price = position.price_from_rational().ok_or(ArithmeticError::DivisionByZero)?;
+
+			let new_position_id = Self::create_and_mint_position_instance(&who)?;
+
+			<Positions<T>>::insert(position_id, &updated_position);
+			<Positions<T>>::insert(new_position_id, &new_position);
+
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who.clone(),
+				asset: updated_position.asset_id,
+				amount: updated_position.amount,
+				shares: updated_position.shares,
+				price,
+			});
+
+			Self::deposit_event(Event::PositionCreated {
+				position_id: new_position_id,
+				owner: who,
+				asset: new_position.asset_id,
+				amount: new_position.amount,
+				shares: new_position.shares,
+				price,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -1541,6 +1854,46 @@ pub mod pallet {
 			);
 			assert_ne!(T::MaxInRatio::get(), Balance::zero(), "MaxInRatio is 0.");
 			assert_ne!(T::MaxOutRatio::get(), Balance::zero(), "MaxOutRatio is 0.");
+			assert_ne!(
+				T::ImbalanceBuybackAssetId::get(),
+				T::HubAssetId::get(),
+				"Hub asset cannot be used to buy back hub asset."
+			);
+		}
+
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let buyback_weight =
+				<T as Config>::WeightInfo::buy_back_hub_asset().saturating_add(T::OmnipoolHooks::on_trade_weight());
+
+			if remaining_weight.any_lt(buyback_weight) {
+				return Weight::zero();
+			}
+
+			let budget = <ImbalanceBuybackBudget<T>>::get();
+			let imbalance = <HubAssetImbalance<T>>::get();
+
+			// Only negative imbalance is reduced by buying back hub asset
+			if budget.is_zero() || imbalance.value.is_zero() || !imbalance.negative {
+				return T::DbWeight::get().reads(2);
+			}
+
+			// budget, imbalance, asset state and balances of the pool and of the buyback account
+			let calculation_weight = T::DbWeight::get().reads(5);
+
+			let (asset_state, state_changes) = match Self::calculate_hub_asset_buyback(budget, imbalance.value) {
+				Ok(Some(buyback)) => buyback,
+				Ok(None) => return calculation_weight,
+				Err(e) => {
+					log::warn!(target: "omnipool::buy_back_hub_asset", "hub asset buyback failed: {:?}", e);
+					return calculation_weight;
+				}
+			};
+
+			if let Err(e) = Self::buy_back_hub_asset(asset_state, state_changes) {
+				log::warn!(target: "omnipool::buy_back_hub_asset", "hub asset buyback failed: {:?}", e);
+			}
+
+			buyback_weight
 		}
 	}
 }
@@ -1551,6 +1904,92 @@ impl<T: Config> Pallet<T> {
 		PalletId(*b"omnipool").into_account_truncating()
 	}
 
+	/// Retrieve reserve and hub reserve of stable asset
+	fn stable_asset(asset_id: T::AssetId) -> Result<(Balance, Balance), DispatchError> {
+		let stable_asset = Self::load_asset_state(asset_id).map_err(|_| Error::<T>::NoStableAssetInPool)?;
+		Ok((stable_asset.reserve, stable_asset.hub_reserve))
+	}
+
+	/// Calculate the state changes of selling up to per block budget of `ImbalanceBuybackAssetId`
+	/// to the pool for hub asset.
+	///
+	/// Returns `None` if no hub asset would be bought back.
+	fn calculate_hub_asset_buyback(
+		budget: Balance,
+		imbalance: Balance,
+	) -> Result<Option<(AssetReserveState<Balance>, HubTradeStateChange<Balance>)>, DispatchError> {
+		let asset_id = T::ImbalanceBuybackAssetId::get();
+
+		let asset_state = Self::load_asset_state(asset_id)?;
+		ensure!(asset_state.tradable.contains(Tradability::SELL), Error::<T>::NotAllowed);
+
+		let max_amount_in = asset_state
+			.reserve
+			.checked_div(T::MaxInRatio::get())
+			.ok_or(ArithmeticError::DivisionByZero)?;
+
+		let amount = budget
+			.min(T::Currency::free_balance(asset_id, &T::ImbalanceBuybackAccount::get()))
+			.min(max_amount_in);
+
+		let state_changes = hydra_dx_math::omnipool::calculate_hub_asset_buyback_state_changes(
+			&(&asset_state).into(),
+			amount,
+			imbalance,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		if (*state_changes.asset.delta_reserve).is_zero() || (*state_changes.asset.delta_hub_reserve).is_zero() {
+			return Ok(None);
+		}
+
+		Ok(Some((asset_state, state_changes)))
+	}
+
+	/// Sell `ImbalanceBuybackAssetId` to the pool for hub asset and burn it to reduce negative imbalance.
+	///
+	/// Hub asset trade hooks are called with `ImbalanceBuybackAccount` as origin, so the trade is
+	/// reflected in the oracle and limited by the circuit breaker like user's trades.
+	///
+	/// No fees are charged. Asset fee is paid to LPs of the bought asset, which is hub asset here and has
+	/// no LPs. Protocol fee is charged in hub asset which is burned in full anyway.
+	#[transactional]
+	fn buy_back_hub_asset(
+		asset_state: AssetReserveState<Balance>,
+		state_changes: HubTradeStateChange<Balance>,
+	) -> DispatchResult {
+		let asset_id = T::ImbalanceBuybackAssetId::get();
+		let account = T::ImbalanceBuybackAccount::get();
+
+		let amount = *state_changes.asset.delta_reserve;
+		let hub_amount = *state_changes.asset.delta_hub_reserve;
+
+		let new_asset_state = asset_state
+			.clone()
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		T::Currency::transfer(asset_id, &account, &Self::protocol_account(), amount)?;
+		T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), hub_amount)?;
+
+		let info: AssetInfo<T::AssetId, Balance> =
+			AssetInfo::new(asset_id, &asset_state, &new_asset_state, &state_changes.asset, false);
+
+		Self::update_imbalance(state_changes.delta_imbalance)?;
+
+		Self::set_asset_state(asset_id, new_asset_state);
+
+		T::OmnipoolHooks::on_hub_asset_trade(frame_system::RawOrigin::Signed(account).into(), info)?;
+
+		Self::deposit_event(Event::ImbalanceReduced {
+			asset_id,
+			amount,
+			hub_amount_burned: hub_amount,
+		});
+
+		Ok(())
+	}
+
 	/// Retrieve state of asset from the pool and its pool balance
 	pub fn load_asset_state(asset_id: T::AssetId) -> Result<AssetReserveState<Balance>, DispatchError> {
 		let state = <Assets<T>>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
@@ -1,12 +1,13 @@
-use crate::types::Balance;
-use crate::{Config, Error, HubAssetImbalance, Pallet};
+use crate::types::{Balance, Tradability};
+use crate::{Assets, Config, Error, HubAssetImbalance, HubAssetTradability, Pallet};
 use frame_system::pallet_prelude::OriginFor;
 use hydra_dx_math::omnipool::types::I129;
 
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, TradablePairs, Trade, TradeExecution};
 use orml_traits::{GetByKey, MultiCurrency};
 use sp_runtime::traits::Get;
 use sp_runtime::{ArithmeticError, DispatchError};
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -163,3 +164,41 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 		Ok(asset_state.reserve)
 	}
 }
+
+impl<T: Config> TradablePairs<T::AssetId> for Pallet<T> {
+	fn tradable_pairs() -> Vec<Trade<T::AssetId>> {
+		let assets: Vec<(T::AssetId, Tradability)> = Assets::<T>::iter()
+			.map(|(asset_id, state)| (asset_id, state.tradable))
+			.collect();
+
+		let hub_asset_sellable = HubAssetTradability::<T>::get().contains(Tradability::SELL);
+
+		let mut trades = Vec::new();
+
+		for (asset_out, asset_out_tradability) in assets.iter() {
+			if !asset_out_tradability.contains(Tradability::BUY) {
+				continue;
+			}
+
+			if hub_asset_sellable {
+				trades.push(Trade {
+					pool: PoolType::Omnipool,
+					asset_in: T::HubAssetId::get(),
+					asset_out: *asset_out,
+				});
+			}
+
+			for (asset_in, asset_in_tradability) in assets.iter() {
+				if asset_in != asset_out && asset_in_tradability.contains(Tradability::SELL) {
+					trades.push(Trade {
+						pool: PoolType::Omnipool,
+						asset_in: *asset_in,
+						asset_out: *asset_out,
+					});
+				}
+			}
+		}
+
+		trades
+	}
+}
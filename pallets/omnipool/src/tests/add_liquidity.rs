@@ -216,3 +216,46 @@ fn add_liquidity_should_fail_when_prices_differ_and_is_lower() {
 			);
 		});
 }
+
+#[test]
+fn add_liquidity_with_limit_should_work_when_shares_are_above_limit() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let liq_added = 400 * ONE;
+			let position_id = last_position_id();
+
+			assert_ok!(Omnipool::add_liquidity_with_limit(
+				RuntimeOrigin::signed(LP1),
+				1_000,
+				liq_added,
+				liq_added
+			));
+
+			let position = Positions::<Test>::get(position_id).unwrap();
+			assert_eq!(position.shares, liq_added);
+			assert_balance!(LP1, 1_000, 4600 * ONE);
+		});
+}
+
+#[test]
+fn add_liquidity_with_limit_should_fail_when_shares_are_below_limit() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let liq_added = 400 * ONE;
+
+			assert_noop!(
+				Omnipool::add_liquidity_with_limit(RuntimeOrigin::signed(LP1), 1_000, liq_added, liq_added + 1),
+				Error::<Test>::SlippageLimit
+			);
+		});
+}
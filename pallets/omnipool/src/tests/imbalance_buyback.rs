@@ -0,0 +1,197 @@
+use super::*;
+use frame_support::assert_noop;
+use frame_support::traits::OnIdle;
+use frame_support::weights::Weight;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn set_imbalance_buyback_budget_should_work_when_origin_is_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(1);
+
+		assert_ok!(Omnipool::set_imbalance_buyback_budget(RuntimeOrigin::root(), 10 * ONE));
+
+		assert_eq!(Omnipool::imbalance_buyback_budget(), 10 * ONE);
+		frame_system::Pallet::<Test>::assert_last_event(
+			crate::Event::ImbalanceBuybackBudgetUpdated { budget: 10 * ONE }.into(),
+		);
+	});
+}
+
+#[test]
+fn set_imbalance_buyback_budget_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Omnipool::set_imbalance_buyback_budget(RuntimeOrigin::signed(LP1), 10 * ONE),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_buy_back_and_burn_hub_asset_when_imbalance_is_negative() {
+	ExtBuilder::default()
+		.add_endowed_accounts((TREASURY, HDX, 1000 * ONE))
+		.add_endowed_accounts((LP3, LRNA, 100 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, DAI, 50 * ONE, ONE));
+			assert_ok!(Omnipool::set_imbalance_buyback_budget(RuntimeOrigin::root(), 10 * ONE));
+
+			let old_imbalance = HubAssetImbalance::<Test>::get();
+			let q = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+			let lrna_issuance = Tokens::total_issuance(LRNA);
+			let hdx_state = Omnipool::load_asset_state(HDX).unwrap();
+
+			Omnipool::on_idle(1, Weight::MAX);
+
+			let hub_amount_burned = 9_990_009_990_009;
+
+			let updated_imbalance = HubAssetImbalance::<Test>::get();
+			let q_minus = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+
+			assert_eq!(updated_imbalance.value, old_imbalance.value - hub_amount_burned);
+			assert!(updated_imbalance.negative);
+			assert_eq!(
+				q.checked_sub(old_imbalance.value).unwrap(),
+				q_minus.checked_sub(updated_imbalance.value).unwrap()
+			);
+			assert_eq!(Tokens::total_issuance(LRNA), lrna_issuance - hub_amount_burned);
+
+			let updated_hdx_state = Omnipool::load_asset_state(HDX).unwrap();
+			assert_eq!(updated_hdx_state.reserve, hdx_state.reserve + 10 * ONE);
+			assert_eq!(updated_hdx_state.hub_reserve, hdx_state.hub_reserve - hub_amount_burned);
+
+			assert_balance!(TREASURY, HDX, 990 * ONE);
+			assert_hub_asset!();
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::ImbalanceReduced {
+					asset_id: HDX,
+					amount: 10 * ONE,
+					hub_amount_burned,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn on_idle_should_not_buy_back_more_than_imbalance() {
+	ExtBuilder::default()
+		.add_endowed_accounts((TREASURY, HDX, 1000 * ONE))
+		.add_endowed_accounts((LP3, LRNA, 100 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, DAI, 5 * ONE, 1));
+			assert_ok!(Omnipool::set_imbalance_buyback_budget(
+				RuntimeOrigin::root(),
+				1000 * ONE
+			));
+
+			let old_imbalance = HubAssetImbalance::<Test>::get();
+			let lrna_issuance = Tokens::total_issuance(LRNA);
+
+			Omnipool::on_idle(1, Weight::MAX);
+
+			let updated_imbalance = HubAssetImbalance::<Test>::get();
+			let hub_amount_burned = lrna_issuance - Tokens::total_issuance(LRNA);
+
+			assert!(hub_amount_burned <= old_imbalance.value);
+			assert_eq!(updated_imbalance.value, old_imbalance.value - hub_amount_burned);
+			assert!(updated_imbalance.negative);
+			assert!(Tokens::free_balance(HDX, &TREASURY) > 0);
+		});
+}
+
+#[test]
+fn on_idle_should_not_buy_back_hub_asset_when_budget_is_not_set() {
+	ExtBuilder::default()
+		.add_endowed_accounts((TREASURY, HDX, 1000 * ONE))
+		.add_endowed_accounts((LP3, LRNA, 100 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, DAI, 50 * ONE, ONE));
+
+			let old_imbalance = HubAssetImbalance::<Test>::get();
+
+			Omnipool::on_idle(1, Weight::MAX);
+
+			assert_eq!(HubAssetImbalance::<Test>::get(), old_imbalance);
+			assert_balance!(TREASURY, HDX, 1000 * ONE);
+		});
+}
+
+#[test]
+fn on_idle_should_not_buy_back_hub_asset_when_remaining_weight_is_not_sufficient() {
+	ExtBuilder::default()
+		.add_endowed_accounts((TREASURY, HDX, 1000 * ONE))
+		.add_endowed_accounts((LP3, LRNA, 100 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, DAI, 50 * ONE, ONE));
+			assert_ok!(Omnipool::set_imbalance_buyback_budget(RuntimeOrigin::root(), 10 * ONE));
+
+			let old_imbalance = HubAssetImbalance::<Test>::get();
+
+			assert_eq!(Omnipool::on_idle(1, Weight::zero()), Weight::zero());
+
+			assert_eq!(HubAssetImbalance::<Test>::get(), old_imbalance);
+			assert_balance!(TREASURY, HDX, 1000 * ONE);
+		});
+}
+
+#[test]
+fn on_idle_should_not_buy_back_hub_asset_when_imbalance_is_not_negative() {
+	ExtBuilder::default()
+		.add_endowed_accounts((TREASURY, HDX, 1000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			let imbalance = SimpleImbalance {
+				value: 10 * ONE,
+				negative: false,
+			};
+			HubAssetImbalance::<Test>::put(imbalance);
+			assert_ok!(Omnipool::set_imbalance_buyback_budget(RuntimeOrigin::root(), 10 * ONE));
+
+			let weight = Omnipool::on_idle(1, Weight::MAX);
+
+			assert_eq!(weight, <Test as frame_system::Config>::DbWeight::get().reads(2));
+			assert_eq!(HubAssetImbalance::<Test>::get(), imbalance);
+			assert_balance!(TREASURY, HDX, 1000 * ONE);
+		});
+}
+
+#[test]
+fn on_idle_should_only_consume_calculation_weight_when_buyback_asset_cannot_be_sold() {
+	ExtBuilder::default()
+		.add_endowed_accounts((TREASURY, HDX, 1000 * ONE))
+		.add_endowed_accounts((LP3, LRNA, 100 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), LRNA, DAI, 50 * ONE, ONE));
+			assert_ok!(Omnipool::set_imbalance_buyback_budget(RuntimeOrigin::root(), 10 * ONE));
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				HDX,
+				Tradability::BUY | Tradability::ADD_LIQUIDITY | Tradability::REMOVE_LIQUIDITY
+			));
+
+			let old_imbalance = HubAssetImbalance::<Test>::get();
+
+			let weight = Omnipool::on_idle(1, Weight::MAX);
+
+			assert_eq!(weight, <Test as frame_system::Config>::DbWeight::get().reads(5));
+			assert_eq!(HubAssetImbalance::<Test>::get(), old_imbalance);
+			assert_balance!(TREASURY, HDX, 1000 * ONE);
+		});
+}
@@ -12,11 +12,13 @@ mod sell;
 
 mod barrier;
 mod imbalance;
+mod imbalance_buyback;
 pub(crate) mod mock;
 mod positions;
 mod refund;
 mod remove_token;
 mod tradability;
+mod tvl_cap;
 mod types;
 mod verification;
 
@@ -122,3 +122,291 @@ fn sacrifice_position_should_emit_event_when_succesful() {
 			);
 		});
 }
+
+#[test]
+fn merge_positions_should_work_when_positions_are_of_same_asset() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			let position = Positions::<Test>::get(position_id).unwrap();
+			let other_position = Positions::<Test>::get(other_position_id).unwrap();
+
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				other_position_id
+			));
+
+			let merged_position = Positions::<Test>::get(position_id).unwrap();
+			assert_eq!(merged_position.asset_id, asset_id);
+			assert_eq!(merged_position.amount, position.amount + other_position.amount);
+			assert_eq!(merged_position.shares, position.shares + other_position.shares);
+			assert_eq!(merged_position.price_from_rational(), position.price_from_rational());
+
+			assert_eq!(Positions::<Test>::get(other_position_id), None);
+			assert_eq!(POSITIONS.with(|v| v.borrow().get(&other_position_id).copied()), None);
+			assert_eq!(POSITIONS.with(|v| v.borrow().get(&position_id).copied()), Some(LP1));
+		});
+}
+
+#[test]
+fn merge_positions_should_weight_price_by_amount_when_prices_differ() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP3, DAI, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_ok!(Omnipool::sell(
+				RuntimeOrigin::signed(LP3),
+				DAI,
+				asset_id,
+				100 * ONE,
+				Balance::zero()
+			));
+
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 100 * ONE));
+
+			let price = Positions::<Test>::get(position_id)
+				.unwrap()
+				.price_from_rational()
+				.unwrap();
+			let other_price = Positions::<Test>::get(other_position_id)
+				.unwrap()
+				.price_from_rational()
+				.unwrap();
+			assert!(other_price > price);
+
+			assert_ok!(Omnipool::merge_positions(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				other_position_id
+			));
+
+			let merged_price = Positions::<Test>::get(position_id)
+				.unwrap()
+				.price_from_rational()
+				.unwrap();
+			assert!(merged_price > price);
+			assert!(merged_price < other_price);
+			// Merged price is closer to the price of the larger position.
+			assert!(merged_price - price < other_price - merged_price);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_positions_are_of_different_assets() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP1, DAI, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), DAI, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::PositionsNotMergeable
+			);
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, position_id),
+				Error::<Test>::PositionsNotMergeable
+			);
+		});
+}
+
+#[test]
+fn merge_positions_should_fail_when_caller_is_not_owner_of_both_positions() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let other_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), asset_id, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn split_position_should_work_when_shares_are_less_than_position_shares() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let position = Positions::<Test>::get(position_id).unwrap();
+
+			let new_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				100 * ONE
+			));
+
+			assert_eq!(
+				Positions::<Test>::get(position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 300 * ONE,
+					shares: 300 * ONE,
+					price: position.price,
+				}
+			);
+			assert_eq!(
+				Positions::<Test>::get(new_position_id).unwrap(),
+				Position {
+					asset_id,
+					amount: 100 * ONE,
+					shares: 100 * ONE,
+					price: position.price,
+				}
+			);
+			assert_eq!(POSITIONS.with(|v| v.borrow().get(&new_position_id).copied()), Some(LP1));
+
+			let lp1_asset_balance = Tokens::free_balance(asset_id, &LP1);
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP1),
+				new_position_id,
+				100 * ONE
+			));
+			assert_eq!(Tokens::free_balance(asset_id, &LP1), lp1_asset_balance + 100 * ONE);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_shares_are_not_less_than_position_shares() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 400 * ONE),
+				Error::<Test>::InsufficientShares
+			);
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 0),
+				Error::<Test>::InvalidSharesAmount
+			);
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP2), position_id, 100 * ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn split_position_should_fail_when_amount_of_any_position_is_below_minimum_pool_liquidity() {
+	let asset_id: AssetId = 1_000;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_min_added_liquidity(100 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 100 * ONE - 1),
+				Error::<Test>::InsufficientLiquidity
+			);
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 300 * ONE + 1),
+				Error::<Test>::InsufficientLiquidity
+			);
+			assert_ok!(Omnipool::split_position(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				100 * ONE
+			));
+		});
+}
+
+#[test]
+fn merge_and_split_positions_should_fail_when_position_is_deposited_in_liquidity_mining() {
+	let asset_id: AssetId = 1_000;
+	let liquidity_mining_account: AccountId = 100;
+
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, asset_id, 5000 * ONE))
+		.add_endowed_accounts((LP2, asset_id, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(asset_id, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+			let deposited_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), asset_id, 400 * ONE));
+
+			// Deposit in liquidity mining transfers the position NFT to the liquidity mining account
+			POSITIONS.with(|v| v.borrow_mut().insert(deposited_position_id, liquidity_mining_account));
+
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, deposited_position_id),
+				Error::<Test>::Forbidden
+			);
+			assert_noop!(
+				Omnipool::merge_positions(RuntimeOrigin::signed(LP1), deposited_position_id, position_id),
+				Error::<Test>::Forbidden
+			);
+			assert_noop!(
+				Omnipool::split_position(RuntimeOrigin::signed(LP1), deposited_position_id, 100 * ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
@@ -1071,3 +1071,98 @@ fn remove_liquidity_should_skip_price_check_when_price_is_lower_and_is_safe_to_w
 			),);
 		});
 }
+
+#[test]
+fn remove_liquidity_with_limit_should_work_when_amounts_are_above_limits() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP3, 1_000, 100 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+			(LP2, DAI, 50000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP3, 100 * ONE)
+		.build()
+		.execute_with(|| {
+			let liq_added = 400 * ONE;
+
+			let current_position_id = <NextPositionId<Test>>::get();
+
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, liq_added));
+
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP2),
+				1_000,
+				DAI,
+				200 * ONE,
+				500000 * ONE
+			));
+
+			let expected_amount = 240 * ONE;
+			let expected_hub_amount = 203_921_568_627_449;
+
+			assert_noop!(
+				Omnipool::remove_liquidity_with_limit(
+					RuntimeOrigin::signed(LP1),
+					current_position_id,
+					liq_added,
+					expected_amount + 1,
+					expected_hub_amount
+				),
+				Error::<Test>::SlippageLimit
+			);
+			assert_noop!(
+				Omnipool::remove_liquidity_with_limit(
+					RuntimeOrigin::signed(LP1),
+					current_position_id,
+					liq_added,
+					expected_amount,
+					expected_hub_amount + 1
+				),
+				Error::<Test>::SlippageLimit
+			);
+
+			assert_ok!(Omnipool::remove_liquidity_with_limit(
+				RuntimeOrigin::signed(LP1),
+				current_position_id,
+				liq_added,
+				expected_amount,
+				expected_hub_amount
+			));
+			assert_balance!(LP1, 1000, 4600 * ONE + expected_amount);
+			assert_balance!(LP1, LRNA, expected_hub_amount);
+		});
+}
+
+#[test]
+fn remove_liquidity_with_limit_should_fail_when_no_hub_asset_is_received_and_hub_limit_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let liq_added = 400 * ONE;
+			let current_position_id = <NextPositionId<Test>>::get();
+
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, liq_added));
+
+			assert_noop!(
+				Omnipool::remove_liquidity_with_limit(
+					RuntimeOrigin::signed(LP1),
+					current_position_id,
+					liq_added,
+					liq_added,
+					1
+				),
+				Error::<Test>::SlippageLimit
+			);
+		});
+}
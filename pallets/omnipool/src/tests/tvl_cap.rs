@@ -0,0 +1,157 @@
+use super::*;
+use crate::types::Tradability;
+use frame_support::assert_noop;
+use sp_runtime::DispatchError::BadOrigin;
+
+#[test]
+fn tvl_cap_should_not_be_set_by_default() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Omnipool::tvl_cap(), None);
+	});
+}
+
+#[test]
+fn set_tvl_cap_should_work_when_origin_is_authority() {
+	ExtBuilder::default()
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(Omnipool::set_tvl_cap(RuntimeOrigin::root(), DAI, 1_000_000 * ONE));
+
+			assert_eq!(Omnipool::tvl_cap(), Some((DAI, 1_000_000 * ONE)));
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::TvlCapUpdated {
+					stable_asset: DAI,
+					cap: 1_000_000 * ONE,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn set_tvl_cap_should_remove_cap_when_cap_is_max_balance() {
+	ExtBuilder::default()
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_tvl_cap(RuntimeOrigin::root(), DAI, 1_000_000 * ONE));
+
+			assert_ok!(Omnipool::set_tvl_cap(RuntimeOrigin::root(), DAI, Balance::MAX));
+
+			assert_eq!(Omnipool::tvl_cap(), None);
+		});
+}
+
+#[test]
+fn set_tvl_cap_should_fail_when_origin_is_not_authority() {
+	ExtBuilder::default()
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::set_tvl_cap(RuntimeOrigin::signed(LP1), DAI, 1_000_000 * ONE),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn set_tvl_cap_should_fail_when_stable_asset_is_not_in_pool() {
+	ExtBuilder::default()
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::set_tvl_cap(RuntimeOrigin::root(), 1_000, 1_000_000 * ONE),
+				Error::<Test>::NoStableAssetInPool
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_should_work_when_tvl_cap_is_not_exceeded() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_tvl_cap(RuntimeOrigin::root(), DAI, 24_120 * ONE));
+
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+
+			assert_pool_state!(12_060 * ONE, 24_120 * ONE, SimpleImbalance::default());
+		});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_tvl_cap_is_exceeded() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_tvl_cap(RuntimeOrigin::root(), DAI, 24_000 * ONE));
+
+			assert_noop!(
+				Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE),
+				Error::<Test>::TvlCapExceeded
+			);
+		});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_adding_stable_asset_exceeds_tvl_cap() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, DAI, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// TVL after adding 100 DAI is 11_850 * 1_100 / 550
+			assert_ok!(Omnipool::set_tvl_cap(RuntimeOrigin::root(), DAI, 23_650 * ONE));
+
+			assert_noop!(
+				Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), DAI, 100 * ONE),
+				Error::<Test>::TvlCapExceeded
+			);
+
+			assert_ok!(Omnipool::set_tvl_cap(RuntimeOrigin::root(), DAI, 23_700 * ONE));
+
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), DAI, 100 * ONE));
+		});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_stable_asset_was_removed_from_pool() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, DAI, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get() - 1;
+			assert_ok!(Omnipool::set_tvl_cap(RuntimeOrigin::root(), 1_000, 100_000 * ONE));
+
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				1_000,
+				Tradability::FROZEN
+			));
+			assert_ok!(Omnipool::sacrifice_position(RuntimeOrigin::signed(LP2), position_id));
+			assert_ok!(Omnipool::remove_token(RuntimeOrigin::root(), 1_000, LP2));
+
+			assert_noop!(
+				Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), DAI, 100 * ONE),
+				Error::<Test>::NoStableAssetInPool
+			);
+		});
+}
@@ -2,7 +2,8 @@ use super::*;
 use codec::MaxEncodedLen;
 use frame_support::pallet_prelude::*;
 use hydra_dx_math::omnipool::types::{AssetReserveState as MathReserveState, AssetStateChange, BalanceUpdate};
-use sp_runtime::{FixedPointNumber, FixedU128};
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::{FixedPointNumber, FixedU128, Rounding};
 use sp_std::ops::{Add, Sub};
 
 /// Balance type used in Omnipool
@@ -135,6 +136,56 @@ where
 	}
 }
 
+impl<AssetId: Copy> Position<Balance, AssetId> {
+	/// Merge position with other position of the same asset.
+	///
+	/// Price of merged position is average of positions' prices weighted by provided amounts.
+	pub(super) fn merge(&self, other: &Self) -> Option<Self> {
+		let amount = self.amount.checked_add(other.amount)?;
+		let shares = self.shares.checked_add(other.shares)?;
+
+		let price = if amount.is_zero() {
+			self.price
+		} else {
+			let weighted_price = |position: &Self| {
+				FixedU128::checked_from_rational(position.amount, amount)?.checked_mul(&position.price_from_rational()?)
+			};
+			let price = weighted_price(self)?.checked_add(&weighted_price(other)?)?;
+			(price.into_inner(), FixedU128::DIV)
+		};
+
+		Some(Self {
+			asset_id: self.asset_id,
+			amount,
+			shares,
+			price,
+		})
+	}
+
+	/// Split `shares` off the position.
+	///
+	/// Amount is split proportionally to shares, both positions keep the price.
+	/// Returns updated position and the new position.
+	pub(super) fn split(&self, shares: Balance) -> Option<(Self, Self)> {
+		let amount = multiply_by_rational_with_rounding(self.amount, shares, self.shares, Rounding::Down)?;
+
+		Some((
+			Self {
+				asset_id: self.asset_id,
+				amount: self.amount.checked_sub(amount)?,
+				shares: self.shares.checked_sub(shares)?,
+				price: self.price,
+			},
+			Self {
+				asset_id: self.asset_id,
+				amount,
+				shares,
+				price: self.price,
+			},
+		))
+	}
+}
+
 /// Simple type to represent imbalance which can be positive or negative.
 // Note: Simple prefix is used not to confuse with Imbalance trait from frame_support.
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
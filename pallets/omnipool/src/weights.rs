@@ -61,6 +61,11 @@ pub trait WeightInfo {
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 	fn withdraw_protocol_liquidity() -> Weight;
 	fn remove_token() -> Weight;
+	fn merge_positions() -> Weight;
+	fn split_position() -> Weight;
+	fn set_tvl_cap() -> Weight;
+	fn set_imbalance_buyback_budget() -> Weight;
+	fn buy_back_hub_asset() -> Weight;
 }
 
 /// Weights for pallet_omnipool using the hydraDX node and recommended hardware.
@@ -400,6 +405,74 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	fn withdraw_protocol_liquidity() -> Weight {
 		Weight::zero()
 	}
+	// Storage: Uniques Asset (r:2 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:2 w:2)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:1)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn merge_positions() -> Weight {
+		// Minimum execution time: 81_915 nanoseconds.
+		Weight::from_ref_time(82_604_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Uniques Asset (r:2 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:1 w:2)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Omnipool NextPositionId (r:1 w:1)
+	// Proof: Omnipool NextPositionId (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Proof: Uniques CollectionMaxSupply (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:1)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	fn split_position() -> Weight {
+		// Minimum execution time: 87_320 nanoseconds.
+		Weight::from_ref_time(88_051_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Omnipool Assets (r:1 w:0)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool TvlCap (r:0 w:1)
+	// Proof: Omnipool TvlCap (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	fn set_tvl_cap() -> Weight {
+		// Minimum execution time: 19_514 nanoseconds.
+		Weight::from_ref_time(20_016_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Omnipool ImbalanceBuybackBudget (r:0 w:1)
+	// Proof: Omnipool ImbalanceBuybackBudget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_imbalance_buyback_budget() -> Weight {
+		// Minimum execution time: 19_633 nanoseconds.
+		Weight::from_ref_time(20_141_000 as u64).saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Omnipool ImbalanceBuybackBudget (r:1 w:0)
+	// Proof: Omnipool ImbalanceBuybackBudget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:1)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn buy_back_hub_asset() -> Weight {
+		// Minimum execution time: 58_412 nanoseconds.
+		Weight::from_ref_time(59_204_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -736,4 +809,72 @@ impl WeightInfo for () {
 	fn withdraw_protocol_liquidity() -> Weight {
 		Weight::zero()
 	}
+	// Storage: Uniques Asset (r:2 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:2 w:2)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:1)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn merge_positions() -> Weight {
+		// Minimum execution time: 81_915 nanoseconds.
+		Weight::from_ref_time(82_604_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Uniques Asset (r:2 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:1 w:2)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Omnipool NextPositionId (r:1 w:1)
+	// Proof: Omnipool NextPositionId (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Proof: Uniques CollectionMaxSupply (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:1)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	fn split_position() -> Weight {
+		// Minimum execution time: 87_320 nanoseconds.
+		Weight::from_ref_time(88_051_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Omnipool Assets (r:1 w:0)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool TvlCap (r:0 w:1)
+	// Proof: Omnipool TvlCap (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	fn set_tvl_cap() -> Weight {
+		// Minimum execution time: 19_514 nanoseconds.
+		Weight::from_ref_time(20_016_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Omnipool ImbalanceBuybackBudget (r:0 w:1)
+	// Proof: Omnipool ImbalanceBuybackBudget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	fn set_imbalance_buyback_budget() -> Weight {
+		// Minimum execution time: 19_633 nanoseconds.
+		Weight::from_ref_time(20_141_000 as u64).saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Omnipool ImbalanceBuybackBudget (r:1 w:0)
+	// Proof: Omnipool ImbalanceBuybackBudget (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:1)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: System Account (r:2 w:2)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	fn buy_back_hub_asset() -> Weight {
+		// Minimum execution time: 58_412 nanoseconds.
+		Weight::from_ref_time(59_204_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
 }
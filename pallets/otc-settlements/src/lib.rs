@@ -0,0 +1,381 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// # OTC settlements pallet
+// ## General description
+// This pallet settles OTC orders which are priced better than the router quote.
+// An OTC order of `amount_out` of `asset_out` for `amount_in` of `asset_in` is profitable to settle when selling
+// `amount_out` of `asset_out` via the router yields more than `amount_in` of `asset_in`.
+//
+// The offchain worker searches for such orders and submits unsigned `settle_otc_order` transactions.
+// The settlement is executed atomically by the pallet account:
+// 1. `amount_in` of `asset_in` is minted to the pallet account,
+// 2. the OTC order is filled completely,
+// 3. received `asset_out` is sold via the router for `asset_in`,
+// 4. the minted `amount_in` is burned and the remaining `asset_in` is transferred to `ProfitReceiver`.
+//
+// ## Notes
+// Only orders which yield at least `MinProfitPercentage` of `amount_in` as profit are settled.
+// The number of settlements per block is limited by `MaxSettlementsPerBlock`.
+// The offchain worker checks at most `MaxOrdersCheckedPerBlock` orders in a block and continues with the next orders
+// in the following block, starting from the beginning once all orders have been checked.
+// Orders with an allowed filler are never settled.
+//
+// ## Dispatachable functions
+// * `settle_otc_order` - settle a profitable OTC order. Unsigned, submitted by the offchain worker.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	log,
+	pallet_prelude::*,
+	traits::tokens::fungibles::{Inspect, Mutate, Transfer},
+	PalletId,
+};
+use frame_system::{
+	ensure_none,
+	offchain::{SendTransactionTypes, SubmitTransaction},
+	pallet_prelude::OriginFor,
+	RawOrigin,
+};
+use hydradx_traits::router::{AmmTradeWeights, AmountInAndOut, AssetPair, RouteProvider, RouterT, Trade};
+use pallet_otc::{Balance, Order, OrderId};
+use sp_runtime::{
+	offchain::storage::StorageValueRef,
+	traits::{AccountIdConversion, Zero},
+	ArithmeticError, DispatchError, PerThing, Permill,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+pub const LOG_TARGET: &str = "runtime::otc-settlements";
+
+/// Offchain storage key of the raw storage key of the last order checked by the offchain worker
+pub const OFFCHAIN_LAST_CHECKED_ORDER_KEY: &[u8] = b"otc-settlements::last-checked-order";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(crate) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_otc::Config + SendTransactionTypes<Call<Self>> {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency used to mint, burn and transfer the settled assets
+		type Currency: Mutate<Self::AccountId, AssetId = Self::AssetId, Balance = Balance>
+			+ Transfer<Self::AccountId, AssetId = Self::AssetId, Balance = Balance>;
+
+		/// Router implementation used to sell the assets received from OTC orders
+		type Router: RouterT<Self::RuntimeOrigin, Self::AssetId, Balance, Trade<Self::AssetId>, AmountInAndOut<Balance>>;
+
+		/// Route provider to get the route used to sell the assets received from OTC orders
+		type RouteProvider: RouteProvider<Self::AssetId>;
+
+		/// Account receiving the profit of settlements
+		#[pallet::constant]
+		type ProfitReceiver: Get<Self::AccountId>;
+
+		/// Minimum profit of a settlement, relative to the `amount_in` of the settled order
+		#[pallet::constant]
+		type MinProfitPercentage: Get<Permill>;
+
+		/// Maximum number of settlements executed in a block
+		#[pallet::constant]
+		type MaxSettlementsPerBlock: Get<u32>;
+
+		/// Maximum number of orders checked by the offchain worker in a block
+		#[pallet::constant]
+		type MaxOrdersCheckedPerBlock: Get<u32>;
+
+		/// Pallet id of the account executing the settlements
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Priority of the unsigned settlement transactions
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+
+		/// Weight information of the router trades executed by the settlements.
+		type AmmTradeWeights: AmmTradeWeights<Trade<Self::AssetId>>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An OTC order has been settled and the profit sent to the profit receiver
+		Settled {
+			order_id: OrderId,
+			asset_id: T::AssetId,
+			profit: Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// OTC order cannot be found
+		OrderNotFound,
+		/// OTC order cannot be settled by the pallet
+		OrderNotSettleable,
+		/// Profit of the settlement is lower than the minimum profit
+		ProfitTooLow,
+		/// Maximum number of settlements in the block has been reached
+		MaxSettlementsPerBlockReached,
+	}
+
+	/// Number of settlements executed in the current block
+	#[pallet::storage]
+	#[pallet::getter(fn settlements_in_block)]
+	pub type SettlementsInBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			SettlementsInBlock::<T>::kill();
+			T::DbWeight::get().writes(1)
+		}
+
+		fn offchain_worker(block_number: BlockNumberFor<T>) {
+			let last_checked_order = StorageValueRef::persistent(OFFCHAIN_LAST_CHECKED_ORDER_KEY);
+			let mut orders = match last_checked_order.get::<Vec<u8>>() {
+				Ok(Some(last_key)) => pallet_otc::Orders::<T>::iter_from(last_key),
+				_ => pallet_otc::Orders::<T>::iter(),
+			};
+
+			let mut checked: u32 = 0;
+			let mut submitted: u32 = 0;
+			let mut all_orders_checked = false;
+
+			while checked < T::MaxOrdersCheckedPerBlock::get() && submitted < T::MaxSettlementsPerBlock::get() {
+				let Some((order_id, order)) = orders.next() else {
+					all_orders_checked = true;
+					break;
+				};
+				checked = checked.saturating_add(1);
+
+				if Self::ensure_profitable(&order).is_err() {
+					continue;
+				}
+
+				let call = Call::settle_otc_order { order_id };
+				match SubmitTransaction::<T, Call<T>>::submit_unsigned_transaction(call.into()) {
+					Ok(()) => submitted = submitted.saturating_add(1),
+					Err(()) => log::warn!(
+						target: LOG_TARGET,
+						"Failed to submit settlement of OTC order {:?} in block {:?}",
+						order_id,
+						block_number,
+					),
+				}
+			}
+
+			if all_orders_checked {
+				last_checked_order.clear();
+			} else {
+				last_checked_order.set(&orders.last_raw_key().to_vec());
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Settle an OTC order which is priced better than the router quote.
+		///
+		/// The order is filled completely by the pallet account, the received asset is sold via the router
+		/// and the profit is sent to `ProfitReceiver`. Profit below the existential deposit of the asset is kept
+		/// in the pallet account.
+		///
+		/// Parameters:
+		/// - `origin`: none, the transaction is submitted by the offchain worker
+		/// - `order_id`: ID of the OTC order
+		///
+		/// Validations:
+		/// - order must not have an allowed filler
+		/// - profit must not be lower than `MinProfitPercentage` of the order `amount_in`
+		/// - number of settlements in the block must be lower than `MaxSettlementsPerBlock`
+		///
+		/// Emits `Settled` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::settle_otc_order()
+			.saturating_add(Pallet::<T>::settlement_trade_weight(*order_id)))]
+		#[transactional]
+		pub fn settle_otc_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			ensure_none(origin)?;
+
+			SettlementsInBlock::<T>::try_mutate(|settlements| -> DispatchResult {
+				ensure!(
+					*settlements < T::MaxSettlementsPerBlock::get(),
+					Error::<T>::MaxSettlementsPerBlockReached
+				);
+				*settlements = settlements.saturating_add(1);
+				Ok(())
+			})?;
+
+			let order = pallet_otc::Orders::<T>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			Self::ensure_profitable(&order)?;
+
+			let profit = Self::settle(order_id, &order)?;
+
+			Self::deposit_event(Event::Settled {
+				order_id,
+				asset_id: order.asset_in,
+				profit,
+			});
+
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if source == TransactionSource::External {
+				return InvalidTransaction::Call.into();
+			}
+
+			let Call::settle_otc_order { order_id } = call else {
+				return InvalidTransaction::Call.into();
+			};
+
+			if Self::settlements_in_block() >= T::MaxSettlementsPerBlock::get() {
+				return InvalidTransaction::ExhaustsResources.into();
+			}
+
+			let Some(order) = pallet_otc::Orders::<T>::get(order_id) else {
+				return InvalidTransaction::Stale.into();
+			};
+
+			if Self::ensure_profitable(&order).is_err() {
+				return InvalidTransaction::Call.into();
+			}
+
+			ValidTransaction::with_tag_prefix("settle-otc-order")
+				.priority(T::UnsignedPriority::get())
+				.and_provides(("settle_otc_order", order_id))
+				.longevity(1)
+				.propagate(false)
+				.build()
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Weight of the router trades executed by the settlement of the order.
+	fn settlement_trade_weight(order_id: OrderId) -> Weight {
+		pallet_otc::Orders::<T>::get(order_id)
+			.map(|order| T::AmmTradeWeights::sell_and_calculate_sell_trade_amounts_weight(&Self::get_route(&order)))
+			.unwrap_or_else(Weight::zero)
+	}
+
+	/// Route used to sell `asset_out` of the order for its `asset_in`.
+	fn get_route(order: &Order<T::AccountId, T::AssetId, T::BlockNumber>) -> Vec<Trade<T::AssetId>> {
+		T::RouteProvider::get_route(AssetPair::new(order.asset_out, order.asset_in))
+	}
+
+	/// Calculates the expected profit of settling the order, in `asset_in` of the order.
+	pub fn calculate_profit(order: &Order<T::AccountId, T::AssetId, T::BlockNumber>) -> Result<Balance, DispatchError> {
+		let route = Self::get_route(order);
+		let trade_amounts = T::Router::calculate_sell_trade_amounts(&route, order.amount_out)?;
+		let amount_out = trade_amounts.last().ok_or(Error::<T>::OrderNotSettleable)?.amount_out;
+
+		Ok(amount_out.saturating_sub(order.amount_in))
+	}
+
+	fn min_profit(order: &Order<T::AccountId, T::AssetId, T::BlockNumber>) -> Balance {
+		T::MinProfitPercentage::get().mul_floor(order.amount_in)
+	}
+
+	fn ensure_profitable(order: &Order<T::AccountId, T::AssetId, T::BlockNumber>) -> DispatchResult {
+		ensure!(order.allowed_filler.is_none(), Error::<T>::OrderNotSettleable);
+		if let Some(expires_at) = order.expires_at {
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= expires_at,
+				Error::<T>::OrderNotSettleable
+			);
+		}
+
+		let profit = Self::calculate_profit(order)?;
+		ensure!(
+			!profit.is_zero() && profit >= Self::min_profit(order),
+			Error::<T>::ProfitTooLow
+		);
+
+		Ok(())
+	}
+
+	/// Fills the order by the pallet account and sells the received asset via the router.
+	/// Returns the profit sent to `ProfitReceiver`.
+	/// Profit below the existential deposit is kept in the pallet account.
+	fn settle(
+		order_id: OrderId,
+		order: &Order<T::AccountId, T::AssetId, T::BlockNumber>,
+	) -> Result<Balance, DispatchError> {
+		let account = Self::account_id();
+		let balance_before = <T as Config>::Currency::balance(order.asset_in, &account);
+
+		<T as Config>::Currency::mint_into(order.asset_in, &account, order.amount_in)?;
+
+		pallet_otc::Pallet::<T>::fill_order(RawOrigin::Signed(account.clone()).into(), order_id)?;
+
+		let min_amount_out = order
+			.amount_in
+			.checked_add(Self::min_profit(order))
+			.ok_or(ArithmeticError::Overflow)?;
+		T::Router::sell(
+			RawOrigin::Signed(account.clone()).into(),
+			order.asset_out,
+			order.asset_in,
+			order.amount_out,
+			min_amount_out,
+			Self::get_route(order),
+		)?;
+
+		<T as Config>::Currency::burn_from(order.asset_in, &account, order.amount_in)?;
+
+		let profit = <T as Config>::Currency::balance(order.asset_in, &account)
+			.checked_sub(balance_before)
+			.ok_or(ArithmeticError::Underflow)?;
+
+		// Transfer of profit below the existential deposit could fail when `ProfitReceiver` has no balance
+		if profit >= <T as Config>::Currency::minimum_balance(order.asset_in) {
+			<T as Config>::Currency::transfer(order.asset_in, &account, &T::ProfitReceiver::get(), profit, false)?;
+		}
+
+		Ok(profit)
+	}
+}
@@ -0,0 +1,339 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as otc_settlements;
+use crate::Config;
+use frame_support::{
+	parameter_types,
+	traits::{Everything, GenesisBuild, Nothing},
+	PalletId,
+};
+use frame_system as system;
+use frame_system::ensure_signed;
+use hydradx_traits::{
+	router::{AmountInAndOut, AssetPair, PoolType, RouteProvider, RouterT, Trade},
+	AssetKind, Registry,
+};
+use orml_tokens::AccountData;
+use orml_traits::{parameter_type_with_key, MultiCurrency};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	transaction_validity::TransactionPriority,
+	DispatchError, DispatchResult, DispatchResultWithPostInfo, FixedPointNumber, FixedU128, Permill,
+};
+use std::{cell::RefCell, collections::HashMap};
+
+pub type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Amount = i128;
+pub type AssetId = u32;
+pub type Balance = u128;
+pub type NamedReserveIdentifier = [u8; 8];
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const TREASURY: AccountId = 99;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+	 Block = Block,
+	 NodeBlock = Block,
+	 UncheckedExtrinsic = UncheckedExtrinsic,
+	 {
+		 System: frame_system,
+		 OTC: pallet_otc,
+		 OtcSettlements: otc_settlements,
+		 Tokens: orml_tokens,
+	 }
+);
+
+thread_local! {
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u32>> = RefCell::new(HashMap::default());
+	pub static ROUTER_PRICE: RefCell<FixedU128> = RefCell::new(FixedU128::one());
+}
+
+parameter_types! {
+	pub ExistentialDepositMultiplier: u8 = 5;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		1_000
+	};
+}
+
+impl pallet_otc::Config for Test {
+	type AssetId = AssetId;
+	type AssetRegistry = DummyRegistry<Test>;
+	type Currency = Tokens;
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposits = ExistentialDeposits;
+	type ExistentialDepositMultiplier = ExistentialDepositMultiplier;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinProfitPercentage: Permill = Permill::from_percent(1);
+	pub const MaxSettlementsPerBlock: u32 = 2;
+	pub const MaxOrdersCheckedPerBlock: u32 = 2;
+	pub const OtcSettlementsPalletId: PalletId = PalletId(*b"otcsettl");
+	pub const UnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+	pub const ProfitReceiver: AccountId = TREASURY;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Tokens;
+	type Router = MockRouter;
+	type RouteProvider = MockRouter;
+	type ProfitReceiver = ProfitReceiver;
+	type MinProfitPercentage = MinProfitPercentage;
+	type MaxSettlementsPerBlock = MaxSettlementsPerBlock;
+	type MaxOrdersCheckedPerBlock = MaxOrdersCheckedPerBlock;
+	type PalletId = OtcSettlementsPalletId;
+	type UnsignedPriority = UnsignedPriority;
+	type WeightInfo = ();
+	type AmmTradeWeights = ();
+}
+
+impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Test
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = AccountData<u128>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = NamedReserveIdentifier;
+	type MaxReserves = MaxReserves;
+	type CurrencyHooks = ();
+}
+
+pub struct DummyRegistry<T>(sp_std::marker::PhantomData<T>);
+
+impl<T: pallet_otc::Config> Registry<AssetId, Vec<u8>, Balance, DispatchError> for DummyRegistry<T> {
+	fn exists(asset_id: AssetId) -> bool {
+		let asset = REGISTERED_ASSETS.with(|v| v.borrow().get(&(asset_id)).copied());
+		matches!(asset, Some(_))
+	}
+
+	fn retrieve_asset(_name: &Vec<u8>) -> Result<AssetId, DispatchError> {
+		Ok(0)
+	}
+
+	fn retrieve_asset_type(_asset_id: AssetId) -> Result<AssetKind, DispatchError> {
+		unimplemented!()
+	}
+
+	fn create_asset(_name: &Vec<u8>, _existential_deposit: Balance) -> Result<AssetId, DispatchError> {
+		unimplemented!()
+	}
+}
+
+/// Router which sells any asset for `ROUTER_PRICE` units of the other asset.
+pub struct MockRouter;
+
+impl MockRouter {
+	fn calculate_amount_out(amount_in: Balance) -> Balance {
+		ROUTER_PRICE.with(|v| v.borrow().saturating_mul_int(amount_in))
+	}
+}
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for MockRouter {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		let who = ensure_signed(origin)?;
+		let amount_out = Self::calculate_amount_out(amount_in);
+		frame_support::ensure!(amount_out >= min_amount_out, "Trading limit reached");
+
+		Tokens::withdraw(asset_in, &who, amount_in)?;
+		Tokens::deposit(asset_out, &who, amount_out)
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		Ok(vec![AmountInAndOut {
+			amount_in,
+			amount_out: Self::calculate_amount_out(amount_in),
+		}])
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
+
+impl RouteProvider<AssetId> for MockRouter {
+	fn get_route(asset_pair: AssetPair<AssetId>) -> Vec<Trade<AssetId>> {
+		vec![Trade {
+			pool: PoolType::XYK,
+			asset_in: asset_pair.asset_in,
+			asset_out: asset_pair.asset_out,
+		}]
+	}
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(u64, AssetId, Balance)>,
+	registered_assets: Vec<AssetId>,
+	router_price: FixedU128,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+
+		Self {
+			endowed_accounts: vec![
+				(ALICE, HDX, 10_000 * ONE),
+				(BOB, HDX, 10_000 * ONE),
+				(ALICE, DAI, 100 * ONE),
+				(BOB, DAI, 100 * ONE),
+			],
+			registered_assets: vec![HDX, DAI],
+			router_price: FixedU128::one(),
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_router_price(mut self, price: FixedU128) -> Self {
+		self.router_price = price;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			self.registered_assets.iter().for_each(|asset| {
+				v.borrow_mut().insert(*asset, *asset);
+			});
+		});
+		ROUTER_PRICE.with(|v| {
+			*v.borrow_mut() = self.router_price;
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+		});
+
+		r
+	}
+}
+
+pub fn set_router_price(price: FixedU128) {
+	ROUTER_PRICE.with(|v| {
+		*v.borrow_mut() = price;
+	});
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	test_utils::expect_events::<RuntimeEvent, Test>(e);
+}
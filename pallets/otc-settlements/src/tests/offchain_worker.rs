@@ -0,0 +1,117 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::Call;
+use codec::Decode;
+use frame_support::{
+	assert_ok,
+	traits::{Get, Hooks},
+};
+use pallet_otc::OrderId;
+use pretty_assertions::assert_eq;
+use sp_core::offchain::{
+	testing::{TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_runtime::FixedU128;
+
+/// Executes the test with the offchain extensions registered.
+/// The test is given a function which runs the offchain worker and returns the IDs of the orders
+/// of the submitted settlements.
+fn execute_with_offchain(test: impl FnOnce(&dyn Fn() -> Vec<OrderId>)) {
+	let mut ext = ExtBuilder::default()
+		.with_router_price(FixedU128::from_rational(6, 10))
+		.build();
+
+	let (offchain, _) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	ext.execute_with(|| {
+		let run_offchain_worker = || {
+			OtcSettlements::offchain_worker(System::block_number());
+
+			pool_state
+				.write()
+				.transactions
+				.drain(..)
+				.map(|tx| match UncheckedExtrinsic::decode(&mut &tx[..]).unwrap().function {
+					RuntimeCall::OtcSettlements(Call::settle_otc_order { order_id }) => order_id,
+					call => panic!("unexpected call {call:?}"),
+				})
+				.collect()
+		};
+
+		test(&run_offchain_worker);
+	});
+}
+
+fn place_profitable_orders(count: u32) {
+	for _ in 0..count {
+		// Alice sells 20 HDX for 10 DAI
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			10 * ONE,
+			20 * ONE,
+			false,
+			None,
+			None,
+			None
+		));
+	}
+}
+
+#[test]
+fn offchain_worker_should_submit_settlements_of_profitable_orders() {
+	execute_with_offchain(|run_offchain_worker| {
+		// Arrange
+		place_profitable_orders(1);
+
+		// Act
+		let settled_orders = run_offchain_worker();
+
+		// Assert
+		assert_eq!(settled_orders, vec![0]);
+	});
+}
+
+#[test]
+fn offchain_worker_should_continue_with_next_orders_in_next_block() {
+	execute_with_offchain(|run_offchain_worker| {
+		// Arrange
+		place_profitable_orders(3);
+
+		// Act
+		let first_block = run_offchain_worker();
+		System::set_block_number(2);
+		let second_block = run_offchain_worker();
+		System::set_block_number(3);
+		let third_block = run_offchain_worker();
+
+		// Assert
+		assert_eq!(first_block.len(), MaxOrdersCheckedPerBlock::get() as usize);
+		assert_eq!(second_block.len(), 1);
+		let mut checked_orders = [first_block.clone(), second_block].concat();
+		checked_orders.sort();
+		assert_eq!(checked_orders, vec![0, 1, 2]);
+		// all orders were checked, so the worker starts from the beginning again
+		assert_eq!(third_block, first_block);
+	});
+}
@@ -0,0 +1,289 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Call, Error, Event};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::{
+	traits::{BadOrigin, ValidateUnsigned},
+	transaction_validity::{InvalidTransaction, TransactionSource},
+	FixedU128,
+};
+
+fn place_hdx_for_dai_order() {
+	// Alice sells 200 HDX for 100 DAI
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		DAI,
+		HDX,
+		100 * ONE,
+		200 * ONE,
+		false,
+		None,
+		None,
+		None
+	));
+}
+
+#[test]
+fn settle_otc_order_should_work_when_order_is_profitable() {
+	ExtBuilder::default()
+		.with_router_price(FixedU128::from_rational(6, 10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			place_hdx_for_dai_order();
+			let alice_dai_balance_before = Tokens::free_balance(DAI, &ALICE);
+
+			// Act
+			assert_ok!(OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 0));
+
+			// Assert
+			assert!(OTC::orders(0).is_none());
+			assert_eq!(Tokens::free_balance(DAI, &ALICE), alice_dai_balance_before + 100 * ONE);
+			assert_eq!(Tokens::free_balance(DAI, &TREASURY), 20 * ONE);
+			assert_eq!(Tokens::free_balance(DAI, &OtcSettlements::account_id()), 0);
+			assert_eq!(Tokens::free_balance(HDX, &OtcSettlements::account_id()), 0);
+			// minted DAI is burned, only the router output is added to the issuance
+			assert_eq!(Tokens::total_issuance(DAI), 200 * ONE + 120 * ONE);
+			assert_eq!(OtcSettlements::settlements_in_block(), 1);
+
+			expect_events(vec![Event::Settled {
+				order_id: 0,
+				asset_id: DAI,
+				profit: 20 * ONE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn settle_otc_order_should_keep_profit_in_pallet_account_when_profit_is_below_existential_deposit() {
+	ExtBuilder::default()
+		.with_router_price(FixedU128::from_rational(506, 1_000))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			// Alice sells 100_000 units of HDX for 50_000 units of DAI, the profit of 600 units is below ED
+			assert_ok!(OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				50_000,
+				100_000,
+				false,
+				None,
+				None,
+				None
+			));
+			assert_ok!(Tokens::deposit(DAI, &OtcSettlements::account_id(), ONE));
+
+			// Act
+			assert_ok!(OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 0));
+
+			// Assert
+			assert!(OTC::orders(0).is_none());
+			assert_eq!(Tokens::free_balance(DAI, &TREASURY), 0);
+			assert_eq!(Tokens::free_balance(DAI, &OtcSettlements::account_id()), ONE + 600);
+
+			expect_events(vec![Event::Settled {
+				order_id: 0,
+				asset_id: DAI,
+				profit: 600,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_order_is_not_profitable() {
+	ExtBuilder::default()
+		.with_router_price(FixedU128::from_rational(1, 2))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			place_hdx_for_dai_order();
+
+			// Act & Assert
+			assert_noop!(
+				OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 0),
+				Error::<Test>::ProfitTooLow
+			);
+		});
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_profit_is_lower_than_min_profit() {
+	// profit of 0.9 DAI is lower than 1% of 100 DAI
+	ExtBuilder::default()
+		.with_router_price(FixedU128::from_rational(5045, 10_000))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			place_hdx_for_dai_order();
+			assert_eq!(
+				OtcSettlements::calculate_profit(&OTC::orders(0).unwrap()),
+				Ok(9 * ONE / 10)
+			);
+
+			// Act & Assert
+			assert_noop!(
+				OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 0),
+				Error::<Test>::ProfitTooLow
+			);
+		});
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_order_has_allowed_filler() {
+	ExtBuilder::default()
+		.with_router_price(FixedU128::from_rational(6, 10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				100 * ONE,
+				200 * ONE,
+				false,
+				None,
+				Some(BOB),
+				None
+			));
+
+			// Act & Assert
+			assert_noop!(
+				OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 0),
+				Error::<Test>::OrderNotSettleable
+			);
+		});
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_order_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act & Assert
+		assert_noop!(
+			OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 0),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_origin_is_signed() {
+	ExtBuilder::default()
+		.with_router_price(FixedU128::from_rational(6, 10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			place_hdx_for_dai_order();
+
+			// Act & Assert
+			assert_noop!(
+				OtcSettlements::settle_otc_order(RuntimeOrigin::signed(BOB), 0),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn settle_otc_order_should_fail_when_max_settlements_per_block_is_reached() {
+	ExtBuilder::default()
+		.with_router_price(FixedU128::from_rational(6, 10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			place_hdx_for_dai_order();
+			place_hdx_for_dai_order();
+			place_hdx_for_dai_order();
+			assert_ok!(OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 0));
+			assert_ok!(OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 1));
+
+			// Act & Assert
+			assert_noop!(
+				OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 2),
+				Error::<Test>::MaxSettlementsPerBlockReached
+			);
+		});
+}
+
+#[test]
+fn settlements_counter_should_be_reset_in_next_block() {
+	ExtBuilder::default()
+		.with_router_price(FixedU128::from_rational(6, 10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			place_hdx_for_dai_order();
+			place_hdx_for_dai_order();
+			place_hdx_for_dai_order();
+			assert_ok!(OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 0));
+			assert_ok!(OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 1));
+
+			// Act
+			System::set_block_number(2);
+			OtcSettlements::on_initialize(2);
+
+			// Assert
+			assert_eq!(OtcSettlements::settlements_in_block(), 0);
+			assert_ok!(OtcSettlements::settle_otc_order(RuntimeOrigin::none(), 2));
+		});
+}
+
+#[test]
+fn validate_unsigned_should_accept_local_settlement_of_profitable_order() {
+	ExtBuilder::default()
+		.with_router_price(FixedU128::from_rational(6, 10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			place_hdx_for_dai_order();
+			let call = Call::settle_otc_order { order_id: 0 };
+
+			// Act & Assert
+			assert!(OtcSettlements::validate_unsigned(TransactionSource::Local, &call).is_ok());
+			assert!(OtcSettlements::validate_unsigned(TransactionSource::InBlock, &call).is_ok());
+		});
+}
+
+#[test]
+fn validate_unsigned_should_reject_invalid_settlements() {
+	ExtBuilder::default()
+		.with_router_price(FixedU128::from_rational(1, 2))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			place_hdx_for_dai_order();
+
+			// Act & Assert
+			assert_eq!(
+				OtcSettlements::validate_unsigned(TransactionSource::External, &Call::settle_otc_order { order_id: 0 }),
+				InvalidTransaction::Call.into()
+			);
+			assert_eq!(
+				OtcSettlements::validate_unsigned(TransactionSource::Local, &Call::settle_otc_order { order_id: 0 }),
+				InvalidTransaction::Call.into()
+			);
+			assert_eq!(
+				OtcSettlements::validate_unsigned(TransactionSource::Local, &Call::settle_otc_order { order_id: 1 }),
+				InvalidTransaction::Stale.into()
+			);
+		});
+}
@@ -33,7 +33,7 @@ benchmarks! {
 		let (hdx, dai) = seed_registry::<T>()?;
 
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(hdx, dai))?;
-  }:  _(RawOrigin::Signed(owner.clone()), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true)
+  }:  _(RawOrigin::Signed(owner.clone()), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true, Some(10u32.into()), None, Some(ONE))
 	verify {
 		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, hdx.into(), &owner), 100 * ONE);
 	}
@@ -45,7 +45,7 @@ benchmarks! {
 		let filler: T::AccountId = create_account_with_balances::<T>("filler", 2, vec!(hdx, dai))?;
 
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true, None, None, None)
 		);
   }:  _(RawOrigin::Signed(filler.clone()), 0u32, 10 * ONE)
 	verify {
@@ -59,7 +59,7 @@ benchmarks! {
 		let filler: T::AccountId = create_account_with_balances::<T>("filler", 2, vec!(hdx, dai))?;
 
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true, None, None, None)
 		);
   }:  _(RawOrigin::Signed(filler.clone()), 0u32)
 	verify {
@@ -71,12 +71,26 @@ benchmarks! {
 
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(hdx, dai))?;
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true, None, None, None)
 		);
   }:  _(RawOrigin::Signed(owner.clone()), 0u32)
 	verify {
 		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, hdx.into(), &owner), 0);
 	}
+
+	expire_order {
+		let (hdx, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(hdx, dai))?;
+		let expires_at: T::BlockNumber = 10u32.into();
+		assert_ok!(
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), hdx.into(), 20 * ONE, 100 * ONE, true, Some(expires_at), None, None)
+		);
+  }:  { crate::Pallet::<T>::expire_order(expires_at, 0u32) }
+	verify {
+		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, hdx.into(), &owner), 0);
+		assert!(<Orders<T>>::get(0u32).is_none());
+	}
 }
 
 fn seed_registry<T: Config>() -> Result<(u32, u32), DispatchError>
@@ -19,6 +19,12 @@
 // It allows anyone to `place_order` by specifying a pair of assets (in and out), their respective amounts, and
 // whether the order is partially fillable. The order price is static and calculated as `amount_out / amount_in`.
 //
+// Optionally, an order can be placed with:
+// * `expires_at` - block number after which the order cannot be filled anymore. Expired orders are removed
+//   in `on_idle` and the reserved amount is returned to the owner.
+// * `allowed_filler` - the only account which is allowed to fill the order (private deal).
+// * `min_partial_fill_amount` - the minimum `amount_in` which can be used to partially fill the order.
+//
 // ## Notes
 // The pallet implements a minimum order size as an alternative to storage fees. The amounts of an open order cannot
 // be lower than the existential deposit for the respective asset, multiplied by `ExistentialDepositMultiplier`.
@@ -30,23 +36,28 @@
 // * `partial_fill_order` - fill an OTC order (partially).
 // * `fill_order` - fill an OTC order (completely).
 // * `cancel_order` - cancel an open OTC order.
+//
+// ## Hooks
+// * `on_idle` - remove expired orders and unreserve their remaining amounts.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::MaxEncodedLen;
-use frame_support::{pallet_prelude::*, require_transactional};
+use frame_support::{log, pallet_prelude::*, require_transactional};
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use hydradx_traits::Registry;
 use orml_traits::{GetByKey, MultiCurrency, NamedMultiReservableCurrency};
 use sp_core::U256;
 use sp_runtime::{
-	traits::{One, Zero},
+	traits::{One, Saturating, Zero},
 	DispatchError,
 };
 use sp_std::vec::Vec;
 #[cfg(test)]
 mod tests;
 
+pub mod migration;
+
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod benchmarks;
 
@@ -64,24 +75,42 @@ pub type NamedReserveIdentifier = [u8; 8];
 pub const NAMED_RESERVE_ID: NamedReserveIdentifier = *b"otcorder";
 
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
-pub struct Order<AccountId, AssetId> {
+pub struct Order<AccountId, AssetId, BlockNumber> {
 	pub owner: AccountId,
 	pub asset_in: AssetId,
 	pub asset_out: AssetId,
 	pub amount_in: Balance,
 	pub amount_out: Balance,
 	pub partially_fillable: bool,
+	/// Block number after which the order cannot be filled anymore.
+	pub expires_at: Option<BlockNumber>,
+	/// The only account allowed to fill the order.
+	pub allowed_filler: Option<AccountId>,
+	/// Minimum `amount_in` of a partial fill.
+	pub min_partial_fill_amount: Option<Balance>,
 }
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use codec::HasCompact;
+	use frame_system::pallet_prelude::BlockNumberFor;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(crate) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::remove_expired_orders(now, remaining_weight)
+		}
+	}
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		/// Identifier for the class of asset.
@@ -137,6 +166,8 @@ pub mod pallet {
 			amount_out: Balance,
 			partially_fillable: bool,
 		},
+		/// An Order has expired and has been removed
+		Expired { order_id: OrderId },
 	}
 
 	#[pallet::error]
@@ -159,6 +190,14 @@ pub mod pallet {
 		Forbidden,
 		/// Reserved amount not sufficient.
 		InsufficientReservedAmount,
+		/// Order has expired and cannot be filled anymore
+		OrderExpired,
+		/// Expiry block number must be in the future
+		InvalidExpiry,
+		/// Minimum partial fill amount must not be higher than the amount_in of the order
+		InvalidMinPartialFillAmount,
+		/// Amount of the partial fill is lower than the minimum partial fill amount of the order
+		PartialFillAmountTooSmall,
 	}
 
 	/// ID sequencer for Orders
@@ -168,7 +207,19 @@ pub mod pallet {
 
 	#[pallet::storage]
 	#[pallet::getter(fn orders)]
-	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId, T::AssetId>, OptionQuery>;
+	pub type Orders<T: Config> =
+		StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId, T::AssetId, T::BlockNumber>, OptionQuery>;
+
+	/// Orders with expiry, indexed by the expiry block number
+	#[pallet::storage]
+	#[pallet::getter(fn order_expiries)]
+	pub type OrderExpiries<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Blake2_128Concat, OrderId, (), OptionQuery>;
+
+	/// The next block number to be checked for expired orders, cleared when no orders are left to expire
+	#[pallet::storage]
+	#[pallet::getter(fn next_expiry_check)]
+	pub type NextExpiryCheck<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -180,6 +231,9 @@ pub mod pallet {
 		/// - `amount_in`: Amount that the order is seeking to buy
 		/// - `amount_out`: Amount that the order is selling
 		/// - `partially_fillable`: Flag indicating whether users can fill the order partially
+		/// - `expires_at`: Optional block number after which the order cannot be filled anymore
+		/// - `allowed_filler`: Optional account which is the only one allowed to fill the order
+		/// - `min_partial_fill_amount`: Optional minimum amount_in of a partial fill
 		///
 		/// Validations:
 		/// - asset_in must be registered
@@ -187,6 +241,8 @@ pub mod pallet {
 		///   ExistentialDepositMultiplier
 		/// - amount_out must be higher than the existential deposit of asset_out multiplied by
 		///   ExistentialDepositMultiplier
+		/// - expires_at must be in the future
+		/// - min_partial_fill_amount must not be higher than amount_in
 		///
 		/// Events:
 		/// - `Placed` event when successful.
@@ -199,6 +255,9 @@ pub mod pallet {
 			amount_in: Balance,
 			amount_out: Balance,
 			partially_fillable: bool,
+			expires_at: Option<T::BlockNumber>,
+			allowed_filler: Option<T::AccountId>,
+			min_partial_fill_amount: Option<Balance>,
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
 			let order = Order {
@@ -208,18 +267,39 @@ pub mod pallet {
 				amount_in,
 				amount_out,
 				partially_fillable,
+				expires_at,
+				allowed_filler,
+				min_partial_fill_amount,
 			};
 
 			ensure!(T::AssetRegistry::exists(order.asset_in), Error::<T>::AssetNotRegistered);
 			Self::ensure_min_order_amount(order.asset_in, order.amount_in)?;
 			Self::ensure_min_order_amount(order.asset_out, amount_out)?;
 
+			let current_block = <frame_system::Pallet<T>>::block_number();
+			if let Some(expires_at) = order.expires_at {
+				ensure!(expires_at > current_block, Error::<T>::InvalidExpiry);
+			}
+			if let Some(min_partial_fill_amount) = order.min_partial_fill_amount {
+				ensure!(
+					min_partial_fill_amount <= order.amount_in,
+					Error::<T>::InvalidMinPartialFillAmount
+				);
+			}
+
 			<NextOrderId<T>>::try_mutate(|next_id| -> DispatchResult {
 				let order_id = *next_id;
 
 				T::Currency::reserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out)?;
 				<Orders<T>>::insert(order_id, &order);
 
+				if let Some(expires_at) = order.expires_at {
+					<OrderExpiries<T>>::insert(expires_at, order_id, ());
+					<NextExpiryCheck<T>>::mutate(|maybe_block| {
+						maybe_block.get_or_insert(current_block);
+					});
+				}
+
 				Self::deposit_event(Event::Placed {
 					order_id,
 					asset_in: order.asset_in,
@@ -242,6 +322,9 @@ pub mod pallet {
 		///
 		/// Validations:
 		/// - order must be partially_fillable
+		/// - order must not be expired
+		/// - caller must be the allowed filler, if the order specifies one
+		/// - amount_in must not be lower than the minimum partial fill amount of the order
 		/// - after the partial_fill, the remaining order.amount_in must be higher than the existential deposit
 		///   of asset_in multiplied by ExistentialDepositMultiplier
 		/// - after the partial_fill, the remaining order.amount_out must be higher than the existential deposit
@@ -257,6 +340,13 @@ pub mod pallet {
 				let order = maybe_order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
 
 				ensure!(order.partially_fillable, Error::<T>::OrderNotPartiallyFillable);
+				Self::ensure_can_fill(order, &who)?;
+				if let Some(min_partial_fill_amount) = order.min_partial_fill_amount {
+					ensure!(
+						amount_in >= min_partial_fill_amount,
+						Error::<T>::PartialFillAmountTooSmall
+					);
+				}
 
 				let amount_out_calculation = U256::from(order.amount_out)
 					.checked_mul(U256::from(amount_in))
@@ -287,6 +377,10 @@ pub mod pallet {
 		/// Parameters:
 		/// - `order_id`: ID of the order
 		///
+		/// Validations:
+		/// - order must not be expired
+		/// - caller must be the allowed filler, if the order specifies one
+		///
 		/// Events:
 		/// `Filled` event when successful.
 		#[pallet::call_index(2)]
@@ -295,8 +389,10 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
 
+			Self::ensure_can_fill(&order, &who)?;
+
 			Self::execute_order(&order, &who, order.amount_in, order.amount_out)?;
-			<Orders<T>>::remove(order_id);
+			Self::remove_order(order_id, &order);
 
 			Self::deposit_event(Event::Filled {
 				order_id,
@@ -330,6 +426,9 @@ pub mod pallet {
 				let remaining_to_unreserve =
 					T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
 				ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+				if let Some(expires_at) = order.expires_at {
+					<OrderExpiries<T>>::remove(expires_at, order_id);
+				}
 				*maybe_order = None;
 
 				Self::deposit_event(Event::Cancelled { order_id });
@@ -350,9 +449,99 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_can_fill(order: &Order<T::AccountId, T::AssetId, T::BlockNumber>, who: &T::AccountId) -> DispatchResult {
+		if let Some(expires_at) = order.expires_at {
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() <= expires_at,
+				Error::<T>::OrderExpired
+			);
+		}
+		if let Some(allowed_filler) = &order.allowed_filler {
+			ensure!(allowed_filler == who, Error::<T>::Forbidden);
+		}
+
+		Ok(())
+	}
+
+	fn remove_order(order_id: OrderId, order: &Order<T::AccountId, T::AssetId, T::BlockNumber>) {
+		<Orders<T>>::remove(order_id);
+		if let Some(expires_at) = order.expires_at {
+			<OrderExpiries<T>>::remove(expires_at, order_id);
+		}
+	}
+
+	fn remove_expired_orders(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+		let block_check_weight = T::DbWeight::get().reads_writes(1, 1);
+		let expire_order_weight = T::WeightInfo::expire_order();
+
+		let read_weight = T::DbWeight::get().reads(1);
+		if remaining_weight.any_lt(read_weight) {
+			return Weight::zero();
+		}
+
+		let Some(mut block) = <NextExpiryCheck<T>>::get() else {
+			return read_weight;
+		};
+
+		// NextExpiryCheck, OrderExpiries emptiness check and NextExpiryCheck update
+		let mut used_weight = T::DbWeight::get().reads_writes(2, 1);
+		if remaining_weight.any_lt(used_weight) {
+			return read_weight;
+		}
+		let start_block = block;
+
+		'blocks: while block < now {
+			if remaining_weight.any_lt(used_weight.saturating_add(block_check_weight)) {
+				break;
+			}
+			used_weight.saturating_accrue(block_check_weight);
+
+			for order_id in <OrderExpiries<T>>::iter_key_prefix(block) {
+				if remaining_weight.any_lt(used_weight.saturating_add(expire_order_weight)) {
+					break 'blocks;
+				}
+				used_weight.saturating_accrue(expire_order_weight);
+
+				Self::expire_order(block, order_id);
+			}
+
+			block = block.saturating_add(One::one());
+		}
+
+		// Stop checking blocks once there are no orders left to expire
+		if block >= now && <OrderExpiries<T>>::iter_keys().next().is_none() {
+			<NextExpiryCheck<T>>::kill();
+		} else if block != start_block {
+			<NextExpiryCheck<T>>::put(block);
+		}
+
+		used_weight
+	}
+
+	fn expire_order(expires_at: T::BlockNumber, order_id: OrderId) {
+		<OrderExpiries<T>>::remove(expires_at, order_id);
+
+		let Some(order) = <Orders<T>>::take(order_id) else {
+			return;
+		};
+
+		let remaining_to_unreserve =
+			T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
+		if !remaining_to_unreserve.is_zero() {
+			log::warn!(
+				target: "runtime::otc",
+				"Expired order {:?} has insufficient reserved amount: {:?}",
+				order_id,
+				remaining_to_unreserve,
+			);
+		}
+
+		Self::deposit_event(Event::Expired { order_id });
+	}
+
 	#[require_transactional]
 	fn execute_order(
-		order: &Order<T::AccountId, T::AssetId>,
+		order: &Order<T::AccountId, T::AssetId, T::BlockNumber>,
 		who: &T::AccountId,
 		amount_in: Balance,
 		amount_out: Balance,
@@ -0,0 +1,101 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Balance, Config, Order, Orders, Pallet};
+use codec::{Decode, Encode};
+use frame_support::{
+	log,
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+
+/// Orders are extended with optional `expires_at`, `allowed_filler` and `min_partial_fill_amount`.
+pub mod v1 {
+	use super::*;
+
+	#[derive(Encode, Decode)]
+	pub struct OldOrder<AccountId, AssetId> {
+		pub owner: AccountId,
+		pub asset_in: AssetId,
+		pub asset_out: AssetId,
+		pub amount_in: Balance,
+		pub amount_out: Balance,
+		pub partially_fillable: bool,
+	}
+
+	pub fn pre_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+		log::info!(
+			target: "runtime::otc",
+			"OTC migration: PRE checks successful!"
+		);
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			log::warn!(
+				target: "runtime::otc",
+				"Attempted to apply migration to v1 but storage version is already v1"
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(
+			target: "runtime::otc",
+			"Running migration to v1 for OTC"
+		);
+
+		let mut translated: u64 = 0;
+		Orders::<T>::translate(|_order_id, old: OldOrder<T::AccountId, T::AssetId>| {
+			translated += 1;
+			Some(Order {
+				owner: old.owner,
+				asset_in: old.asset_in,
+				asset_out: old.asset_out,
+				amount_in: old.amount_in,
+				amount_out: old.amount_out,
+				partially_fillable: old.partially_fillable,
+				expires_at: None,
+				allowed_filler: None,
+				min_partial_fill_amount: None,
+			})
+		});
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		log::info!(
+			target: "runtime::otc",
+			"Migrated {:?} orders",
+			translated
+		);
+
+		//storage version read and write included
+		T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+	}
+
+	pub fn post_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+		for (_order_id, order) in Orders::<T>::iter() {
+			assert!(order.expires_at.is_none(), "Unexpected expiry of migrated order.");
+		}
+
+		log::info!(
+			target: "runtime::otc",
+			"OTC migration: POST checks successful!"
+		);
+	}
+}
@@ -0,0 +1,247 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate as otc;
+use crate::tests::mock::*;
+use crate::{Error, Event};
+use frame_support::traits::{Get, Hooks};
+use frame_support::weights::Weight;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn place_order_should_store_expiry_when_expires_at_is_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None,
+			None
+		));
+
+		// Assert
+		assert_eq!(OTC::orders(0).unwrap().expires_at, Some(10));
+		assert!(OTC::order_expiries(10, 0).is_some());
+		assert_eq!(OTC::next_expiry_check(), Some(1));
+	});
+}
+
+#[test]
+fn place_order_should_throw_error_when_expires_at_is_not_in_future() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act & Assert
+		assert_noop!(
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				Some(1),
+				None,
+				None
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_throw_error_when_order_is_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None,
+			None
+		));
+
+		System::set_block_number(11);
+
+		// Act & Assert
+		assert_noop!(
+			OTC::fill_order(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::OrderExpired
+		);
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 5 * ONE),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_work_when_expiry_block_is_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None,
+			None
+		));
+
+		System::set_block_number(10);
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::order_expiries(10, 0).is_none());
+	});
+}
+
+#[test]
+fn on_idle_should_remove_expired_order_and_unreserve_amount() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None,
+			None
+		));
+		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
+
+		System::set_block_number(11);
+
+		// Act
+		OTC::on_idle(11, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::order_expiries(10, 0).is_none());
+		assert_eq!(OTC::next_expiry_check(), None);
+		assert_eq!(Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(
+			Tokens::free_balance(HDX, &ALICE),
+			alice_free_hdx_balance_before + 100 * ONE
+		);
+
+		expect_events(vec![Event::Expired { order_id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_not_remove_order_when_expiry_block_is_not_passed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None,
+			None
+		));
+
+		System::set_block_number(10);
+
+		// Act
+		OTC::on_idle(10, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_some());
+		assert!(OTC::order_expiries(10, 0).is_some());
+		assert_eq!(OTC::next_expiry_check(), Some(10));
+	});
+}
+
+#[test]
+fn on_idle_should_not_remove_expired_order_when_weight_is_not_sufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None,
+			None
+		));
+
+		System::set_block_number(11);
+
+		// Act
+		let weight = OTC::on_idle(11, Weight::zero());
+
+		// Assert
+		assert_eq!(weight, Weight::zero());
+		assert!(OTC::orders(0).is_some());
+		assert_eq!(OTC::next_expiry_check(), Some(1));
+	});
+}
+
+#[test]
+fn on_idle_should_only_read_next_expiry_check_when_no_orders_are_pending() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			Some(10),
+			None,
+			None
+		));
+		assert_ok!(OTC::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		System::set_block_number(5);
+		OTC::on_idle(5, Weight::MAX);
+		assert_eq!(OTC::next_expiry_check(), None);
+
+		System::set_block_number(6);
+
+		// Act
+		let weight = OTC::on_idle(6, Weight::MAX);
+
+		// Assert
+		assert_eq!(weight, <Test as frame_system::Config>::DbWeight::get().reads(1));
+		assert_eq!(OTC::next_expiry_check(), None);
+	});
+}
@@ -14,7 +14,7 @@
 // limitations under the License.
 use crate as otc;
 use crate::tests::mock::*;
-use crate::Event;
+use crate::{Error, Event};
 use frame_support::{assert_noop, assert_ok};
 use orml_tokens::Error::BalanceTooLow;
 use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
@@ -30,7 +30,10 @@ fn complete_fill_order_should_work() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -82,7 +85,10 @@ fn complete_fill_order_should_work_when_order_is_not_partially_fillable() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			false
+			false,
+			None,
+			None,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -134,7 +140,10 @@ fn complete_fill_order_should_work_when_there_are_multiple_orders() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		assert_ok!(OTC::place_order(
@@ -143,7 +152,10 @@ fn complete_fill_order_should_work_when_there_are_multiple_orders() {
 			HDX,
 			10 * ONE,
 			50 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -198,7 +210,10 @@ fn fill_order_should_throw_error_when_insufficient_balance() {
 			HDX,
 			200 * ONE,
 			100 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -229,3 +244,53 @@ fn fill_order_should_throw_error_when_insufficient_balance() {
 		assert_eq!(bob_dai_balance_after, bob_dai_balance_before);
 	});
 }
+
+#[test]
+fn fill_order_should_work_when_called_by_allowed_filler() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None,
+			Some(BOB),
+			None
+		));
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+	});
+}
+
+#[test]
+fn fill_order_should_throw_error_when_called_by_not_allowed_filler() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let charlie: AccountId = 3;
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			20 * ONE,
+			100 * ONE,
+			true,
+			None,
+			Some(charlie),
+			None
+		));
+
+		// Act & Assert
+		assert_noop!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0), Error::<Test>::Forbidden);
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 5 * ONE),
+			Error::<Test>::Forbidden
+		);
+	});
+}
@@ -99,7 +99,10 @@ proptest! {
 				HDX,
 				initial_amount_in,
 				initial_amount_out,
-				true
+				true,
+				None,
+				None,
+				None
 			).unwrap();
 
 			let initial_price = FixedU128::from_rational(initial_amount_out, initial_amount_in);
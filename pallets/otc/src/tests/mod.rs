@@ -16,6 +16,7 @@
 pub mod mock;
 
 pub mod cancel_order;
+pub mod expire_order;
 pub mod fill_order;
 pub mod invariants;
 pub mod partial_fill_order;
@@ -30,7 +30,10 @@ fn place_order_should_work() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		// Assert
@@ -72,7 +75,10 @@ fn place_order_should_work_when_user_has_multiple_orders() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		assert_ok!(OTC::place_order(
@@ -81,7 +87,10 @@ fn place_order_should_work_when_user_has_multiple_orders() {
 			HDX,
 			10 * ONE,
 			50 * ONE,
-			true
+			true,
+			None,
+			None,
+			None
 		));
 
 		// Assert
@@ -97,7 +106,17 @@ fn place_order_should_throw_error_when_amount_is_higher_than_balance() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Act
 		assert_noop!(
-			OTC::place_order(RuntimeOrigin::signed(ALICE), DAI, HDX, 20 * ONE, 100_000 * ONE, true),
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100_000 * ONE,
+				true,
+				None,
+				None,
+				None
+			),
 			BalanceTooLow::<Test>
 		);
 	});
@@ -108,7 +127,17 @@ fn place_order_should_throw_error_when_asset_out_is_not_registered() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Act
 		assert_noop!(
-			OTC::place_order(RuntimeOrigin::signed(ALICE), DAI, DOGE, 20 * ONE, 100 * ONE, true),
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				DOGE,
+				20 * ONE,
+				100 * ONE,
+				true,
+				None,
+				None,
+				None
+			),
 			BalanceTooLow::<Test>
 		);
 	});
@@ -119,7 +148,17 @@ fn place_order_should_throw_error_when_asset_in_is_not_registered() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Act
 		assert_noop!(
-			OTC::place_order(RuntimeOrigin::signed(ALICE), DOGE, HDX, 20 * ONE, 100 * ONE, true),
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DOGE,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				None,
+				None,
+				None
+			),
 			Error::<Test>::AssetNotRegistered
 		);
 	});
@@ -130,7 +169,17 @@ fn place_order_should_throw_error_when_amount_in_is_too_low() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Act
 		assert_noop!(
-			OTC::place_order(RuntimeOrigin::signed(ALICE), DAI, HDX, 4 * ONE, 100 * ONE, true),
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				4 * ONE,
+				100 * ONE,
+				true,
+				None,
+				None,
+				None
+			),
 			Error::<Test>::OrderAmountTooSmall
 		);
 	});
@@ -141,7 +190,17 @@ fn place_order_should_throw_error_when_amount_out_is_too_low() {
 	ExtBuilder::default().build().execute_with(|| {
 		// Act
 		assert_noop!(
-			OTC::place_order(RuntimeOrigin::signed(ALICE), DAI, HDX, 20 * ONE, 4 * ONE, true),
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				4 * ONE,
+				true,
+				None,
+				None,
+				None
+			),
 			Error::<Test>::OrderAmountTooSmall
 		);
 	});
@@ -0,0 +1,202 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for quoting router trades.
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use codec::Codec;
+use hydradx_traits::router::{AmountInAndOut, Trade, TradeQuoteError};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+pub use pallet_route_executor_runtime_api::RouterApi as RouterRuntimeApi;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Direction of the quoted trade.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TradeType {
+	/// Quote a sell of given amount of `asset_in`.
+	Sell,
+	/// Quote a buy of given amount of `asset_out`.
+	Buy,
+}
+
+/// Route with the best output found for a sell.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BestRoute<AssetId, Balance> {
+	pub route: Vec<Trade<AssetId>>,
+	pub amount_out: Balance,
+}
+
+#[rpc(client, server)]
+pub trait RouterApi<BlockHash, AssetId, Balance> {
+	/// Calculates the amounts in and out of each trade of the route, without executing it.
+	///
+	/// If `route` is not provided, the on-chain route for the asset pair is used.
+	#[method(name = "router_quote")]
+	fn quote(
+		&self,
+		trade_type: TradeType,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		route: Option<Vec<Trade<AssetId>>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<AmountInAndOut<Balance>>>;
+
+	/// Finds the route with the best output for a sell of `amount_in` of `asset_in` for `asset_out`.
+	#[method(name = "router_findBestRoute")]
+	fn find_best_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<BestRoute<AssetId, Balance>>;
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// A trade of the route could not be calculated.
+	TradeCalculationError,
+	/// No route could be found.
+	RouteNotFound,
+	/// The route has too many trades.
+	RouteTooLong,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::TradeCalculationError => 2,
+			Error::RouteNotFound => 3,
+			Error::RouteTooLong => 4,
+		}
+	}
+}
+
+/// Provides RPC methods to query router trade quotes.
+pub struct Router<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Router<C, Block> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+impl<C, Block, AssetId, Balance> RouterApiServer<<Block as BlockT>::Hash, AssetId, Balance> for Router<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: RouterRuntimeApi<Block, AssetId, Balance>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	Balance: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn quote(
+		&self,
+		trade_type: TradeType,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount: Balance,
+		route: Option<Vec<Trade<AssetId>>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<AmountInAndOut<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let route = route.unwrap_or_default();
+
+		let quote = match trade_type {
+			TradeType::Sell => api.quote_sell(at, asset_in, asset_out, amount, route),
+			TradeType::Buy => api.quote_buy(at, asset_in, asset_out, amount, route),
+		}
+		.map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to query router quote.",
+				Some(e.to_string()),
+			))
+		})?;
+
+		quote.map_err(|error| match error {
+			TradeQuoteError::RouteTooLong => CallError::Custom(ErrorObject::owned(
+				Error::RouteTooLong.into(),
+				"The route has too many trades.",
+				None::<String>,
+			))
+			.into(),
+			TradeQuoteError::RouteNotFound => CallError::Custom(ErrorObject::owned(
+				Error::RouteNotFound.into(),
+				"No route found.",
+				None::<String>,
+			))
+			.into(),
+			TradeQuoteError::TradeCalculationFailed { trade_index, error } => CallError::Custom(ErrorObject::owned(
+				Error::TradeCalculationError.into(),
+				format!("Calculation of trade {trade_index} of the route failed."),
+				Some(format!("{error:?}")),
+			))
+			.into(),
+		})
+	}
+
+	fn find_best_route(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<BestRoute<AssetId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let best_route = api.find_best_route(at, asset_in, asset_out, amount_in).map_err(|e| {
+			CallError::Custom(ErrorObject::owned(
+				Error::RuntimeError.into(),
+				"Unable to find best route.",
+				Some(e.to_string()),
+			))
+		})?;
+
+		best_route
+			.map(|(route, amount_out)| BestRoute { route, amount_out })
+			.map_err(|error| {
+				CallError::Custom(ErrorObject::owned(
+					Error::RouteNotFound.into(),
+					"No route found.",
+					Some(format!("{error:?}")),
+				))
+				.into()
+			})
+	}
+}
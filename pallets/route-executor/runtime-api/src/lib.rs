@@ -0,0 +1,63 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for quoting router trades.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use hydradx_traits::router::{AmountInAndOut, Trade, TradeQuoteError};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to calculate amounts of router trades without executing them.
+	pub trait RouterApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Calculates the amounts in and out of each trade of a sell of `amount_in` of `asset_in`.
+		///
+		/// If `route` is empty, the on-chain route for the asset pair is used.
+		fn quote_sell(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			route: Vec<Trade<AssetId>>,
+		) -> Result<Vec<AmountInAndOut<Balance>>, TradeQuoteError>;
+
+		/// Calculates the amounts in and out of each trade of a buy of `amount_out` of `asset_out`.
+		///
+		/// If `route` is empty, the on-chain route for the asset pair is used.
+		fn quote_buy(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+			route: Vec<Trade<AssetId>>,
+		) -> Result<Vec<AmountInAndOut<Balance>>, TradeQuoteError>;
+
+		/// Finds the route with the best output for a sell of `amount_in` of `asset_in` for `asset_out`,
+		/// among all pools supported by the router.
+		///
+		/// Returns the route and its expected amount out.
+		fn find_best_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Result<(Vec<Trade<AssetId>>, Balance), DispatchError>;
+	}
+}
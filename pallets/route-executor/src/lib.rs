@@ -32,11 +32,13 @@ use frame_system::pallet_prelude::OriginFor;
 use frame_system::{ensure_signed, Origin};
 use hydradx_traits::router::{inverse_route, AssetPair, RouteProvider};
 pub use hydradx_traits::router::{
-	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, Trade, TradeExecution,
+	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, TradablePairs, Trade, TradeExecution,
+	TradeQuoteError,
 };
 use orml_traits::arithmetic::{CheckedAdd, CheckedSub};
-use sp_runtime::traits::{AccountIdConversion, CheckedDiv};
-use sp_runtime::{ArithmeticError, DispatchError, TransactionOutcome};
+use sp_runtime::traits::{AccountIdConversion, CheckedDiv, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, Permill, RuntimeDebug, TransactionOutcome};
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::{vec, vec::Vec};
 
 #[cfg(test)]
@@ -48,6 +50,14 @@ pub mod weights;
 pub use pallet::*;
 
 pub const MAX_NUMBER_OF_TRADES: u32 = 5;
+pub const MAX_NUMBER_OF_SPLIT_ROUTES: u32 = 3;
+
+/// A route with the weight of the total trade amount to be executed through it
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SplitRoute<AssetId> {
+	pub weight: Permill,
+	pub route: Vec<Trade<AssetId>>,
+}
 
 //TODO: rebenchmark on reference machine
 
@@ -76,6 +86,7 @@ pub mod pallet {
 			+ PartialOrd
 			+ MaybeSerializeDeserialize
 			+ From<u128>
+			+ Into<u128>
 			+ Default
 			+ CheckedSub
 			+ CheckedAdd
@@ -98,6 +109,9 @@ pub mod pallet {
 			Error = DispatchError,
 		>;
 
+		/// Provider of all tradable asset pairs of the AMM pools, used to discover routes
+		type Pools: TradablePairs<Self::AssetId>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: AmmTradeWeights<Trade<Self::AssetId>>;
 	}
@@ -114,6 +128,14 @@ pub mod pallet {
 		},
 		///The route with trades has been successfully executed
 		RouteUpdated { asset_ids: Vec<T::AssetId> },
+		///The trade split across several routes has been successfully executed
+		SplitRouteExecuted {
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+			number_of_routes: u32,
+		},
 	}
 
 	#[pallet::error]
@@ -136,6 +158,14 @@ pub mod pallet {
 		InvalidRoute,
 		///The route update was not successful
 		RouteUpdateIsNotSuccessful,
+		///No route has been found between the assets
+		RouteNotFound,
+		///The weights of the split routes are zero or do not sum up to 100%
+		InvalidSplitWeights,
+		///The max number of split routes is exceeded
+		MaxSplitRoutesExceeded,
+		///The amount of a split route is zero
+		ZeroSplitAmount,
 	}
 
 	/// Storing routes for asset pairs
@@ -174,60 +204,13 @@ pub mod pallet {
 			min_amount_out: T::Balance,
 			route: Vec<Trade<T::AssetId>>,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-			Self::ensure_route_size(route.len())?;
-
-			let route = Self::get_route_or_default(route, AssetPair::new(asset_in, asset_out))?;
-
-			let user_balance_of_asset_in_before_trade = T::Currency::reducible_balance(asset_in, &who, false);
-			let user_balance_of_asset_out_before_trade = T::Currency::reducible_balance(asset_out, &who, false);
-			ensure!(
-				user_balance_of_asset_in_before_trade >= amount_in,
-				Error::<T>::InsufficientBalance
-			);
-
-			let trade_amounts = Self::calculate_sell_trade_amounts(&route, amount_in)?;
-
-			let last_trade_amount = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
-			ensure!(
-				last_trade_amount.amount_out >= min_amount_out,
-				Error::<T>::TradingLimitReached
-			);
-
-			for (trade_amount, trade) in trade_amounts.iter().zip(route) {
-				let user_balance_of_asset_in_before_trade = T::Currency::reducible_balance(trade.asset_in, &who, true);
-
-				let execution_result = T::AMM::execute_sell(
-					origin.clone(),
-					trade.pool,
-					trade.asset_in,
-					trade.asset_out,
-					trade_amount.amount_in,
-					trade_amount.amount_out,
-				);
-
-				handle_execution_error!(execution_result);
-
-				Self::ensure_that_user_spent_asset_in(
-					who.clone(),
-					trade.asset_in,
-					user_balance_of_asset_in_before_trade,
-					trade_amount.amount_in,
-				)?;
-			}
-
-			Self::ensure_that_user_received_asset_out(
-				who,
-				asset_out,
-				user_balance_of_asset_out_before_trade,
-				last_trade_amount.amount_out,
-			)?;
+			let amount_out = Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)?;
 
 			Self::deposit_event(Event::RouteExecuted {
 				asset_in,
 				asset_out,
 				amount_in,
-				amount_out: last_trade_amount.amount_out,
+				amount_out,
 			});
 
 			Ok(())
@@ -257,55 +240,12 @@ pub mod pallet {
 			max_amount_in: T::Balance,
 			route: Vec<Trade<T::AssetId>>,
 		) -> DispatchResult {
-			let who = ensure_signed(origin.clone())?;
-			Self::ensure_route_size(route.len())?;
-
-			let route = Self::get_route_or_default(route, AssetPair::new(asset_in, asset_out))?;
-
-			let user_balance_of_asset_in_before_trade = T::Currency::reducible_balance(asset_in, &who, true);
-
-			let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
-
-			let last_trade_amount = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
-			ensure!(
-				last_trade_amount.amount_in <= max_amount_in,
-				Error::<T>::TradingLimitReached
-			);
-
-			for (trade_amount, trade) in trade_amounts.iter().rev().zip(route) {
-				let user_balance_of_asset_out_before_trade =
-					T::Currency::reducible_balance(trade.asset_out, &who, false);
-
-				let execution_result = T::AMM::execute_buy(
-					origin.clone(),
-					trade.pool,
-					trade.asset_in,
-					trade.asset_out,
-					trade_amount.amount_out,
-					trade_amount.amount_in,
-				);
-
-				handle_execution_error!(execution_result);
-
-				Self::ensure_that_user_received_asset_out(
-					who.clone(),
-					trade.asset_out,
-					user_balance_of_asset_out_before_trade,
-					trade_amount.amount_out,
-				)?;
-			}
-
-			Self::ensure_that_user_spent_asset_in(
-				who,
-				asset_in,
-				user_balance_of_asset_in_before_trade,
-				last_trade_amount.amount_in,
-			)?;
+			let amount_in = Self::do_buy(origin, asset_in, asset_out, amount_out, max_amount_in, route)?;
 
 			Self::deposit_event(Event::RouteExecuted {
 				asset_in,
 				asset_out,
-				amount_in: last_trade_amount.amount_in,
+				amount_in,
 				amount_out,
 			});
 
@@ -393,6 +333,129 @@ pub mod pallet {
 
 			Err(Error::<T>::RouteUpdateIsNotSuccessful.into())
 		}
+
+		/// Executes a sell split across several routes, executed one after another.
+		///
+		/// `amount_in` is distributed between the routes according to their weights,
+		/// which must sum up to 100%. The last route receives the remainder of the split.
+		///
+		/// All routes are executed atomically. The slippage limit applies to the total amount received from all routes.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `amount_in`: The total amount of `asset_in` to sell
+		/// - `min_amount_out`: The minimum total amount of `asset_out` to receive.
+		/// - `routes`: Weighted routes to be executed. A route which is not specified is replaced by the on-chain route.
+		///
+		/// Emits `SplitRouteExecuted` when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(Pallet::<T>::sell_split_weight(routes))]
+		#[transactional]
+		pub fn sell_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+			routes: Vec<SplitRoute<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			let amounts_in = Self::split_amount(amount_in, AssetPair::new(asset_in, asset_out), &routes)?;
+
+			let user_balance_of_asset_in = T::Currency::reducible_balance(asset_in, &who, false);
+			ensure!(user_balance_of_asset_in >= amount_in, Error::<T>::InsufficientBalance);
+
+			let number_of_routes = routes.len() as u32;
+			let mut total_amount_out = T::Balance::default();
+
+			for (split_route, route_amount_in) in routes.into_iter().zip(amounts_in) {
+				let route_amount_out = Self::do_sell(
+					origin.clone(),
+					asset_in,
+					asset_out,
+					route_amount_in,
+					T::Balance::default(),
+					split_route.route,
+				)?;
+
+				total_amount_out = total_amount_out
+					.checked_add(&route_amount_out)
+					.ok_or(ArithmeticError::Overflow)?;
+			}
+
+			ensure!(total_amount_out >= min_amount_out, Error::<T>::TradingLimitReached);
+
+			Self::deposit_event(Event::SplitRouteExecuted {
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out: total_amount_out,
+				number_of_routes,
+			});
+
+			Ok(())
+		}
+
+		/// Executes a buy split across several routes, executed one after another.
+		///
+		/// `amount_out` is distributed between the routes according to their weights,
+		/// which must sum up to 100%. The last route receives the remainder of the split.
+		///
+		/// All routes are executed atomically. The slippage limit applies to the total amount spent on all routes.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `amount_out`: The total amount of `asset_out` to buy
+		/// - `max_amount_in`: The max total amount of `asset_in` to spend on the buy.
+		/// - `routes`: Weighted routes to be executed. A route which is not specified is replaced by the on-chain route.
+		///
+		/// Emits `SplitRouteExecuted` when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(Pallet::<T>::buy_split_weight(routes))]
+		#[transactional]
+		pub fn buy_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+			routes: Vec<SplitRoute<T::AssetId>>,
+		) -> DispatchResult {
+			let amounts_out = Self::split_amount(amount_out, AssetPair::new(asset_in, asset_out), &routes)?;
+
+			let number_of_routes = routes.len() as u32;
+			let mut total_amount_in = T::Balance::default();
+
+			for (split_route, route_amount_out) in routes.into_iter().zip(amounts_out) {
+				let route_amount_in = Self::do_buy(
+					origin.clone(),
+					asset_in,
+					asset_out,
+					route_amount_out,
+					max_amount_in,
+					split_route.route,
+				)?;
+
+				total_amount_in = total_amount_in
+					.checked_add(&route_amount_in)
+					.ok_or(ArithmeticError::Overflow)?;
+			}
+
+			ensure!(total_amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+
+			Self::deposit_event(Event::SplitRouteExecuted {
+				asset_in,
+				asset_out,
+				amount_in: total_amount_in,
+				amount_out,
+				number_of_routes,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -447,6 +510,182 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn do_sell(
+		origin: OriginFor<T>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+		min_amount_out: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<T::Balance, DispatchError> {
+		let who = ensure_signed(origin.clone())?;
+		Self::ensure_route_size(route.len())?;
+
+		let route = Self::get_route_or_default(route, AssetPair::new(asset_in, asset_out))?;
+
+		let user_balance_of_asset_in_before_trade = T::Currency::reducible_balance(asset_in, &who, false);
+		let user_balance_of_asset_out_before_trade = T::Currency::reducible_balance(asset_out, &who, false);
+		ensure!(
+			user_balance_of_asset_in_before_trade >= amount_in,
+			Error::<T>::InsufficientBalance
+		);
+
+		let trade_amounts = Self::calculate_sell_trade_amounts(&route, amount_in)?;
+
+		let last_trade_amount = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+		ensure!(
+			last_trade_amount.amount_out >= min_amount_out,
+			Error::<T>::TradingLimitReached
+		);
+
+		for (trade_amount, trade) in trade_amounts.iter().zip(route) {
+			let user_balance_of_asset_in_before_trade = T::Currency::reducible_balance(trade.asset_in, &who, true);
+
+			let execution_result = T::AMM::execute_sell(
+				origin.clone(),
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_in,
+				trade_amount.amount_out,
+			);
+
+			handle_execution_error!(execution_result);
+
+			Self::ensure_that_user_spent_asset_in(
+				who.clone(),
+				trade.asset_in,
+				user_balance_of_asset_in_before_trade,
+				trade_amount.amount_in,
+			)?;
+		}
+
+		Self::ensure_that_user_received_asset_out(
+			who,
+			asset_out,
+			user_balance_of_asset_out_before_trade,
+			last_trade_amount.amount_out,
+		)?;
+
+		Ok(last_trade_amount.amount_out)
+	}
+
+	fn do_buy(
+		origin: OriginFor<T>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<T::Balance, DispatchError> {
+		let who = ensure_signed(origin.clone())?;
+		Self::ensure_route_size(route.len())?;
+
+		let route = Self::get_route_or_default(route, AssetPair::new(asset_in, asset_out))?;
+
+		let user_balance_of_asset_in_before_trade = T::Currency::reducible_balance(asset_in, &who, true);
+
+		let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
+
+		let last_trade_amount = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+		ensure!(
+			last_trade_amount.amount_in <= max_amount_in,
+			Error::<T>::TradingLimitReached
+		);
+
+		for (trade_amount, trade) in trade_amounts.iter().rev().zip(route) {
+			let user_balance_of_asset_out_before_trade = T::Currency::reducible_balance(trade.asset_out, &who, false);
+
+			let execution_result = T::AMM::execute_buy(
+				origin.clone(),
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_out,
+				trade_amount.amount_in,
+			);
+
+			handle_execution_error!(execution_result);
+
+			Self::ensure_that_user_received_asset_out(
+				who.clone(),
+				trade.asset_out,
+				user_balance_of_asset_out_before_trade,
+				trade_amount.amount_out,
+			)?;
+		}
+
+		Self::ensure_that_user_spent_asset_in(
+			who,
+			asset_in,
+			user_balance_of_asset_in_before_trade,
+			last_trade_amount.amount_in,
+		)?;
+
+		Ok(last_trade_amount.amount_in)
+	}
+
+	/// Splits `amount` between the routes according to their weights.
+	/// The last route gets the remainder, so that the whole amount is distributed.
+	fn split_amount(
+		amount: T::Balance,
+		asset_pair: AssetPair<T::AssetId>,
+		routes: &[SplitRoute<T::AssetId>],
+	) -> Result<Vec<T::Balance>, DispatchError> {
+		ensure!(!routes.is_empty(), Error::<T>::RouteHasNoTrades);
+		ensure!(
+			(routes.len() as u32) <= MAX_NUMBER_OF_SPLIT_ROUTES,
+			Error::<T>::MaxSplitRoutesExceeded
+		);
+
+		let total_weight = routes.iter().try_fold(0u32, |total, split_route| {
+			ensure!(!split_route.weight.is_zero(), Error::<T>::InvalidSplitWeights);
+			Ok::<u32, Error<T>>(total.saturating_add(split_route.weight.deconstruct()))
+		})?;
+		ensure!(
+			total_weight == Permill::one().deconstruct(),
+			Error::<T>::InvalidSplitWeights
+		);
+
+		for split_route in routes.iter() {
+			if let (Some(first_trade), Some(last_trade)) = (split_route.route.first(), split_route.route.last()) {
+				ensure!(
+					first_trade.asset_in == asset_pair.asset_in && last_trade.asset_out == asset_pair.asset_out,
+					Error::<T>::InvalidRoute
+				);
+			}
+		}
+
+		let amount: u128 = amount.into();
+		let mut remaining = amount;
+		let mut amounts = Vec::with_capacity(routes.len());
+
+		for (idx, split_route) in routes.iter().enumerate() {
+			let route_amount = if idx == routes.len() - 1 {
+				remaining
+			} else {
+				split_route.weight.mul_floor(amount)
+			};
+			ensure!(!route_amount.is_zero(), Error::<T>::ZeroSplitAmount);
+			remaining = remaining.checked_sub(route_amount).ok_or(ArithmeticError::Underflow)?;
+			amounts.push(route_amount.into());
+		}
+
+		Ok(amounts)
+	}
+
+	fn sell_split_weight(routes: &[SplitRoute<T::AssetId>]) -> Weight {
+		routes.iter().fold(Weight::zero(), |weight, split_route| {
+			weight.saturating_add(T::WeightInfo::sell_weight(&split_route.route))
+		})
+	}
+
+	fn buy_split_weight(routes: &[SplitRoute<T::AssetId>]) -> Weight {
+		routes.iter().fold(Weight::zero(), |weight, split_route| {
+			weight.saturating_add(T::WeightInfo::buy_weight(&split_route.route))
+		})
+	}
+
 	fn get_route_or_default(
 		route: Vec<Trade<T::AssetId>>,
 		asset_pair: AssetPair<T::AssetId>,
@@ -526,14 +765,27 @@ impl<T: Config> Pallet<T> {
 		route: &[Trade<T::AssetId>],
 		amount_in: T::Balance,
 	) -> Result<Vec<AmountInAndOut<T::Balance>>, DispatchError> {
+		Self::calculate_sell_trade_amounts_per_trade(route, amount_in).map_err(Self::to_dispatch_error)
+	}
+
+	fn calculate_buy_trade_amounts(
+		route: &[Trade<T::AssetId>],
+		amount_out: T::Balance,
+	) -> Result<Vec<AmountInAndOut<T::Balance>>, DispatchError> {
+		Self::calculate_buy_trade_amounts_per_trade(route, amount_out).map_err(Self::to_dispatch_error)
+	}
+
+	fn calculate_sell_trade_amounts_per_trade(
+		route: &[Trade<T::AssetId>],
+		amount_in: T::Balance,
+	) -> Result<Vec<AmountInAndOut<T::Balance>>, TradeQuoteError> {
 		let mut amount_in_and_outs = Vec::<AmountInAndOut<T::Balance>>::with_capacity(route.len());
 		let mut amount_in = amount_in;
 
-		for trade in route.iter() {
+		for (trade_index, trade) in route.iter().enumerate() {
 			let result = T::AMM::calculate_sell(trade.pool, trade.asset_in, trade.asset_out, amount_in);
 			match result {
-				Err(ExecutorError::NotSupported) => return Err(Error::<T>::PoolNotSupported.into()),
-				Err(ExecutorError::Error(dispatch_error)) => return Err(dispatch_error),
+				Err(error) => return Err(Self::to_trade_quote_error(trade_index, error)),
 				Ok(amount_out) => {
 					amount_in_and_outs.push(AmountInAndOut { amount_in, amount_out });
 					amount_in = amount_out;
@@ -544,19 +796,18 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_in_and_outs)
 	}
 
-	fn calculate_buy_trade_amounts(
+	fn calculate_buy_trade_amounts_per_trade(
 		route: &[Trade<T::AssetId>],
 		amount_out: T::Balance,
-	) -> Result<Vec<AmountInAndOut<T::Balance>>, DispatchError> {
+	) -> Result<Vec<AmountInAndOut<T::Balance>>, TradeQuoteError> {
 		let mut amount_in_and_outs = Vec::<AmountInAndOut<T::Balance>>::with_capacity(route.len());
 		let mut amount_out = amount_out;
 
-		for trade in route.iter().rev() {
+		for (trade_index, trade) in route.iter().enumerate().rev() {
 			let result = T::AMM::calculate_buy(trade.pool, trade.asset_in, trade.asset_out, amount_out);
 
 			match result {
-				Err(ExecutorError::NotSupported) => return Err(Error::<T>::PoolNotSupported.into()),
-				Err(ExecutorError::Error(dispatch_error)) => return Err(dispatch_error),
+				Err(error) => return Err(Self::to_trade_quote_error(trade_index, error)),
 				Ok(amount_in) => {
 					amount_in_and_outs.push(AmountInAndOut { amount_in, amount_out });
 					amount_out = amount_in;
@@ -567,6 +818,131 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_in_and_outs)
 	}
 
+	fn to_trade_quote_error(trade_index: usize, error: ExecutorError<DispatchError>) -> TradeQuoteError {
+		let error = match error {
+			ExecutorError::NotSupported => Error::<T>::PoolNotSupported.into(),
+			ExecutorError::Error(dispatch_error) => dispatch_error,
+		};
+
+		TradeQuoteError::TradeCalculationFailed {
+			trade_index: trade_index as u32,
+			error,
+		}
+	}
+
+	fn to_dispatch_error(error: TradeQuoteError) -> DispatchError {
+		match error {
+			TradeQuoteError::RouteTooLong => Error::<T>::MaxTradesExceeded.into(),
+			TradeQuoteError::RouteNotFound => Error::<T>::RouteNotFound.into(),
+			TradeQuoteError::TradeCalculationFailed { error, .. } => error,
+		}
+	}
+
+	/// Calculates the amounts in and out of each trade of a sell, without executing it.
+	///
+	/// If `route` is empty, then the on-chain route (or the default omnipool route) is used.
+	///
+	/// The amounts are returned in the order of the trades in the route.
+	/// On failure, the error contains the index of the trade which could not be calculated.
+	pub fn quote_sell(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<Vec<AmountInAndOut<T::Balance>>, TradeQuoteError> {
+		let route = Self::get_route_for_quote(route, AssetPair::new(asset_in, asset_out))?;
+
+		Self::calculate_sell_trade_amounts_per_trade(&route, amount_in)
+	}
+
+	/// Calculates the amounts in and out of each trade of a buy, without executing it.
+	///
+	/// If `route` is empty, then the on-chain route (or the default omnipool route) is used.
+	///
+	/// The amounts are returned in the order of the trades in the route.
+	/// On failure, the error contains the index of the trade which could not be calculated.
+	pub fn quote_buy(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<Vec<AmountInAndOut<T::Balance>>, TradeQuoteError> {
+		let route = Self::get_route_for_quote(route, AssetPair::new(asset_in, asset_out))?;
+
+		let mut trade_amounts = Self::calculate_buy_trade_amounts_per_trade(&route, amount_out)?;
+		trade_amounts.reverse();
+
+		Ok(trade_amounts)
+	}
+
+	/// Finds the route with the best output for a sell of `amount_in` of `asset_in` for `asset_out`.
+	///
+	/// The route is searched among all the tradable asset pairs provided by the pools,
+	/// with at most `MAX_NUMBER_OF_TRADES` trades, visiting each asset at most once.
+	///
+	/// In each round, the best known route to every asset is extended by one trade,
+	/// keeping a route only if it yields a higher amount of its last asset than the best known one.
+	///
+	/// Returns the route together with its expected amount out.
+	pub fn find_best_route(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+	) -> Result<(Vec<Trade<T::AssetId>>, T::Balance), DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::InvalidRoute);
+
+		let tradable_pairs = T::Pools::tradable_pairs();
+
+		let mut best_routes = BTreeMap::<T::AssetId, (T::Balance, Vec<Trade<T::AssetId>>)>::new();
+		best_routes.insert(asset_in, (amount_in, vec![]));
+
+		for _ in 0..MAX_NUMBER_OF_TRADES {
+			let mut extended_routes = best_routes.clone();
+
+			for trade in tradable_pairs.iter() {
+				let Some((amount, route)) = best_routes.get(&trade.asset_in) else {
+					continue;
+				};
+
+				let asset_already_visited =
+					trade.asset_out == asset_in || route.iter().any(|visited| visited.asset_out == trade.asset_out);
+				if asset_already_visited {
+					continue;
+				}
+
+				let Ok(amount_out) = T::AMM::calculate_sell(trade.pool, trade.asset_in, trade.asset_out, *amount) else {
+					continue;
+				};
+
+				let is_better = extended_routes
+					.get(&trade.asset_out)
+					.map_or(true, |(best_amount, _)| amount_out > *best_amount);
+				if is_better {
+					let mut extended_route = route.clone();
+					extended_route.push(*trade);
+					extended_routes.insert(trade.asset_out, (amount_out, extended_route));
+				}
+			}
+
+			best_routes = extended_routes;
+		}
+
+		let (_, best_route) = best_routes.remove(&asset_out).ok_or(Error::<T>::RouteNotFound)?;
+
+		let amount_out = Self::calculate_expected_amount_out(&best_route, amount_in)?;
+
+		Ok((best_route, amount_out))
+	}
+
+	fn get_route_for_quote(
+		route: Vec<Trade<T::AssetId>>,
+		asset_pair: AssetPair<T::AssetId>,
+	) -> Result<Vec<Trade<T::AssetId>>, TradeQuoteError> {
+		Self::ensure_route_size(route.len()).map_err(|_| TradeQuoteError::RouteTooLong)?;
+
+		Self::get_route_or_default(route, asset_pair).map_err(|_| TradeQuoteError::RouteNotFound)
+	}
+
 	fn insert_route(asset_pair: AssetPair<T::AssetId>, route: Vec<Trade<T::AssetId>>) -> DispatchResultWithPostInfo {
 		let route_as_bounded_vec: BoundedVec<Trade<T::AssetId>, sp_runtime::traits::ConstU32<MAX_NUMBER_OF_TRADES>> =
 			route.try_into().map_err(|_| Error::<T>::MaxTradesExceeded)?;
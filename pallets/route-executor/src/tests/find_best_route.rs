@@ -0,0 +1,93 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::Error;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn find_best_route_should_prefer_multi_trade_route_with_better_output() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let best_route = Router::find_best_route(HDX, AUSD, 10);
+
+		//Assert
+		assert_eq!(
+			best_route,
+			Ok((
+				vec![HDX_MOVR_TRADE_IN_XYK, MOVR_AUSD_TRADE_IN_LBP],
+				LBP_SELL_CALCULATION_RESULT
+			))
+		);
+	});
+}
+
+#[test]
+fn find_best_route_should_keep_shorter_route_when_outputs_are_equal() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let best_route = Router::find_best_route(HDX, KSM, 10);
+
+		//Assert
+		assert_eq!(
+			best_route,
+			Ok((
+				vec![HDX_AUSD_TRADE_IN_OMNIPOOL, AUSD_KSM_TRADE_IN_STABLESWAP],
+				STABLESWAP_SELL_CALCULATION_RESULT
+			))
+		);
+	});
+}
+
+#[test]
+fn find_best_route_should_return_single_trade_route_when_only_one_exists() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let best_route = Router::find_best_route(HDX, MOVR, 10);
+
+		//Assert
+		assert_eq!(
+			best_route,
+			Ok((vec![HDX_MOVR_TRADE_IN_XYK], XYK_SELL_CALCULATION_RESULT))
+		);
+	});
+}
+
+#[test]
+fn find_best_route_should_fail_when_assets_are_not_connected() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(Router::find_best_route(HDX, DOT, 10), Error::<Test>::RouteNotFound);
+	});
+}
+
+#[test]
+fn find_best_route_should_skip_trades_which_cannot_be_calculated() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Router::find_best_route(HDX, AUSD, INVALID_CALCULATION_AMOUNT),
+			Error::<Test>::RouteNotFound
+		);
+	});
+}
+
+#[test]
+fn find_best_route_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(Router::find_best_route(HDX, HDX, 10), Error::<Test>::InvalidRoute);
+	});
+}
@@ -22,7 +22,7 @@ use frame_support::traits::{Everything, GenesisBuild, Nothing};
 use frame_system as system;
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::OriginFor;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, TradablePairs, TradeExecution};
 use orml_traits::parameter_type_with_key;
 use pallet_currencies::fungibles::FungibleCurrencies;
 use pallet_currencies::BasicCurrencyAdapter;
@@ -150,6 +150,7 @@ impl Config for Test {
 	type NativeAssetId = NativeCurrencyId;
 	type Currency = FungibleCurrencies<Test>;
 	type AMM = Pools;
+	type Pools = MockedTradablePairs;
 	type WeightInfo = ();
 }
 
@@ -195,6 +196,43 @@ pub const HDX_AUSD_TRADE_IN_XYK: Trade<AssetId> = Trade {
 	asset_out: AUSD,
 };
 
+pub const HDX_MOVR_TRADE_IN_XYK: Trade<AssetId> = Trade {
+	pool: PoolType::XYK,
+	asset_in: HDX,
+	asset_out: MOVR,
+};
+
+pub const MOVR_AUSD_TRADE_IN_LBP: Trade<AssetId> = Trade {
+	pool: PoolType::LBP,
+	asset_in: MOVR,
+	asset_out: AUSD,
+};
+
+pub const HDX_AUSD_TRADE_IN_OMNIPOOL: Trade<AssetId> = Trade {
+	pool: PoolType::Omnipool,
+	asset_in: HDX,
+	asset_out: AUSD,
+};
+
+pub const AUSD_KSM_TRADE_IN_STABLESWAP: Trade<AssetId> = Trade {
+	pool: PoolType::Stableswap(STABLE_SHARE_ASSET),
+	asset_in: AUSD,
+	asset_out: KSM,
+};
+
+pub struct MockedTradablePairs;
+
+impl TradablePairs<AssetId> for MockedTradablePairs {
+	fn tradable_pairs() -> Vec<Trade<AssetId>> {
+		vec![
+			HDX_AUSD_TRADE_IN_OMNIPOOL,
+			HDX_MOVR_TRADE_IN_XYK,
+			MOVR_AUSD_TRADE_IN_LBP,
+			AUSD_KSM_TRADE_IN_STABLESWAP,
+		]
+	}
+}
+
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
 }
@@ -1,4 +1,7 @@
 pub mod buy;
+pub mod find_best_route;
 pub mod mock;
+pub mod quote;
 pub mod sell;
 pub mod set_route;
+pub mod split;
@@ -0,0 +1,170 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{AmountInAndOut, Trade, TradeQuoteError, MAX_NUMBER_OF_TRADES};
+use hydradx_traits::router::PoolType;
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError;
+
+fn multi_pool_route() -> Vec<Trade<AssetId>> {
+	vec![
+		Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: MOVR,
+		},
+		Trade {
+			pool: PoolType::Stableswap(AUSD),
+			asset_in: MOVR,
+			asset_out: KSM,
+		},
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: KSM,
+			asset_out: DOT,
+		},
+	]
+}
+
+#[test]
+fn quote_sell_should_return_amounts_of_all_trades() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+
+		//Act
+		let trade_amounts = Router::quote_sell(HDX, DOT, amount_to_sell, multi_pool_route());
+
+		//Assert
+		assert_eq!(
+			trade_amounts,
+			Ok(vec![
+				AmountInAndOut {
+					amount_in: amount_to_sell,
+					amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+				},
+				AmountInAndOut {
+					amount_in: OMNIPOOL_SELL_CALCULATION_RESULT,
+					amount_out: STABLESWAP_SELL_CALCULATION_RESULT,
+				},
+				AmountInAndOut {
+					amount_in: STABLESWAP_SELL_CALCULATION_RESULT,
+					amount_out: XYK_SELL_CALCULATION_RESULT,
+				},
+			])
+		);
+	});
+}
+
+#[test]
+fn quote_buy_should_return_amounts_of_all_trades_in_route_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+
+		//Act
+		let trade_amounts = Router::quote_buy(HDX, DOT, amount_to_buy, multi_pool_route());
+
+		//Assert
+		assert_eq!(
+			trade_amounts,
+			Ok(vec![
+				AmountInAndOut {
+					amount_in: OMNIPOOL_BUY_CALCULATION_RESULT,
+					amount_out: STABLESWAP_BUY_CALCULATION_RESULT,
+				},
+				AmountInAndOut {
+					amount_in: STABLESWAP_BUY_CALCULATION_RESULT,
+					amount_out: XYK_BUY_CALCULATION_RESULT,
+				},
+				AmountInAndOut {
+					amount_in: XYK_BUY_CALCULATION_RESULT,
+					amount_out: amount_to_buy,
+				},
+			])
+		);
+	});
+}
+
+#[test]
+fn quote_sell_should_use_default_omnipool_route_when_no_route_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+
+		//Act
+		let trade_amounts = Router::quote_sell(HDX, AUSD, amount_to_sell, vec![]);
+
+		//Assert
+		assert_eq!(
+			trade_amounts,
+			Ok(vec![AmountInAndOut {
+				amount_in: amount_to_sell,
+				amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+			}])
+		);
+	});
+}
+
+#[test]
+fn quote_sell_should_return_index_of_failing_trade() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let trade_amounts = Router::quote_sell(HDX, DOT, INVALID_CALCULATION_AMOUNT, multi_pool_route());
+
+		//Assert
+		assert_eq!(
+			trade_amounts,
+			Err(TradeQuoteError::TradeCalculationFailed {
+				trade_index: 0,
+				error: DispatchError::Other("Some error happened"),
+			})
+		);
+	});
+}
+
+#[test]
+fn quote_buy_should_return_index_of_failing_trade() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let trade_amounts = Router::quote_buy(HDX, DOT, INVALID_CALCULATION_AMOUNT, multi_pool_route());
+
+		//Assert
+		assert_eq!(
+			trade_amounts,
+			Err(TradeQuoteError::TradeCalculationFailed {
+				trade_index: 2,
+				error: DispatchError::Other("Some error happened"),
+			})
+		);
+	});
+}
+
+#[test]
+fn quote_sell_should_fail_when_route_has_too_many_trades() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let route = vec![HDX_AUSD_TRADE_IN_XYK; MAX_NUMBER_OF_TRADES as usize + 1];
+
+		//Act
+		let trade_amounts = Router::quote_sell(HDX, AUSD, 10, route);
+
+		//Assert
+		assert_eq!(trade_amounts, Err(TradeQuoteError::RouteTooLong));
+	});
+}
@@ -0,0 +1,305 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, SplitRoute};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use sp_runtime::Permill;
+
+fn fifty_fifty_routes() -> Vec<SplitRoute<AssetId>> {
+	vec![
+		SplitRoute {
+			weight: Permill::from_percent(50),
+			route: vec![HDX_AUSD_TRADE_IN_XYK],
+		},
+		SplitRoute {
+			weight: Permill::from_percent(50),
+			route: vec![HDX_AUSD_TRADE_IN_OMNIPOOL],
+		},
+	]
+}
+
+#[test]
+fn sell_split_should_work_when_amount_is_split_between_routes() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let limit = 20;
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			limit,
+			fifty_fifty_routes()
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, 5, HDX, AUSD), (PoolType::Omnipool, 5, HDX, AUSD)]);
+		expect_events(vec![Event::SplitRouteExecuted {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: amount_to_sell,
+			amount_out: XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT,
+			number_of_routes: 2,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_split_should_give_remainder_to_last_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let routes = vec![
+			SplitRoute {
+				weight: Permill::from_percent(33),
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			SplitRoute {
+				weight: Permill::from_percent(67),
+				route: vec![HDX_AUSD_TRADE_IN_OMNIPOOL],
+			},
+		];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			1,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, 3, HDX, AUSD), (PoolType::Omnipool, 7, HDX, AUSD)]);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_total_amount_out_is_below_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let limit = XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT + 1;
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				amount_to_sell,
+				limit,
+				fifty_fifty_routes()
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_weights_do_not_sum_up_to_one() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			SplitRoute {
+				weight: Permill::from_percent(50),
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			SplitRoute {
+				weight: Permill::from_percent(40),
+				route: vec![HDX_AUSD_TRADE_IN_OMNIPOOL],
+			},
+		];
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 1, routes),
+			Error::<Test>::InvalidSplitWeights
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_route_has_zero_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			SplitRoute {
+				weight: Permill::one(),
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			SplitRoute {
+				weight: Permill::zero(),
+				route: vec![HDX_AUSD_TRADE_IN_OMNIPOOL],
+			},
+		];
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 1, routes),
+			Error::<Test>::InvalidSplitWeights
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_amount_of_route_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			SplitRoute {
+				weight: Permill::from_percent(1),
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			SplitRoute {
+				weight: Permill::from_percent(99),
+				route: vec![HDX_AUSD_TRADE_IN_OMNIPOOL],
+			},
+		];
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 1, routes),
+			Error::<Test>::ZeroSplitAmount
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_amount_of_route_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			SplitRoute {
+				weight: Permill::from_percent(1),
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			SplitRoute {
+				weight: Permill::from_percent(99),
+				route: vec![HDX_AUSD_TRADE_IN_OMNIPOOL],
+			},
+		];
+
+		//Act and Assert
+		assert_noop!(
+			Router::buy_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 1_000, routes),
+			Error::<Test>::ZeroSplitAmount
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_route_does_not_match_asset_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			SplitRoute {
+				weight: Permill::from_percent(50),
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			},
+			SplitRoute {
+				weight: Permill::from_percent(50),
+				route: vec![HDX_MOVR_TRADE_IN_XYK],
+			},
+		];
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 1, routes),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_max_number_of_split_routes_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			SplitRoute {
+				weight: Permill::from_percent(25),
+				route: vec![HDX_AUSD_TRADE_IN_XYK],
+			};
+			4
+		];
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 1, routes),
+			Error::<Test>::MaxSplitRoutesExceeded
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_work_when_amount_is_split_between_routes() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let limit = 10;
+
+		//Act
+		assert_ok!(Router::buy_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_buy,
+			limit,
+			fifty_fifty_routes()
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::XYK, 5, HDX, AUSD), (PoolType::Omnipool, 5, HDX, AUSD)]);
+		expect_events(vec![Event::SplitRouteExecuted {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: XYK_BUY_CALCULATION_RESULT + OMNIPOOL_BUY_CALCULATION_RESULT,
+			amount_out: amount_to_buy,
+			number_of_routes: 2,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_total_amount_in_is_above_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let limit = XYK_BUY_CALCULATION_RESULT + OMNIPOOL_BUY_CALCULATION_RESULT - 1;
+
+		//Act and Assert
+		assert_noop!(
+			Router::buy_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				amount_to_buy,
+				limit,
+				fifty_fifty_routes()
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
@@ -0,0 +1,148 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn create_yield_farm_should_work_when_pool_contains_incentivized_asset() {
+	default_farms().build().execute_with(|| {
+		let global_farm_id = 1;
+		let multiplier = FixedU128::from_float(0.5_f64);
+		let loyalty_curve = Some(LoyaltyCurve::default());
+
+		//NOTE: yield farm for `POOL_ID` was created in the `default_farms()` with id: 2
+		assert_ok!(StableswapMining::stop_yield_farm(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			POOL_ID
+		));
+
+		assert_ok!(StableswapMining::create_yield_farm(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			POOL_ID,
+			multiplier,
+			loyalty_curve.clone()
+		));
+
+		assert_last_event!(crate::Event::YieldFarmCreated {
+			global_farm_id,
+			yield_farm_id: 3,
+			pool_id: POOL_ID,
+			multiplier,
+			loyalty_curve,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_pool_does_not_exist() {
+	default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::create_yield_farm(RuntimeOrigin::signed(GC), 1, 1_000, FixedU128::one(), None),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_pool_does_not_contain_incentivized_asset() {
+	default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::create_yield_farm(RuntimeOrigin::signed(GC), 1, DOT_POOL_ID, FixedU128::one(), None),
+			WarehouseError::MissingIncentivizedAsset
+		);
+	});
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_origin_is_not_farm_owner() {
+	default_farms().build().execute_with(|| {
+		assert_ok!(StableswapMining::stop_yield_farm(RuntimeOrigin::signed(GC), 1, POOL_ID));
+
+		assert_noop!(
+			StableswapMining::create_yield_farm(RuntimeOrigin::signed(ALICE), 1, POOL_ID, FixedU128::one(), None),
+			WarehouseError::Forbidden
+		);
+	});
+}
+
+#[test]
+fn update_yield_farm_should_fail_when_pool_does_not_exist() {
+	default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::update_yield_farm(RuntimeOrigin::signed(GC), 1, 1_000, FixedU128::one()),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn stop_resume_and_terminate_yield_farm_should_work() {
+	default_farms().build().execute_with(|| {
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+		let multiplier = FixedU128::from(2_u128);
+
+		assert_ok!(StableswapMining::stop_yield_farm(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			POOL_ID
+		));
+		assert_last_event!(crate::Event::YieldFarmStopped {
+			global_farm_id,
+			yield_farm_id,
+			pool_id: POOL_ID,
+			who: GC,
+		}
+		.into());
+
+		assert_ok!(StableswapMining::resume_yield_farm(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			yield_farm_id,
+			POOL_ID,
+			multiplier,
+		));
+		assert_last_event!(crate::Event::YieldFarmResumed {
+			global_farm_id,
+			yield_farm_id,
+			pool_id: POOL_ID,
+			who: GC,
+			multiplier,
+		}
+		.into());
+
+		assert_ok!(StableswapMining::stop_yield_farm(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			POOL_ID
+		));
+		assert_ok!(StableswapMining::terminate_yield_farm(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			yield_farm_id,
+			POOL_ID,
+		));
+		assert_last_event!(crate::Event::YieldFarmTerminated {
+			global_farm_id,
+			yield_farm_id,
+			pool_id: POOL_ID,
+			who: GC,
+		}
+		.into());
+	});
+}
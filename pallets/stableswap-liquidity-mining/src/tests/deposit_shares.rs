@@ -0,0 +1,114 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn deposit_shares_should_lock_shares_and_mint_nft_when_yield_farm_exists() {
+	default_farms().build().execute_with(|| {
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+		let deposit_id = 1;
+		let lp1_shares = pool_shares(LP1, POOL_ID);
+		let shares_amount = lp1_shares / 2;
+
+		set_block_number(100);
+
+		//Act
+		assert_ok!(StableswapMining::deposit_shares(
+			RuntimeOrigin::signed(LP1),
+			global_farm_id,
+			yield_farm_id,
+			POOL_ID,
+			shares_amount,
+		));
+
+		//Assert
+		assert_last_event!(crate::Event::SharesDeposited {
+			global_farm_id,
+			yield_farm_id,
+			deposit_id,
+			pool_id: POOL_ID,
+			who: LP1,
+			shares_amount,
+		}
+		.into());
+
+		assert_eq!(pool_shares(LP1, POOL_ID), lp1_shares - shares_amount);
+		assert_eq!(pool_shares(StableswapMining::account_id(), POOL_ID), shares_amount);
+
+		assert_eq!(crate::DepositPoolId::<Test>::get(deposit_id), Some(POOL_ID));
+		assert_eq!(nft_owner(deposit_id), Some(LP1));
+	});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_pool_does_not_exist() {
+	default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::deposit_shares(RuntimeOrigin::signed(LP1), 1, 2, 1_000, ONE),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_account_has_not_enough_shares() {
+	default_farms().build().execute_with(|| {
+		let lp1_shares = pool_shares(LP1, POOL_ID);
+
+		assert_noop!(
+			StableswapMining::deposit_shares(RuntimeOrigin::signed(LP1), 1, 2, POOL_ID, lp1_shares + 1),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_origin_is_not_signed() {
+	default_farms().build().execute_with(|| {
+		assert_noop!(
+			StableswapMining::deposit_shares(RuntimeOrigin::none(), 1, 2, POOL_ID, ONE),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn shares_should_be_valued_in_incentivized_asset() {
+	default_farms().build().execute_with(|| {
+		let share_issuance = Tokens::total_issuance(POOL_ID);
+
+		let value = StableswapMining::get_shares_value(DAI, POOL_ID, share_issuance).unwrap();
+
+		//NOTE: pool is balanced so value of all shares is ~ sum of the pool's reserves.
+		let expected = 3 * 450_000 * ONE;
+		let tolerance = expected / 1_000;
+		assert!(
+			value.abs_diff(expected) <= tolerance,
+			"value {value} is not close to {expected}"
+		);
+	});
+}
+
+#[test]
+fn shares_valuation_should_fail_when_asset_is_not_in_pool() {
+	default_farms().build().execute_with(|| {
+		assert_eq!(
+			StableswapMining::get_shares_value(DOT, POOL_ID, ONE),
+			Err(Error::<Test>::SharePriceNotAvailable.into())
+		);
+	});
+}
@@ -0,0 +1,636 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::type_complexity)]
+#![allow(clippy::too_many_arguments)]
+
+use crate::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::num::NonZeroU16;
+
+use crate as stableswap_liquidity_mining;
+
+use core::ops::RangeInclusive;
+use frame_support::traits::{Contains, Everything, GenesisBuild};
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use orml_traits::parameter_type_with_key;
+use orml_traits::GetByKey;
+use pallet_liquidity_mining as warehouse_liquidity_mining;
+use pallet_stableswap::types::AssetAmount;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup, One},
+	Permill,
+};
+
+use warehouse_liquidity_mining::{DefaultPriceAdjustment, Instance1};
+
+use hydradx_traits::{pools::DustRemovalAccountWhitelist, AccountIdFor, AssetKind, InspectRegistry, Registry};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+pub type AssetId = u32;
+//NTF types
+pub type ItemId = u128;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+pub const USDT: AssetId = 3;
+pub const USDC: AssetId = 4;
+pub const DOT: AssetId = 5;
+
+pub const POOL_ID: AssetId = 100;
+pub const DOT_POOL_ID: AssetId = 101;
+
+pub const LP1: AccountId = 1;
+pub const LP2: AccountId = 2;
+
+pub const ALICE: AccountId = 4;
+pub const BOB: AccountId = 5;
+pub const GC: AccountId = 7;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const LM_COLLECTION_ID: CollectionId = 1;
+
+thread_local! {
+	pub static NFTS: RefCell<HashMap<(CollectionId, ItemId), AccountId>> = RefCell::new(HashMap::default());
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u8>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+}
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		Stableswap: pallet_stableswap,
+		WarehouseLM: warehouse_liquidity_mining::<Instance1>,
+		StableswapMining: stableswap_liquidity_mining,
+	}
+);
+
+parameter_types! {
+	pub static MockBlockNumberProvider: u64 = 0;
+}
+
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = BlockNumber;
+
+	fn current_block_number() -> Self::BlockNumber {
+		System::block_number()
+	}
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const LMPalletId: PalletId = PalletId(*b"TEST_slm");
+	pub const LMCollectionId: CollectionId = LM_COLLECTION_ID;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Tokens;
+	type CreateOrigin = EnsureRoot<AccountId>;
+	type PalletId = LMPalletId;
+	type NFTCollectionId = LMCollectionId;
+	type NFTHandler = DummyNFT;
+	type LiquidityMiningHandler = WarehouseLM;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const WarehouseLMPalletId: PalletId = PalletId(*b"TEST_lm_");
+	pub const MinTotalFarmRewards: Balance = 1_000_000 * ONE;
+	pub const MinPlannedYieldingPeriods: BlockNumber  = 100;
+	#[derive(PartialEq, Eq)]
+	pub const MaxEntriesPerDeposit: u32 = 5;
+	pub const MaxYieldFarmsPerGlobalFarm: u32 = 10;
+}
+
+impl warehouse_liquidity_mining::Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type MultiCurrency = Tokens;
+	type PalletId = WarehouseLMPalletId;
+	type MinTotalFarmRewards = MinTotalFarmRewards;
+	type MinPlannedYieldingPeriods = MinPlannedYieldingPeriods;
+	type BlockNumberProvider = MockBlockNumberProvider;
+	type AmmPoolId = AssetId;
+	type MaxFarmEntriesPerDeposit = MaxEntriesPerDeposit;
+	type MaxYieldFarmsPerGlobalFarm = MaxYieldFarmsPerGlobalFarm;
+	type AssetRegistry = DummyRegistry;
+	type NonDustableWhitelistHandler = Whitelist;
+	type PriceAdjustment = DefaultPriceAdjustment;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+parameter_type_with_key! {
+	pub StableswapDynamicFee: |_pool_id: AssetId| -> Permill {
+		Permill::zero()
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Everything;
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const MinimumLiquidity: Balance = 1000;
+	pub const MinimumTradingLimit: Balance = 1000;
+	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+}
+
+impl pallet_stableswap::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type ShareAccountId = AccountIdConstructor;
+	type AssetInspection = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type MinPoolLiquidity = MinimumLiquidity;
+	type AmplificationRange = AmplificationRange;
+	type MinTradingLimit = MinimumTradingLimit;
+	type WeightInfo = ();
+	type BlockNumberProvider = System;
+	type DustAccountHandler = Whitelist;
+	type Hooks = ();
+	type DynamicFee = StableswapDynamicFee;
+	type QuoteDynamicFee = StableswapDynamicFee;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<(AssetId, Vec<AssetId>)>,
+	pool_liquidity: Vec<(AccountId, AssetId, Vec<AssetAmount<AssetId>>)>,
+	lm_global_farms: Vec<(
+		Balance,
+		PeriodOf<Test>,
+		BlockNumber,
+		AssetId,
+		AssetId,
+		AccountId,
+		Perquintill,
+		Balance,
+		FixedU128,
+	)>,
+	lm_yield_farms: Vec<(AccountId, GlobalFarmId, AssetId, FarmMultiplier, Option<LoyaltyCurve>)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		NFTS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		DUSTER_WHITELIST.with(|v| {
+			v.borrow_mut().clear();
+		});
+
+		Self {
+			endowed_accounts: vec![],
+			pools: vec![],
+			pool_liquidity: vec![],
+			lm_global_farms: vec![],
+			lm_yield_farms: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_pool(mut self, pool_id: AssetId, assets: Vec<AssetId>) -> Self {
+		self.pools.push((pool_id, assets));
+		self
+	}
+
+	pub fn with_liquidity(mut self, who: AccountId, pool_id: AssetId, assets: Vec<AssetAmount<AssetId>>) -> Self {
+		self.pool_liquidity.push((who, pool_id, assets));
+		self
+	}
+
+	pub fn with_global_farm(
+		mut self,
+		total_rewards: Balance,
+		planned_yielding_periods: PeriodOf<Test>,
+		blocks_per_period: BlockNumber,
+		incentivized_asset: AssetId,
+		reward_currency: AssetId,
+		owner: AccountId,
+		yield_per_period: Perquintill,
+		min_deposit: Balance,
+		price_adjustment: FixedU128,
+	) -> Self {
+		self.lm_global_farms.push((
+			total_rewards,
+			planned_yielding_periods,
+			blocks_per_period,
+			incentivized_asset,
+			reward_currency,
+			owner,
+			yield_per_period,
+			min_deposit,
+			price_adjustment,
+		));
+		self
+	}
+
+	pub fn with_yield_farm(
+		mut self,
+		owner: AccountId,
+		id: GlobalFarmId,
+		pool_id: AssetId,
+		multiplier: FarmMultiplier,
+		loyalty_curve: Option<LoyaltyCurve>,
+	) -> Self {
+		self.lm_yield_farms
+			.push((owner, id, pool_id, multiplier, loyalty_curve));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			for asset in [HDX, DAI, USDT, USDC, DOT] {
+				v.borrow_mut().insert(asset, 12);
+			}
+			for (pool_id, _) in self.pools.iter() {
+				v.borrow_mut().insert(*pool_id, 18);
+			}
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self
+				.endowed_accounts
+				.iter()
+				.flat_map(|(x, asset, amount)| vec![(*x, *asset, *amount)])
+				.collect(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		<stableswap_liquidity_mining::GenesisConfig as GenesisBuild<Test>>::assimilate_storage(
+			&stableswap_liquidity_mining::GenesisConfig::default(),
+			&mut t,
+		)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			set_block_number(1);
+
+			for (pool_id, assets) in self.pools {
+				assert_ok!(Stableswap::create_pool(
+					RuntimeOrigin::root(),
+					pool_id,
+					assets,
+					100,
+					Permill::zero(),
+				));
+			}
+
+			for (who, pool_id, assets) in self.pool_liquidity {
+				assert_ok!(Stableswap::add_liquidity(RuntimeOrigin::signed(who), pool_id, assets));
+			}
+
+			for gf in self.lm_global_farms {
+				assert_ok!(StableswapMining::create_global_farm(
+					RuntimeOrigin::root(),
+					gf.0,
+					gf.1,
+					gf.2,
+					gf.3,
+					gf.4,
+					gf.5,
+					gf.6,
+					gf.7,
+					gf.8,
+				));
+			}
+
+			for yf in self.lm_yield_farms {
+				assert_ok!(StableswapMining::create_yield_farm(
+					RuntimeOrigin::signed(yf.0),
+					yf.1,
+					yf.2,
+					yf.3,
+					yf.4
+				));
+			}
+		});
+
+		r
+	}
+}
+
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate, Transfer};
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u128>> Inspect<AccountId> for DummyNFT {
+	type ItemId = ItemId;
+	type CollectionId = CollectionId;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountId> {
+		let mut owner: Option<AccountId> = None;
+
+		NFTS.with(|v| {
+			if let Some(o) = v.borrow().get(&(*collection, *item)) {
+				owner = Some((*o).into());
+			}
+		});
+		owner
+	}
+}
+
+impl<AccountId: From<u128>> Create<AccountId> for DummyNFT {
+	fn create_collection(_collection: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u128> + Into<u128> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(collection: &Self::CollectionId, item: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.insert((*collection, *item), (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.remove(&(*collection, *item));
+		});
+		Ok(())
+	}
+}
+
+impl Transfer<AccountId> for DummyNFT {
+	fn transfer(collection: &Self::CollectionId, item: &Self::ItemId, destination: &AccountId) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			let key = (*collection, *item);
+
+			if !m.contains_key(&key) {
+				return Err(sp_runtime::DispatchError::Other("NFT not found"));
+			}
+
+			m.insert(key, *destination);
+
+			Ok(())
+		})
+	}
+}
+
+pub struct DummyRegistry;
+
+impl InspectRegistry<AssetId> for DummyRegistry {
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn decimals(asset_id: AssetId) -> Option<u8> {
+		REGISTERED_ASSETS.with(|v| v.borrow().get(&asset_id).copied())
+	}
+
+	fn asset_name(_asset_id: AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_asset_id: AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+}
+
+impl Registry<AssetId, Vec<u8>, Balance, DispatchError> for DummyRegistry {
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn retrieve_asset(_name: &Vec<u8>) -> Result<AssetId, DispatchError> {
+		unimplemented!()
+	}
+
+	fn retrieve_asset_type(_asset_id: AssetId) -> Result<AssetKind, DispatchError> {
+		unimplemented!()
+	}
+
+	fn create_asset(_name: &Vec<u8>, _existential_deposit: Balance) -> Result<AssetId, DispatchError> {
+		unimplemented!()
+	}
+}
+
+impl GetByKey<AssetId, Balance> for DummyRegistry {
+	fn get(_key: &AssetId) -> Balance {
+		1_000_u128
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_stableswap::types::BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, decimals);
+		});
+
+		Ok(())
+	}
+}
+
+pub struct AccountIdConstructor;
+
+impl AccountIdFor<AssetId> for AccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &AssetId, _identifier: Option<&[u8]>) -> Self::AccountId {
+		(*asset as u128) * 1_000
+	}
+
+	fn name(asset: &AssetId, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf: Vec<u8> = if let Some(ident) = identifier {
+			ident.to_vec()
+		} else {
+			vec![]
+		};
+		buf.extend_from_slice(&(asset).to_le_bytes());
+
+		buf
+	}
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		if Whitelist::contains(account) {
+			return Err(sp_runtime::DispatchError::Other("Account is already in the whitelist"));
+		}
+
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| {
+			let mut v = v.borrow_mut();
+
+			let idx = v.iter().position(|x| *x == *account).unwrap();
+			v.remove(idx);
+
+			Ok(())
+		})
+	}
+}
+
+pub fn set_block_number(n: u64) {
+	System::set_block_number(n);
+}
+
+pub fn pool_shares(who: AccountId, pool_id: AssetId) -> Balance {
+	Tokens::free_balance(pool_id, &who)
+}
+
+pub fn nft_owner(deposit_id: DepositId) -> Option<AccountId> {
+	NFTS.with(|v| v.borrow().get(&(LM_COLLECTION_ID, deposit_id)).copied())
+}
+
+/// Returns default environment used by most of the tests: DAI/USDT/USDC stableswap pool and
+/// DOT/DAI pool, `LP1` and `LP2` provide liquidity to the `POOL_ID` and `GC` owns global farm 1
+/// incentivizing `DAI` with yield farm for `POOL_ID`.
+pub fn default_farms() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, DAI, 1_000_000 * ONE),
+			(LP1, USDT, 1_000_000 * ONE),
+			(LP1, USDC, 1_000_000 * ONE),
+			(LP2, DAI, 1_000_000 * ONE),
+			(LP2, USDT, 1_000_000 * ONE),
+			(LP2, USDC, 1_000_000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(ALICE, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL_ID, vec![DAI, USDT, USDC])
+		.with_pool(DOT_POOL_ID, vec![DOT, USDT])
+		.with_liquidity(
+			LP1,
+			POOL_ID,
+			vec![
+				AssetAmount::new(DAI, 100_000 * ONE),
+				AssetAmount::new(USDT, 100_000 * ONE),
+				AssetAmount::new(USDC, 100_000 * ONE),
+			],
+		)
+		.with_liquidity(
+			LP2,
+			POOL_ID,
+			vec![
+				AssetAmount::new(DAI, 50_000 * ONE),
+				AssetAmount::new(USDT, 50_000 * ONE),
+				AssetAmount::new(USDC, 50_000 * ONE),
+			],
+		)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			DAI,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, POOL_ID, FixedU128::one(), None)
+}
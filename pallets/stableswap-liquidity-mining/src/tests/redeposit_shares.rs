@@ -0,0 +1,103 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn redeposit_shares_should_work_when_deposit_exists() {
+	default_farms()
+		.with_global_farm(
+			//id: 3
+			60_000_000 * ONE,
+			2_628_000,
+			1,
+			USDT,
+			HDX,
+			ALICE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(ALICE, 3, POOL_ID, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let deposit_id = 1;
+			let shares_amount = pool_shares(LP1, POOL_ID);
+
+			assert_ok!(StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(LP1),
+				1,
+				2,
+				POOL_ID,
+				shares_amount,
+			));
+
+			//Act
+			assert_ok!(StableswapMining::redeposit_shares(
+				RuntimeOrigin::signed(LP1),
+				3,
+				4,
+				deposit_id,
+			));
+
+			//Assert
+			assert_last_event!(crate::Event::SharesRedeposited {
+				global_farm_id: 3,
+				yield_farm_id: 4,
+				deposit_id,
+				pool_id: POOL_ID,
+				who: LP1,
+				shares_amount,
+			}
+			.into());
+
+			//NOTE: shares are locked only once.
+			assert_eq!(pool_shares(StableswapMining::account_id(), POOL_ID), shares_amount);
+			assert_eq!(pool_shares(LP1, POOL_ID), 0);
+		});
+}
+
+#[test]
+fn redeposit_shares_should_fail_when_origin_is_not_deposit_owner() {
+	default_farms()
+		.with_global_farm(
+			//id: 3
+			60_000_000 * ONE,
+			2_628_000,
+			1,
+			USDT,
+			HDX,
+			ALICE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(ALICE, 3, POOL_ID, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			assert_ok!(StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(LP1),
+				1,
+				2,
+				POOL_ID,
+				pool_shares(LP1, POOL_ID),
+			));
+
+			assert_noop!(
+				StableswapMining::redeposit_shares(RuntimeOrigin::signed(LP2), 3, 4, 1),
+				Error::<Test>::Forbidden
+			);
+		});
+}
@@ -35,7 +35,7 @@
 //!
 //! LP is given certain amount of shares by minting a pool's share token.
 //!
-//! When LP decides to withdraw liquidity, it receives selected asset.
+//! When LP decides to withdraw liquidity, it receives selected asset or all pool assets proportionally.
 //!
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -46,8 +46,8 @@ use frame_support::pallet_prelude::{DispatchResult, Get};
 use frame_support::{ensure, require_transactional, transactional};
 use hydradx_traits::{registry::InspectRegistry, AccountIdFor};
 pub use pallet::*;
-use sp_runtime::traits::{BlockNumberProvider, Zero};
-use sp_runtime::{ArithmeticError, DispatchError, Permill, SaturatedConversion};
+use sp_runtime::traits::{BlockNumberProvider, CheckedDiv, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, SaturatedConversion};
 use sp_std::num::NonZeroU16;
 use sp_std::prelude::*;
 use sp_std::vec;
@@ -61,7 +61,7 @@ pub use trade_execution::*;
 use crate::types::{AssetAmount, Balance, PoolInfo, PoolState, StableswapHooks, Tradability};
 use hydra_dx_math::stableswap::types::AssetReserve;
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
-use orml_traits::MultiCurrency;
+use orml_traits::{GetByKey, MultiCurrency};
 use sp_std::collections::btree_map::BTreeMap;
 use weights::WeightInfo;
 
@@ -83,6 +83,10 @@ pub const MAX_ASSETS_IN_POOL: u32 = 5;
 const D_ITERATIONS: u8 = hydra_dx_math::stableswap::MAX_D_ITERATIONS;
 const Y_ITERATIONS: u8 = hydra_dx_math::stableswap::MAX_Y_ITERATIONS;
 
+/// Function providing trade fee of a pool.
+type TradeFeeOf<T> =
+	fn(<T as Config>::AssetId, &PoolInfo<<T as Config>::AssetId, <T as frame_system::Config>::BlockNumber>) -> Permill;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -137,6 +141,12 @@ pub mod pallet {
 		/// Hooks are actions executed on add_liquidity, sell or buy.
 		type Hooks: StableswapHooks<Self::AssetId>;
 
+		/// Dynamic trade fee of a pool. Pool fee is used as a minimum.
+		type DynamicFee: GetByKey<Self::AssetId, Permill>;
+
+		/// Dynamic trade fee of a pool used to quote trades. It must not modify the storage.
+		type QuoteDynamicFee: GetByKey<Self::AssetId, Permill>;
+
 		/// Minimum pool liquidity
 		#[pallet::constant]
 		type MinPoolLiquidity: Get<Balance>;
@@ -777,7 +787,8 @@ pub mod pallet {
 			let amplification = Self::get_amplification(&pool);
 			let initial_reserves = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
 
-			let (amount_out, fee_amount) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)?;
+			let (amount_out, fee_amount) =
+				Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in, Self::get_trade_fee)?;
 			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
 
 			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
@@ -872,7 +883,8 @@ pub mod pallet {
 			let amplification = Self::get_amplification(&pool);
 			let initial_reserves = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
 
-			let (amount_in, fee_amount) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)?;
+			let (amount_in, fee_amount) =
+				Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out, Self::get_trade_fee)?;
 
 			let pool_account = Self::pool_account(pool_id);
 
@@ -959,6 +971,129 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Remove liquidity from selected pool proportionally.
+		///
+		/// Share amount is burned and LP receives corresponding amounts of all pool assets,
+		/// proportional to the pool reserves. No fee is applied.
+		///
+		/// All pool assets must allow removing liquidity.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: Pool Id
+		/// - 'share_amount': amount of shares to withdraw
+		/// - 'min_amounts_out': minimum amounts of pool assets to receive. Assets not listed have no minimum.
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			share_amount: Balance,
+			min_amounts_out: Vec<AssetAmount<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(share_amount > Balance::zero(), Error::<T>::InvalidAssetAmount);
+
+			let current_share_balance = T::Currency::free_balance(pool_id, &who);
+
+			ensure!(current_share_balance >= share_amount, Error::<T>::InsufficientShares);
+
+			ensure!(
+				current_share_balance == share_amount
+					|| current_share_balance.saturating_sub(share_amount) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientShareBalance
+			);
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(
+				min_amounts_out.len() <= pool.assets.len(),
+				Error::<T>::MaxAssetsExceeded
+			);
+
+			for asset_id in pool.assets.iter() {
+				ensure!(
+					Self::is_asset_allowed(pool_id, *asset_id, Tradability::REMOVE_LIQUIDITY),
+					Error::<T>::NotAllowed
+				);
+			}
+
+			let mut min_amounts = BTreeMap::<T::AssetId, Balance>::new();
+			for asset in min_amounts_out.iter() {
+				ensure!(pool.find_asset(asset.asset_id).is_some(), Error::<T>::AssetNotInPool);
+				if min_amounts.insert(asset.asset_id, asset.amount).is_some() {
+					return Err(Error::<T>::IncorrectAssets.into());
+				}
+			}
+
+			let pool_account = Self::pool_account(pool_id);
+			let balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+			let share_issuance = T::Currency::total_issuance(pool_id);
+
+			ensure!(
+				share_issuance == share_amount
+					|| share_issuance.saturating_sub(share_amount) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidityRemaining
+			);
+
+			let amounts = hydra_dx_math::stableswap::calculate_liquidity_out(&balances, share_amount, share_issuance)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			for (asset_id, amount) in pool.assets.iter().zip(amounts.iter()) {
+				let min_amount_out = min_amounts.get(asset_id).copied().unwrap_or_default();
+				ensure!(*amount >= min_amount_out, Error::<T>::MinimumAmountNotReached);
+			}
+
+			T::Currency::withdraw(pool_id, &who, share_amount)?;
+			for (asset_id, amount) in pool.assets.iter().zip(amounts.iter()) {
+				T::Currency::transfer(*asset_id, &pool_account, &who, *amount)?;
+			}
+
+			let amplification = Self::get_amplification(&pool);
+			let updated_share_issuance = T::Currency::total_issuance(pool_id);
+			let updated_balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+
+			let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
+				&updated_balances,
+				amplification,
+				updated_share_issuance,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			let removed: Vec<AssetAmount<T::AssetId>> = pool
+				.assets
+				.iter()
+				.zip(amounts.iter())
+				.map(|(asset_id, amount)| AssetAmount::new(*asset_id, *amount))
+				.collect();
+
+			let state = PoolState {
+				assets: pool.assets.into_inner(),
+				before: balances.into_iter().map(|v| v.into()).collect(),
+				after: updated_balances.into_iter().map(|v| v.into()).collect(),
+				delta: amounts,
+				issuance_before: share_issuance,
+				issuance_after: updated_share_issuance,
+				share_prices,
+			};
+
+			T::Hooks::on_liquidity_changed(pool_id, state)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				pool_id,
+				who,
+				shares: share_amount,
+				amounts: removed,
+				fee: Balance::zero(),
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -966,11 +1101,13 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Calculates amount out and fee amount of a sell. `trade_fee` provides trade fee of the pool.
 	fn calculate_out_amount(
 		pool_id: T::AssetId,
 		asset_in: T::AssetId,
 		asset_out: T::AssetId,
 		amount_in: Balance,
+		trade_fee: TradeFeeOf<T>,
 	) -> Result<(Balance, Balance), DispatchError> {
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 
@@ -990,16 +1127,18 @@ impl<T: Config> Pallet<T> {
 			index_out,
 			amount_in,
 			amplification,
-			pool.fee,
+			trade_fee(pool_id, &pool),
 		)
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
 
+	/// Calculates amount in and fee amount of a buy. `trade_fee` provides trade fee of the pool.
 	fn calculate_in_amount(
 		pool_id: T::AssetId,
 		asset_in: T::AssetId,
 		asset_out: T::AssetId,
 		amount_out: Balance,
+		trade_fee: TradeFeeOf<T>,
 	) -> Result<(Balance, Balance), DispatchError> {
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 
@@ -1022,13 +1161,16 @@ impl<T: Config> Pallet<T> {
 			index_out,
 			amount_out,
 			amplification,
-			pool.fee,
+			trade_fee(pool_id, &pool),
 		)
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
 
+	/// Create new pool without any liquidity.
+	///
+	/// Used by `create_pool` and by pallets which create stableswap pools on behalf of the protocol.
 	#[require_transactional]
-	fn do_create_pool(
+	pub fn do_create_pool(
 		share_asset: T::AssetId,
 		assets: &[T::AssetId],
 		amplification: NonZeroU16,
@@ -1253,8 +1395,9 @@ impl<T: Config> Pallet<T> {
 		AssetTradability::<T>::get(pool_id, asset_id).contains(operation)
 	}
 
+	/// Account which holds reserves of given pool.
 	#[inline]
-	fn pool_account(pool_id: T::AssetId) -> T::AccountId {
+	pub fn pool_account(pool_id: T::AssetId) -> T::AccountId {
 		T::ShareAccountId::from_assets(&pool_id, Some(POOL_IDENTIFIER))
 	}
 
@@ -1269,6 +1412,18 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
+	/// Trade fee of a pool - greater of pool fee and dynamic fee.
+	#[inline]
+	pub(crate) fn get_trade_fee(pool_id: T::AssetId, pool: &PoolInfo<T::AssetId, T::BlockNumber>) -> Permill {
+		pool.fee.max(T::DynamicFee::get(&pool_id))
+	}
+
+	/// Trade fee of a pool used to quote trades. Unlike `get_trade_fee`, dynamic fee is not updated.
+	#[inline]
+	pub(crate) fn get_quote_fee(pool_id: T::AssetId, pool: &PoolInfo<T::AssetId, T::BlockNumber>) -> Permill {
+		pool.fee.max(T::QuoteDynamicFee::get(&pool_id))
+	}
+
 	#[inline]
 	pub(crate) fn retrieve_decimals(asset_id: T::AssetId) -> Option<u8> {
 		T::AssetInspection::decimals(asset_id)
@@ -1337,4 +1492,60 @@ impl<T: Config> Pallet<T> {
 
 		Ok(share_amount)
 	}
+
+	/// Calculates the spot price of `asset_a` denominated in `asset_b` in given pool.
+	///
+	/// The price is the amount of `asset_b` per one unit of `asset_a`, both in their own decimals.
+	/// The stableswap math works with reserves normalized to 18 decimals, so the price is denormalized
+	/// by `10^(decimals_b - decimals_a)`.
+	///
+	/// Returns `None` if the pool does not exist, any of the assets is not in the pool or the calculation fails.
+	pub fn calculate_spot_price(pool_id: T::AssetId, asset_a: T::AssetId, asset_b: T::AssetId) -> Option<FixedU128> {
+		let pool = Pools::<T>::get(pool_id)?;
+		let pool_account = Self::pool_account(pool_id);
+		let balances = pool.balances::<T>(&pool_account)?;
+		let amplification = Self::get_amplification(&pool);
+
+		let asset_a_idx = pool.find_asset(asset_a)?;
+		let asset_b_idx = pool.find_asset(asset_b)?;
+
+		let d = hydra_dx_math::stableswap::calculate_d::<D_ITERATIONS>(&balances, amplification)?;
+
+		let (a_num, a_denom) =
+			hydra_dx_math::stableswap::calculate_spot_price(&balances, amplification, d, asset_a_idx)?;
+		let (b_num, b_denom) =
+			hydra_dx_math::stableswap::calculate_spot_price(&balances, amplification, d, asset_b_idx)?;
+
+		let price_a = FixedU128::checked_from_rational(a_num, a_denom)?;
+		let price_b = FixedU128::checked_from_rational(b_num, b_denom)?;
+
+		let decimals_adjustment = FixedU128::checked_from_rational(
+			10u128.checked_pow(balances[asset_b_idx].decimals as u32)?,
+			10u128.checked_pow(balances[asset_a_idx].decimals as u32)?,
+		)?;
+
+		price_a.checked_div(&price_b)?.checked_mul(&decimals_adjustment)
+	}
+
+	/// Calculates the price of the pool's share token denominated in `asset_id` of the pool.
+	///
+	/// Returns `(n, d)` - amount of `asset_id` per one unit of share token.
+	/// Returns `None` if the pool does not exist, the asset is not in the pool or the calculation fails.
+	pub fn calculate_share_price(pool_id: T::AssetId, asset_id: T::AssetId) -> Option<(Balance, Balance)> {
+		let pool = Pools::<T>::get(pool_id)?;
+		let pool_account = Self::pool_account(pool_id);
+		let balances = pool.balances::<T>(&pool_account)?;
+		let amplification = Self::get_amplification(&pool);
+		let share_issuance = T::Currency::total_issuance(pool_id);
+
+		let asset_idx = pool.find_asset(asset_id)?;
+
+		hydra_dx_math::stableswap::calculate_share_price::<D_ITERATIONS>(
+			&balances,
+			amplification,
+			share_issuance,
+			asset_idx,
+			None,
+		)
+	}
 }
@@ -37,13 +37,13 @@ use frame_support::{
 	traits::{ConstU32, ConstU64},
 };
 use frame_system::EnsureRoot;
-use orml_traits::parameter_type_with_key;
 pub use orml_traits::MultiCurrency;
+use orml_traits::{parameter_type_with_key, GetByKey};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
-	DispatchError,
+	DispatchError, Permill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -74,6 +74,7 @@ thread_local! {
 	pub static POOL_IDS: RefCell<Vec<AssetId>> = RefCell::new(Vec::new());
 	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
 	pub static LAST_LIQUDITY_CHANGE_HOOK: RefCell<Option<(AssetId, PoolState<AssetId>)>> = RefCell::new(None);
+	pub static DYNAMIC_FEE: RefCell<Permill> = RefCell::new(Permill::zero());
 	pub static LAST_TRADE_HOOK: RefCell<Option<(AssetId, AssetId, AssetId, PoolState<AssetId>)>> = RefCell::new(None);
 }
 
@@ -186,6 +187,8 @@ impl Config for Test {
 	type BlockNumberProvider = System;
 	type DustAccountHandler = Whitelist;
 	type Hooks = DummyHookAdapter;
+	type DynamicFee = DynamicFee;
+	type QuoteDynamicFee = DynamicFee;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = DummyRegistry;
 }
@@ -215,6 +218,9 @@ impl Default for ExtBuilder {
 		POOL_IDS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		DYNAMIC_FEE.with(|v| {
+			*v.borrow_mut() = Permill::zero();
+		});
 		Self {
 			endowed_accounts: vec![],
 			registered_assets: vec![],
@@ -229,6 +235,13 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_dynamic_fee(self, fee: Permill) -> Self {
+		DYNAMIC_FEE.with(|v| {
+			*v.borrow_mut() = fee;
+		});
+		self
+	}
+
 	pub fn with_registered_asset(mut self, name: Vec<u8>, asset: AssetId, decimals: u8) -> Self {
 		self.registered_assets.push((name, asset, decimals));
 		self
@@ -380,6 +393,14 @@ pub(crate) fn get_pool_id_at(idx: usize) -> AssetId {
 	POOL_IDS.with(|v| v.borrow()[idx])
 }
 
+pub struct DynamicFee;
+
+impl GetByKey<AssetId, Permill> for DynamicFee {
+	fn get(_pool_id: &AssetId) -> Permill {
+		DYNAMIC_FEE.with(|v| *v.borrow())
+	}
+}
+
 pub struct DummyHookAdapter;
 
 impl StableswapHooks<AssetId> for DummyHookAdapter {
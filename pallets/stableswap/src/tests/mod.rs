@@ -10,6 +10,7 @@ mod invariants;
 pub(crate) mod mock;
 mod price;
 mod remove_liquidity;
+mod remove_liquidity_proportionally;
 mod trades;
 mod update_pool;
 
@@ -350,3 +350,58 @@ fn test_share_price_case() {
 			assert!(share_price_initial >= exec_price);
 		});
 }
+
+#[test]
+fn calculate_spot_price_should_return_price_of_asset_denominated_in_another_asset() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, asset_a, 52425995641788588073263117),
+			(ALICE, asset_b, 52033213790329),
+			(ALICE, asset_c, 119135337044269),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 18)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 6)
+		.with_registered_asset("three".as_bytes().to_vec(), asset_c, 6)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b, asset_c].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(2000).unwrap(),
+				final_amplification: NonZeroU16::new(2000).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::zero(),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(asset_a, 52425995641788588073263117),
+					AssetAmount::new(asset_b, 52033213790329),
+					AssetAmount::new(asset_c, 119135337044269),
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			// asset_b has 6 decimals and asset_a has 18 decimals
+			assert_eq!(
+				Stableswap::calculate_spot_price(pool_id, asset_b, asset_a),
+				Some(asset_spot_price(pool_id, asset_b) * FixedU128::from(1_000_000_000_000))
+			);
+			assert_eq!(
+				Stableswap::calculate_spot_price(pool_id, asset_b, asset_c),
+				asset_spot_price(pool_id, asset_b).checked_div(&asset_spot_price(pool_id, asset_c))
+			);
+			assert_eq!(
+				Stableswap::calculate_spot_price(pool_id, asset_a, asset_a),
+				Some(FixedU128::from(1))
+			);
+			assert_eq!(Stableswap::calculate_spot_price(pool_id, asset_a, 4), None);
+		});
+}
@@ -0,0 +1,272 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, PoolInfo, Tradability};
+use crate::{assert_balance, Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Permill;
+use std::num::NonZeroU16;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const ASSET_C: AssetId = 3;
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, ASSET_A, 100 * ONE),
+			(ALICE, ASSET_B, 200 * ONE),
+			(ALICE, ASSET_C, 300 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), ASSET_C, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B, ASSET_C].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(1),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, 100 * ONE),
+					AssetAmount::new(ASSET_B, 200 * ONE),
+					AssetAmount::new(ASSET_C, 300 * ONE),
+				],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn remove_liquidity_should_withdraw_all_assets_proportionally() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = get_pool_id_at(0);
+		let pool_account = pool_account(pool_id);
+
+		let issuance = Tokens::total_issuance(pool_id);
+		let shares = issuance / 4;
+
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			vec![],
+		));
+
+		let expected_a = 100 * ONE * shares / issuance;
+		let expected_b = 200 * ONE * shares / issuance;
+		let expected_c = 300 * ONE * shares / issuance;
+
+		assert_balance!(ALICE, ASSET_A, expected_a);
+		assert_balance!(ALICE, ASSET_B, expected_b);
+		assert_balance!(ALICE, ASSET_C, expected_c);
+		assert_balance!(ALICE, pool_id, issuance - shares);
+		assert_balance!(pool_account, ASSET_A, 100 * ONE - expected_a);
+		assert_balance!(pool_account, ASSET_B, 200 * ONE - expected_b);
+		assert_balance!(pool_account, ASSET_C, 300 * ONE - expected_c);
+		assert_eq!(Tokens::total_issuance(pool_id), issuance - shares);
+
+		System::assert_last_event(
+			Event::LiquidityRemoved {
+				pool_id,
+				who: ALICE,
+				shares,
+				amounts: vec![
+					AssetAmount::new(ASSET_A, expected_a),
+					AssetAmount::new(ASSET_B, expected_b),
+					AssetAmount::new(ASSET_C, expected_c),
+				],
+				fee: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_provide_correct_values_in_the_hook() {
+	new_test_ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let issuance = Tokens::total_issuance(pool_id);
+		let shares = issuance / 4;
+
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			vec![],
+		));
+
+		let expected = vec![
+			100 * ONE * shares / issuance,
+			200 * ONE * shares / issuance,
+			300 * ONE * shares / issuance,
+		];
+
+		let (p, state) = last_liquidity_changed_hook_state().unwrap();
+		assert_eq!(p, pool_id);
+		assert_eq!(state.assets, vec![ASSET_A, ASSET_B, ASSET_C]);
+		assert_eq!(state.before, vec![100 * ONE, 200 * ONE, 300 * ONE]);
+		assert_eq!(
+			state.after,
+			vec![
+				100 * ONE - expected[0],
+				200 * ONE - expected[1],
+				300 * ONE - expected[2]
+			]
+		);
+		assert_eq!(state.delta, expected);
+		assert_eq!(state.issuance_before, issuance);
+		assert_eq!(state.issuance_after, issuance - shares);
+		assert_eq!(state.share_prices.len(), 3);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_work_when_min_amounts_are_reached() {
+	new_test_ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let issuance = Tokens::total_issuance(pool_id);
+		let shares = issuance / 4;
+
+		let expected_a = 100 * ONE * shares / issuance;
+		let expected_c = 300 * ONE * shares / issuance;
+
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			vec![
+				AssetAmount::new(ASSET_C, expected_c),
+				AssetAmount::new(ASSET_A, expected_a),
+			],
+		));
+
+		assert_balance!(ALICE, ASSET_A, expected_a);
+		assert_balance!(ALICE, ASSET_C, expected_c);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_min_amount_is_not_reached() {
+	new_test_ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let issuance = Tokens::total_issuance(pool_id);
+		let shares = issuance / 4;
+
+		let expected_b = 200 * ONE * shares / issuance;
+
+		assert_noop!(
+			Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				shares,
+				vec![AssetAmount::new(ASSET_B, expected_b + 1)],
+			),
+			Error::<Test>::MinimumAmountNotReached
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_min_amount_asset_is_not_in_pool() {
+	new_test_ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::total_issuance(pool_id) / 4;
+
+		assert_noop!(
+			Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				shares,
+				vec![AssetAmount::new(HDX, 1)],
+			),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_min_amount_asset_is_duplicated() {
+	new_test_ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::total_issuance(pool_id) / 4;
+
+		assert_noop!(
+			Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				shares,
+				vec![AssetAmount::new(ASSET_A, 1), AssetAmount::new(ASSET_A, 2)],
+			),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_asset_is_not_allowed_to_remove_liquidity() {
+	new_test_ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::total_issuance(pool_id) / 4;
+
+		assert_ok!(Stableswap::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			pool_id,
+			ASSET_B,
+			Tradability::SELL | Tradability::BUY | Tradability::ADD_LIQUIDITY,
+		));
+
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, shares, vec![]),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_shares_are_insufficient() {
+	new_test_ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let issuance = Tokens::total_issuance(pool_id);
+
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(BOB), pool_id, issuance / 4, vec![]),
+			Error::<Test>::InsufficientShares
+		);
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, issuance + 1, vec![]),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_share_amount_is_zero() {
+	new_test_ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, 0, vec![]),
+			Error::<Test>::InvalidAssetAmount
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_remaining_shares_are_below_min_pool_liquidity() {
+	new_test_ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let issuance = Tokens::total_issuance(pool_id);
+
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, issuance - 1, vec![]),
+			Error::<Test>::InsufficientShareBalance
+		);
+	});
+}
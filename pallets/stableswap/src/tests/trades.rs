@@ -666,3 +666,61 @@ fn buy_should_work_when_assets_have_different_decimals() {
 			assert_balance!(pool_account, asset_b, to_precision!(70, dec_b));
 		});
 }
+
+#[test]
+fn sell_should_apply_dynamic_fee_when_greater_than_pool_fee() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+
+	let sell_with_fees = |pool_fee: Permill, dynamic_fee: Permill| -> Balance {
+		let mut received = 0u128;
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![(BOB, 1, 200 * ONE), (ALICE, 1, 200 * ONE), (ALICE, 2, 200 * ONE)])
+			.with_registered_asset("one".as_bytes().to_vec(), 1, 12)
+			.with_registered_asset("two".as_bytes().to_vec(), 2, 12)
+			.with_dynamic_fee(dynamic_fee)
+			.with_pool(
+				ALICE,
+				PoolInfo::<AssetId, u64> {
+					assets: vec![asset_a, asset_b].try_into().unwrap(),
+					initial_amplification: NonZeroU16::new(100).unwrap(),
+					final_amplification: NonZeroU16::new(100).unwrap(),
+					initial_block: 0,
+					final_block: 0,
+					fee: pool_fee,
+				},
+				InitialLiquidity {
+					account: ALICE,
+					assets: vec![
+						AssetAmount::new(asset_a, 100 * ONE),
+						AssetAmount::new(asset_b, 100 * ONE),
+					],
+				},
+			)
+			.build()
+			.execute_with(|| {
+				let pool_id = get_pool_id_at(0);
+
+				assert_ok!(Stableswap::sell(
+					RuntimeOrigin::signed(BOB),
+					pool_id,
+					asset_a,
+					asset_b,
+					30 * ONE,
+					25 * ONE,
+				));
+
+				received = Tokens::free_balance(asset_b, &BOB);
+			});
+		received
+	};
+
+	let with_pool_fee = sell_with_fees(Permill::from_percent(1), Permill::zero());
+	let with_dynamic_fee = sell_with_fees(Permill::zero(), Permill::from_percent(1));
+	let with_lower_dynamic_fee = sell_with_fees(Permill::from_percent(1), Permill::from_rational(1u32, 1000u32));
+	let without_fee = sell_with_fees(Permill::zero(), Permill::zero());
+
+	assert_eq!(with_dynamic_fee, with_pool_fee);
+	assert_eq!(with_lower_dynamic_fee, with_pool_fee);
+	assert!(with_dynamic_fee < without_fee);
+}
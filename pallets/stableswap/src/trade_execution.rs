@@ -1,9 +1,10 @@
-use crate::types::AssetAmount;
-use crate::{Balance, Config, Error, Pallet, Pools, D_ITERATIONS, Y_ITERATIONS};
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use crate::types::{AssetAmount, Tradability};
+use crate::{AssetTradability, Balance, Config, Error, Pallet, Pools, D_ITERATIONS, Y_ITERATIONS};
+use hydradx_traits::router::{ExecutorError, PoolType, TradablePairs, Trade, TradeExecution};
 use orml_traits::MultiCurrency;
 use sp_runtime::{ArithmeticError, DispatchError};
 use sp_std::vec;
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -48,8 +49,9 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 
 					Ok(share_amount)
 				} else {
-					let (amount_out, _) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)
-						.map_err(ExecutorError::Error)?;
+					let (amount_out, _) =
+						Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in, Self::get_quote_fee)
+							.map_err(ExecutorError::Error)?;
 
 					Ok(amount_out)
 				}
@@ -119,8 +121,9 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 
 					Ok(shares_amount)
 				} else {
-					let (amount_in, _) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)
-						.map_err(ExecutorError::Error)?;
+					let (amount_in, _) =
+						Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out, Self::get_quote_fee)
+							.map_err(ExecutorError::Error)?;
 
 					Ok(amount_in)
 				}
@@ -198,3 +201,50 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 		}
 	}
 }
+
+impl<T: Config> TradablePairs<T::AssetId> for Pallet<T> {
+	fn tradable_pairs() -> Vec<Trade<T::AssetId>> {
+		let is_allowed = |pool_id: T::AssetId, asset_id: T::AssetId, operation: Tradability| {
+			AssetTradability::<T>::get(pool_id, asset_id).contains(operation)
+		};
+
+		let mut trades = Vec::new();
+
+		for (pool_id, pool) in Pools::<T>::iter() {
+			let pool_type = PoolType::Stableswap(pool_id);
+
+			for asset_in in pool.assets.iter() {
+				for asset_out in pool.assets.iter() {
+					if asset_in != asset_out
+						&& is_allowed(pool_id, *asset_in, Tradability::SELL)
+						&& is_allowed(pool_id, *asset_out, Tradability::BUY)
+					{
+						trades.push(Trade {
+							pool: pool_type,
+							asset_in: *asset_in,
+							asset_out: *asset_out,
+						});
+					}
+				}
+
+				if is_allowed(pool_id, *asset_in, Tradability::ADD_LIQUIDITY) {
+					trades.push(Trade {
+						pool: pool_type,
+						asset_in: *asset_in,
+						asset_out: pool_id,
+					});
+				}
+
+				if is_allowed(pool_id, *asset_in, Tradability::REMOVE_LIQUIDITY) {
+					trades.push(Trade {
+						pool: pool_type,
+						asset_in: pool_id,
+						asset_out: *asset_in,
+					});
+				}
+			}
+		}
+
+		trades
+	}
+}
@@ -0,0 +1,142 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn create_yield_farm_should_work_when_pool_contains_incentivized_asset() {
+	default_farms().build().execute_with(|| {
+		let global_farm_id = 1;
+		let multiplier = FixedU128::from_float(0.5_f64);
+		let loyalty_curve = Some(LoyaltyCurve::default());
+
+		assert_ok!(XYKLiquidityMining::create_yield_farm(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			DOT_KSM,
+			multiplier,
+			loyalty_curve.clone()
+		));
+
+		assert_last_event!(crate::Event::YieldFarmCreated {
+			global_farm_id,
+			yield_farm_id: 3,
+			asset_pair: DOT_KSM,
+			multiplier,
+			loyalty_curve,
+		}
+		.into());
+	});
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_pool_does_not_exist() {
+	default_farms().build().execute_with(|| {
+		let asset_pair = AssetPair {
+			asset_in: HDX,
+			asset_out: ACA,
+		};
+
+		assert_noop!(
+			XYKLiquidityMining::create_yield_farm(RuntimeOrigin::signed(GC), 1, asset_pair, FixedU128::one(), None),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_pool_does_not_contain_incentivized_asset() {
+	default_farms()
+		.with_pool(LP1, HDX, 1_000_000 * ONE, KSM, 100_000 * ONE)
+		.build()
+		.execute_with(|| {
+			let asset_pair = AssetPair {
+				asset_in: HDX,
+				asset_out: KSM,
+			};
+
+			assert_noop!(
+				XYKLiquidityMining::create_yield_farm(RuntimeOrigin::signed(GC), 1, asset_pair, FixedU128::one(), None),
+				WarehouseError::MissingIncentivizedAsset
+			);
+		});
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_origin_is_not_farm_owner() {
+	default_farms().build().execute_with(|| {
+		assert_noop!(
+			XYKLiquidityMining::create_yield_farm(RuntimeOrigin::signed(ALICE), 1, DOT_KSM, FixedU128::one(), None),
+			WarehouseError::Forbidden
+		);
+	});
+}
+
+#[test]
+fn stop_resume_and_terminate_yield_farm_should_work() {
+	default_farms().build().execute_with(|| {
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+		let multiplier = FixedU128::from(2_u128);
+
+		assert_ok!(XYKLiquidityMining::stop_yield_farm(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			HDX_DOT
+		));
+		assert_last_event!(crate::Event::YieldFarmStopped {
+			global_farm_id,
+			yield_farm_id,
+			asset_pair: HDX_DOT,
+			who: GC,
+		}
+		.into());
+
+		assert_ok!(XYKLiquidityMining::resume_yield_farm(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			yield_farm_id,
+			HDX_DOT,
+			multiplier,
+		));
+		assert_last_event!(crate::Event::YieldFarmResumed {
+			global_farm_id,
+			yield_farm_id,
+			asset_pair: HDX_DOT,
+			who: GC,
+			multiplier,
+		}
+		.into());
+
+		assert_ok!(XYKLiquidityMining::stop_yield_farm(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			HDX_DOT
+		));
+		assert_ok!(XYKLiquidityMining::terminate_yield_farm(
+			RuntimeOrigin::signed(GC),
+			global_farm_id,
+			yield_farm_id,
+			HDX_DOT,
+		));
+		assert_last_event!(crate::Event::YieldFarmTerminated {
+			global_farm_id,
+			yield_farm_id,
+			asset_pair: HDX_DOT,
+			who: GC,
+		}
+		.into());
+	});
+}
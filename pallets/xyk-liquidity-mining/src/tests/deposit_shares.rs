@@ -0,0 +1,122 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn deposit_shares_should_lock_shares_and_mint_nft_when_yield_farm_exists() {
+	default_farms().build().execute_with(|| {
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+		let deposit_id = 1;
+		let lp1_shares = pool_shares(LP1, HDX_DOT);
+		let shares_amount = lp1_shares / 2;
+
+		set_block_number(100);
+
+		//Act
+		assert_ok!(XYKLiquidityMining::deposit_shares(
+			RuntimeOrigin::signed(LP1),
+			global_farm_id,
+			yield_farm_id,
+			HDX_DOT,
+			shares_amount,
+		));
+
+		//Assert
+		assert_last_event!(crate::Event::SharesDeposited {
+			global_farm_id,
+			yield_farm_id,
+			deposit_id,
+			asset_pair: HDX_DOT,
+			who: LP1,
+			shares_amount,
+		}
+		.into());
+
+		assert_eq!(pool_shares(LP1, HDX_DOT), lp1_shares - shares_amount);
+		assert_eq!(pool_shares(XYKLiquidityMining::account_id(), HDX_DOT), shares_amount);
+
+		assert_eq!(
+			crate::DepositPool::<Test>::get(deposit_id),
+			Some((XYK::get_pair_id(HDX_DOT), share_token(HDX_DOT)))
+		);
+		assert_eq!(nft_owner(deposit_id), Some(LP1));
+	});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_pool_does_not_exist() {
+	default_farms().build().execute_with(|| {
+		let asset_pair = AssetPair {
+			asset_in: HDX,
+			asset_out: ACA,
+		};
+
+		assert_noop!(
+			XYKLiquidityMining::deposit_shares(RuntimeOrigin::signed(LP1), 1, 2, asset_pair, ONE),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_account_has_not_enough_shares() {
+	default_farms().build().execute_with(|| {
+		let lp1_shares = pool_shares(LP1, HDX_DOT);
+
+		assert_noop!(
+			XYKLiquidityMining::deposit_shares(RuntimeOrigin::signed(LP1), 1, 2, HDX_DOT, lp1_shares + 1),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_origin_is_not_signed() {
+	default_farms().build().execute_with(|| {
+		assert_noop!(
+			XYKLiquidityMining::deposit_shares(RuntimeOrigin::none(), 1, 2, HDX_DOT, ONE),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn shares_should_be_valued_in_incentivized_asset() {
+	default_farms().build().execute_with(|| {
+		let amm_pool_id = XYK::get_pair_id(HDX_DOT);
+		let all_shares = pool_shares(LP1, HDX_DOT);
+
+		assert_eq!(
+			XYKLiquidityMining::get_shares_value(DOT, amm_pool_id, all_shares),
+			Ok(100_000 * ONE)
+		);
+		assert_eq!(
+			XYKLiquidityMining::get_shares_value(HDX, amm_pool_id, all_shares / 2),
+			Ok(500_000 * ONE)
+		);
+	});
+}
+
+#[test]
+fn shares_valuation_should_fail_when_asset_is_not_in_pool() {
+	default_farms().build().execute_with(|| {
+		assert_eq!(
+			XYKLiquidityMining::get_shares_value(KSM, XYK::get_pair_id(HDX_DOT), ONE),
+			Err(Error::<Test>::AssetNotInPool.into())
+		);
+	});
+}
@@ -0,0 +1,577 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::type_complexity)]
+#![allow(clippy::too_many_arguments)]
+
+use crate::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate as xyk_liquidity_mining;
+
+use frame_support::traits::{Contains, Everything, GenesisBuild};
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use orml_traits::parameter_type_with_key;
+use pallet_liquidity_mining as warehouse_liquidity_mining;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, BlockNumberProvider, IdentityLookup, One},
+};
+
+use warehouse_liquidity_mining::{DefaultPriceAdjustment, Instance1};
+
+use hydradx_traits::{pools::DustRemovalAccountWhitelist, AssetPairAccountIdFor, Source};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u128;
+pub type BlockNumber = u64;
+//NTF types
+pub type ItemId = u128;
+
+pub const HDX: AssetId = 0;
+pub const DOT: AssetId = 1;
+pub const KSM: AssetId = 2;
+pub const ACA: AssetId = 3;
+
+pub const LP1: AccountId = 1;
+pub const LP2: AccountId = 2;
+
+pub const ALICE: AccountId = 4;
+pub const BOB: AccountId = 5;
+pub const GC: AccountId = 7;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const LM_COLLECTION_ID: CollectionId = 1;
+
+pub const HDX_DOT: AssetPair = AssetPair {
+	asset_in: HDX,
+	asset_out: DOT,
+};
+
+pub const DOT_KSM: AssetPair = AssetPair {
+	asset_in: DOT,
+	asset_out: KSM,
+};
+
+thread_local! {
+	pub static NFTS: RefCell<HashMap<(CollectionId, ItemId), AccountId>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+}
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		AssetRegistry: pallet_asset_registry,
+		XYK: pallet_xyk,
+		WarehouseLM: warehouse_liquidity_mining::<Instance1>,
+		XYKLiquidityMining: xyk_liquidity_mining,
+	}
+);
+
+parameter_types! {
+	pub static MockBlockNumberProvider: u64 = 0;
+}
+
+impl BlockNumberProvider for MockBlockNumberProvider {
+	type BlockNumber = BlockNumber;
+
+	fn current_block_number() -> Self::BlockNumber {
+		System::block_number()
+	}
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const LMPalletId: PalletId = PalletId(*b"TEST_xlm");
+	pub const LMCollectionId: CollectionId = LM_COLLECTION_ID;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Tokens;
+	type CreateOrigin = EnsureRoot<AccountId>;
+	type PalletId = LMPalletId;
+	type NFTCollectionId = LMCollectionId;
+	type NFTHandler = DummyNFT;
+	type LiquidityMiningHandler = WarehouseLM;
+	type AMM = XYK;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const WarehouseLMPalletId: PalletId = PalletId(*b"TEST_lm_");
+	pub const MinTotalFarmRewards: Balance = 1_000_000 * ONE;
+	pub const MinPlannedYieldingPeriods: BlockNumber  = 100;
+	#[derive(PartialEq, Eq)]
+	pub const MaxEntriesPerDeposit: u32 = 5;
+	pub const MaxYieldFarmsPerGlobalFarm: u32 = 10;
+}
+
+impl warehouse_liquidity_mining::Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type MultiCurrency = Tokens;
+	type PalletId = WarehouseLMPalletId;
+	type MinTotalFarmRewards = MinTotalFarmRewards;
+	type MinPlannedYieldingPeriods = MinPlannedYieldingPeriods;
+	type BlockNumberProvider = MockBlockNumberProvider;
+	type AmmPoolId = AccountId;
+	type MaxFarmEntriesPerDeposit = MaxEntriesPerDeposit;
+	type MaxYieldFarmsPerGlobalFarm = MaxYieldFarmsPerGlobalFarm;
+	type AssetRegistry = AssetRegistry;
+	type NonDustableWhitelistHandler = Whitelist;
+	type PriceAdjustment = DefaultPriceAdjustment;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+parameter_type_with_key! {
+	pub XYKDynamicFee: |_pool: AccountId| -> (u32, u32) {
+		(0, 1)
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Everything;
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const NativeAssetId: AssetId = HDX;
+	pub RegistryStringLimit: u32 = 100;
+	pub const SequentialIdOffset: u32 = 1_000_000;
+}
+
+impl pallet_asset_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RegistryOrigin = EnsureSigned<AccountId>;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type AssetNativeLocation = u8;
+	type StringLimit = RegistryStringLimit;
+	type SequentialIdStartAt = SequentialIdOffset;
+	type NativeAssetId = NativeAssetId;
+	type WeightInfo = ();
+}
+
+pub struct AssetPairAccountIdTest;
+
+impl AssetPairAccountIdFor<AssetId, AccountId> for AssetPairAccountIdTest {
+	fn from_assets(asset_a: AssetId, asset_b: AssetId, _: &str) -> AccountId {
+		let mut a = asset_a as u128;
+		let mut b = asset_b as u128;
+		if a > b {
+			std::mem::swap(&mut a, &mut b)
+		}
+		(a * 1_000 + b) + 1_000_000
+	}
+}
+
+parameter_types! {
+	pub const MinTradingLimit: Balance = 1_000;
+	pub const MinPoolLiquidity: Balance = 1_000;
+	pub const MaxInRatio: u128 = 3;
+	pub const MaxOutRatio: u128 = 3;
+	pub const ExchangeFeeRate: (u32, u32) = (2, 1_000);
+	pub const MinPoolFee: (u32, u32) = (1, 1_000);
+	pub const MaxPoolFee: (u32, u32) = (10, 1_000);
+	pub const DiscountedFeeRate: (u32, u32) = (7, 10_000);
+	pub const OracleSourceIdentifier: Source = *b"hydraxyk";
+}
+
+impl pallet_xyk::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetRegistry = AssetRegistry;
+	type AssetPairAccountId = AssetPairAccountIdTest;
+	type Currency = Tokens;
+	type NativeAssetId = NativeAssetId;
+	type WeightInfo = ();
+	type GetExchangeFee = ExchangeFeeRate;
+	type MinPoolFee = MinPoolFee;
+	type MaxPoolFee = MaxPoolFee;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type DynamicFee = XYKDynamicFee;
+	type QuoteDynamicFee = XYKDynamicFee;
+	type MinTradingLimit = MinTradingLimit;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MaxInRatio = MaxInRatio;
+	type MaxOutRatio = MaxOutRatio;
+	type CanCreatePool = pallet_xyk::AllowAllPools;
+	type AMMHandler = ();
+	type DiscountedFee = DiscountedFeeRate;
+	type NonDustableWhitelistHandler = Whitelist;
+	type OracleSource = OracleSourceIdentifier;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<(AccountId, AssetId, Balance, AssetId, Balance)>,
+	lm_global_farms: Vec<(
+		Balance,
+		PeriodOf<Test>,
+		BlockNumber,
+		AssetId,
+		AssetId,
+		AccountId,
+		Perquintill,
+		Balance,
+		FixedU128,
+	)>,
+	lm_yield_farms: Vec<(AccountId, GlobalFarmId, AssetPair, FarmMultiplier, Option<LoyaltyCurve>)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		NFTS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		DUSTER_WHITELIST.with(|v| {
+			v.borrow_mut().clear();
+		});
+
+		Self {
+			endowed_accounts: vec![],
+			pools: vec![],
+			lm_global_farms: vec![],
+			lm_yield_farms: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_endowed_accounts(mut self, accounts: Vec<(AccountId, AssetId, Balance)>) -> Self {
+		self.endowed_accounts = accounts;
+		self
+	}
+
+	pub fn with_pool(
+		mut self,
+		who: AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+	) -> Self {
+		self.pools.push((who, asset_a, amount_a, asset_b, amount_b));
+		self
+	}
+
+	pub fn with_global_farm(
+		mut self,
+		total_rewards: Balance,
+		planned_yielding_periods: PeriodOf<Test>,
+		blocks_per_period: BlockNumber,
+		incentivized_asset: AssetId,
+		reward_currency: AssetId,
+		owner: AccountId,
+		yield_per_period: Perquintill,
+		min_deposit: Balance,
+		price_adjustment: FixedU128,
+	) -> Self {
+		self.lm_global_farms.push((
+			total_rewards,
+			planned_yielding_periods,
+			blocks_per_period,
+			incentivized_asset,
+			reward_currency,
+			owner,
+			yield_per_period,
+			min_deposit,
+			price_adjustment,
+		));
+		self
+	}
+
+	pub fn with_yield_farm(
+		mut self,
+		owner: AccountId,
+		id: GlobalFarmId,
+		asset_pair: AssetPair,
+		multiplier: FarmMultiplier,
+		loyalty_curve: Option<LoyaltyCurve>,
+	) -> Self {
+		self.lm_yield_farms
+			.push((owner, id, asset_pair, multiplier, loyalty_curve));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		pallet_asset_registry::GenesisConfig::<Test> {
+			registered_assets: vec![
+				(b"DOT".to_vec(), 1_000, Some(DOT)),
+				(b"KSM".to_vec(), 1_000, Some(KSM)),
+				(b"ACA".to_vec(), 1_000, Some(ACA)),
+			],
+			native_asset_name: b"HDX".to_vec(),
+			native_existential_deposit: 1_000,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		<xyk_liquidity_mining::GenesisConfig as GenesisBuild<Test>>::assimilate_storage(
+			&xyk_liquidity_mining::GenesisConfig::default(),
+			&mut t,
+		)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			set_block_number(1);
+
+			for (who, asset_a, amount_a, asset_b, amount_b) in self.pools {
+				assert_ok!(XYK::create_pool(
+					RuntimeOrigin::signed(who),
+					asset_a,
+					amount_a,
+					asset_b,
+					amount_b,
+					None,
+				));
+			}
+
+			for gf in self.lm_global_farms {
+				assert_ok!(XYKLiquidityMining::create_global_farm(
+					RuntimeOrigin::root(),
+					gf.0,
+					gf.1,
+					gf.2,
+					gf.3,
+					gf.4,
+					gf.5,
+					gf.6,
+					gf.7,
+					gf.8,
+				));
+			}
+
+			for yf in self.lm_yield_farms {
+				assert_ok!(XYKLiquidityMining::create_yield_farm(
+					RuntimeOrigin::signed(yf.0),
+					yf.1,
+					yf.2,
+					yf.3,
+					yf.4
+				));
+			}
+		});
+
+		r
+	}
+}
+
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate, Transfer};
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u128>> Inspect<AccountId> for DummyNFT {
+	type ItemId = ItemId;
+	type CollectionId = CollectionId;
+
+	fn owner(collection: &Self::CollectionId, item: &Self::ItemId) -> Option<AccountId> {
+		let mut owner: Option<AccountId> = None;
+
+		NFTS.with(|v| {
+			if let Some(o) = v.borrow().get(&(*collection, *item)) {
+				owner = Some((*o).into());
+			}
+		});
+		owner
+	}
+}
+
+impl<AccountId: From<u128>> Create<AccountId> for DummyNFT {
+	fn create_collection(_collection: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u128> + Into<u128> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(collection: &Self::CollectionId, item: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.insert((*collection, *item), (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		collection: &Self::CollectionId,
+		item: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.remove(&(*collection, *item));
+		});
+		Ok(())
+	}
+}
+
+impl Transfer<AccountId> for DummyNFT {
+	fn transfer(collection: &Self::CollectionId, item: &Self::ItemId, destination: &AccountId) -> DispatchResult {
+		NFTS.with(|v| {
+			let mut m = v.borrow_mut();
+			let key = (*collection, *item);
+
+			if !m.contains_key(&key) {
+				return Err(sp_runtime::DispatchError::Other("NFT not found"));
+			}
+
+			m.insert(key, *destination);
+
+			Ok(())
+		})
+	}
+}
+
+pub struct Whitelist;
+
+impl Contains<AccountId> for Whitelist {
+	fn contains(account: &AccountId) -> bool {
+		DUSTER_WHITELIST.with(|v| v.borrow().contains(account))
+	}
+}
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		if Whitelist::contains(account) {
+			return Err(sp_runtime::DispatchError::Other("Account is already in the whitelist"));
+		}
+
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| {
+			let mut v = v.borrow_mut();
+
+			let idx = v.iter().position(|x| *x == *account).unwrap();
+			v.remove(idx);
+
+			Ok(())
+		})
+	}
+}
+
+pub fn set_block_number(n: u64) {
+	System::set_block_number(n);
+}
+
+pub fn share_token(asset_pair: AssetPair) -> AssetId {
+	XYK::get_share_token(asset_pair)
+}
+
+pub fn pool_shares(who: AccountId, asset_pair: AssetPair) -> Balance {
+	Tokens::free_balance(share_token(asset_pair), &who)
+}
+
+pub fn nft_owner(deposit_id: DepositId) -> Option<AccountId> {
+	NFTS.with(|v| v.borrow().get(&(LM_COLLECTION_ID, deposit_id)).copied())
+}
+
+/// Returns default environment used by most of the tests: HDX/DOT and DOT/KSM xyk pools created
+/// by `LP1` and `GC` owning global farm 1 incentivizing `DOT` with yield farm for HDX/DOT pool.
+pub fn default_farms() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, HDX, 10_000_000 * ONE),
+			(LP1, DOT, 10_000_000 * ONE),
+			(LP1, KSM, 10_000_000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(ALICE, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(LP1, HDX, 1_000_000 * ONE, DOT, 100_000 * ONE)
+		.with_pool(LP1, DOT, 100_000 * ONE, KSM, 100_000 * ONE)
+		.with_global_farm(
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			DOT,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, HDX_DOT, FixedU128::one(), None)
+}
@@ -0,0 +1,200 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn withdraw_shares_should_unlock_shares_when_last_entry_in_deposit() {
+	default_farms().build().execute_with(|| {
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+		let deposit_id = 1;
+		let shares_amount = pool_shares(LP1, HDX_DOT);
+
+		//Arrange: deposit shares
+		assert_ok!(XYKLiquidityMining::deposit_shares(
+			RuntimeOrigin::signed(LP1),
+			global_farm_id,
+			yield_farm_id,
+			HDX_DOT,
+			shares_amount,
+		));
+
+		let hdx_balance = Tokens::free_balance(HDX, &LP1);
+
+		set_block_number(1_000);
+
+		//Act
+		assert_ok!(XYKLiquidityMining::withdraw_shares(
+			RuntimeOrigin::signed(LP1),
+			deposit_id,
+			yield_farm_id,
+		));
+
+		//Assert
+		let claimed = Tokens::free_balance(HDX, &LP1) - hdx_balance;
+		assert!(claimed > 0);
+		assert!(has_event(
+			crate::Event::RewardClaimed {
+				global_farm_id,
+				yield_farm_id,
+				who: LP1,
+				claimed,
+				reward_currency: HDX,
+				deposit_id
+			}
+			.into()
+		));
+
+		assert!(has_event(
+			crate::Event::SharesWithdrawn {
+				global_farm_id,
+				yield_farm_id,
+				who: LP1,
+				amount: shares_amount,
+				deposit_id
+			}
+			.into()
+		));
+
+		assert_last_event!(crate::Event::DepositDestroyed { who: LP1, deposit_id }.into());
+
+		//Shares should return to the owner
+		assert_eq!(pool_shares(LP1, HDX_DOT), shares_amount);
+		assert_eq!(pool_shares(XYKLiquidityMining::account_id(), HDX_DOT), 0);
+
+		//Storage check
+		assert_eq!(crate::DepositPool::<Test>::get(deposit_id), None);
+
+		//Deposit's NFT should be burned.
+		assert_eq!(nft_owner(deposit_id), None);
+	});
+}
+
+#[test]
+fn withdraw_shares_should_not_unlock_shares_when_deposit_is_not_destroyed() {
+	default_farms()
+		.with_global_farm(
+			//id: 3
+			60_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			HDX,
+			ALICE,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(ALICE, 3, HDX_DOT, FixedU128::one(), None) //id: 4
+		.build()
+		.execute_with(|| {
+			let deposit_id = 1;
+			let shares_amount = pool_shares(LP1, HDX_DOT);
+
+			assert_ok!(XYKLiquidityMining::deposit_shares(
+				RuntimeOrigin::signed(LP1),
+				1,
+				2,
+				HDX_DOT,
+				shares_amount,
+			));
+			assert_ok!(XYKLiquidityMining::redeposit_shares(
+				RuntimeOrigin::signed(LP1),
+				3,
+				4,
+				deposit_id,
+			));
+
+			//Act
+			assert_ok!(XYKLiquidityMining::withdraw_shares(
+				RuntimeOrigin::signed(LP1),
+				deposit_id,
+				2,
+			));
+
+			//Assert
+			assert_eq!(pool_shares(LP1, HDX_DOT), 0);
+			assert_eq!(pool_shares(XYKLiquidityMining::account_id(), HDX_DOT), shares_amount);
+			assert_eq!(
+				crate::DepositPool::<Test>::get(deposit_id),
+				Some((XYK::get_pair_id(HDX_DOT), share_token(HDX_DOT)))
+			);
+			assert_eq!(nft_owner(deposit_id), Some(LP1));
+		});
+}
+
+#[test]
+fn withdraw_shares_should_fail_when_origin_is_not_deposit_owner() {
+	default_farms().build().execute_with(|| {
+		assert_ok!(XYKLiquidityMining::deposit_shares(
+			RuntimeOrigin::signed(LP1),
+			1,
+			2,
+			HDX_DOT,
+			pool_shares(LP1, HDX_DOT),
+		));
+
+		assert_noop!(
+			XYKLiquidityMining::withdraw_shares(RuntimeOrigin::signed(LP2), 1, 2),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn claim_rewards_should_transfer_rewards_to_deposit_owner() {
+	default_farms().build().execute_with(|| {
+		let deposit_id = 1;
+
+		assert_ok!(XYKLiquidityMining::deposit_shares(
+			RuntimeOrigin::signed(LP1),
+			1,
+			2,
+			HDX_DOT,
+			pool_shares(LP1, HDX_DOT),
+		));
+
+		let hdx_balance = Tokens::free_balance(HDX, &LP1);
+
+		set_block_number(1_000);
+
+		//Act
+		assert_ok!(XYKLiquidityMining::claim_rewards(
+			RuntimeOrigin::signed(LP1),
+			deposit_id,
+			2
+		));
+
+		//Assert
+		let claimed = Tokens::free_balance(HDX, &LP1) - hdx_balance;
+		assert!(claimed > 0);
+		assert_last_event!(crate::Event::RewardClaimed {
+			global_farm_id: 1,
+			yield_farm_id: 2,
+			who: LP1,
+			claimed,
+			reward_currency: HDX,
+			deposit_id,
+		}
+		.into());
+
+		//Claiming again in the same period is not allowed.
+		assert_noop!(
+			XYKLiquidityMining::claim_rewards(RuntimeOrigin::signed(LP1), deposit_id, 2),
+			WarehouseError::DoubleClaimInPeriod
+		);
+	});
+}
@@ -46,7 +46,7 @@ benchmarks! {
 		let amount_a : Balance = 10 * 1_000_000_000;
 		let amount_b : Balance = 20 * 1_000_000_000;
 
-	}: _(RawOrigin::Signed(caller.clone()), asset_a, amount_a, asset_b, amount_b)
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, amount_a, asset_b, amount_b, None)
 	verify {
 		assert_eq!(T::Currency::free_balance(asset_a, &caller), 999990000000000);
 	}
@@ -60,7 +60,7 @@ benchmarks! {
 		let amount : Balance = 10 * 1_000_000_000;
 		let max_limit : Balance = 10 * 1_000_000_000_000;
 
-		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000,asset_b, 1_000_000_000)?;
+		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000,asset_b, 1_000_000_000, None)?;
 
 	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, max_limit)
 	verify {
@@ -76,7 +76,7 @@ benchmarks! {
 		let asset_b: AssetId = 2;
 		let amount : Balance = 1_000_000_000;
 
-		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), 1, 10_000_000_000, 2, 20_000_000_000)?;
+		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), 1, 10_000_000_000, 2, 20_000_000_000, None)?;
 		XYK::<T>::add_liquidity(RawOrigin::Signed(caller.clone()).into(), 1, 2, 5_000_000_000, 10_100_000_000)?;
 
 		assert_eq!(T::Currency::free_balance(asset_a, &caller), 999995000000000);
@@ -99,7 +99,7 @@ benchmarks! {
 
 		let min_bought: Balance = 10 * 1_000;
 
-		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000)?;
+		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000, None)?;
 
 	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, min_bought, discount)
 	verify{
@@ -117,13 +117,27 @@ benchmarks! {
 
 		let max_sold: Balance = 6_000_000_000;
 
-		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000)?;
+		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000, None)?;
 
 	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, max_sold, discount)
 	verify{
 		assert_eq!(T::Currency::free_balance(asset_a, &caller), 1000001000000000);
 	}
 
+	update_pool_fee {
+		let maker = funded_account::<T>("maker", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+		let fee = T::MaxPoolFee::get();
+
+		XYK::<T>::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000, None)?;
+
+	}: _(RawOrigin::Signed(maker), asset_a, asset_b, fee)
+	verify{
+		assert_eq!(XYK::<T>::pool_fee(XYK::<T>::pair_account_from_assets(asset_a, asset_b)), Some(fee));
+	}
+
 	router_execution_sell {
 		let c in 1..2;	// if c == 1, calculate_sell is executed
 		let e in 0..1;	// if e == 1, execute_sell is executed
@@ -138,7 +152,7 @@ benchmarks! {
 
 		let min_bought: Balance = 10 * 1_000;
 
-		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000)?;
+		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000, None)?;
 
 	}: {
 		for _ in 1..c {
@@ -168,7 +182,7 @@ benchmarks! {
 
 		let max_sold: Balance = 6_000_000_000;
 
-		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000)?;
+		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000, None)?;
 
 	}: {
 		for _ in 1..c {
@@ -200,6 +214,7 @@ mod tests {
 			assert_ok!(Pallet::<Test>::test_benchmark_remove_liquidity());
 			assert_ok!(Pallet::<Test>::test_benchmark_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_buy());
+			assert_ok!(Pallet::<Test>::test_benchmark_update_pool_fee());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_buy());
 		});
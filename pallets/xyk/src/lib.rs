@@ -29,7 +29,12 @@
 #![allow(clippy::upper_case_acronyms)]
 
 use frame_support::sp_runtime::{traits::Zero, DispatchError};
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{EnsureOrigin, Get},
+	transactional,
+};
 use frame_system::ensure_signed;
 use hydradx_traits::{
 	AMMPosition, AMMTransfer, AssetPairAccountIdFor, CanCreatePool, OnCreatePoolHandler, OnLiquidityChangedHandler,
@@ -39,7 +44,7 @@ use sp_std::{vec, vec::Vec};
 
 use crate::types::{Amount, AssetId, AssetPair, Balance};
 use hydra_dx_math::ratio::Ratio;
-use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use orml_traits::{GetByKey, MultiCurrency, MultiCurrencyExtended};
 
 #[cfg(test)]
 mod tests;
@@ -47,6 +52,7 @@ mod tests;
 mod benchmarking;
 
 mod impls;
+pub mod migration;
 mod trade_execution;
 pub mod types;
 pub mod weights;
@@ -65,7 +71,11 @@ pub mod pallet {
 	use frame_system::pallet_prelude::OriginFor;
 	use hydradx_traits::{pools::DustRemovalAccountWhitelist, registry::ShareTokenRegistry, Source};
 
+	/// Current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
@@ -91,10 +101,27 @@ pub mod pallet {
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 
-		/// Trading fee rate
+		/// Trading fee rate set to newly created pools
 		#[pallet::constant]
 		type GetExchangeFee: Get<(u32, u32)>;
 
+		/// Lower bound of a fee which pool creator can set.
+		#[pallet::constant]
+		type MinPoolFee: Get<(u32, u32)>;
+
+		/// Upper bound of a fee which pool creator can set.
+		#[pallet::constant]
+		type MaxPoolFee: Get<(u32, u32)>;
+
+		/// Origin which can set any pool fee.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Dynamic fee of a pool. Pool fee is used as a minimum.
+		type DynamicFee: GetByKey<Self::AccountId, (u32, u32)>;
+
+		/// Dynamic fee of a pool used to quote trades. It must not modify the storage.
+		type QuoteDynamicFee: GetByKey<Self::AccountId, (u32, u32)>;
+
 		/// Minimum trading limit
 		#[pallet::constant]
 		type MinTradingLimit: Get<Balance>;
@@ -204,6 +231,15 @@ pub mod pallet {
 
 		/// Pool cannot be created due to outside factors.
 		CannotCreatePool,
+
+		/// Fee denominator is zero or fee is greater than 100%.
+		InvalidFee,
+
+		/// Fee is outside of bounds allowed for pool creator.
+		FeeOutOfBounds,
+
+		/// Origin is not allowed to update the pool.
+		Forbidden,
 	}
 
 	#[pallet::event]
@@ -268,6 +304,14 @@ pub mod pallet {
 			fee_amount: Balance,
 			pool: T::AccountId,
 		},
+
+		/// Pool fee was updated.
+		PoolFeeUpdated {
+			pool: T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			fee: (u32, u32),
+		},
 	}
 
 	/// Asset id storage for shared pool tokens
@@ -286,6 +330,16 @@ pub mod pallet {
 	pub(crate) type PoolAssets<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, (AssetId, AssetId), OptionQuery>;
 
+	/// Trading fee of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_fee)]
+	pub(crate) type PoolFee<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (u32, u32), OptionQuery>;
+
+	/// Account which created a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_creator)]
+	pub(crate) type PoolCreator<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId, OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create new pool for given asset pair.
@@ -296,6 +350,9 @@ pub mod pallet {
 		/// Pool is created with initial liquidity provided by `origin`.
 		/// Shares are issued with specified initial price and represents proportion of asset in the pool.
 		///
+		/// Trading fee of the pool is `fee` if provided, otherwise default `GetExchangeFee`. Provided
+		/// fee has to be within `MinPoolFee` and `MaxPoolFee` bounds.
+		///
 		/// Emits `PoolCreated` event when successful.
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
@@ -305,6 +362,7 @@ pub mod pallet {
 			amount_a: Balance,
 			asset_b: AssetId,
 			amount_b: Balance,
+			fee: Option<(u32, u32)>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -320,6 +378,15 @@ pub mod pallet {
 
 			ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
 
+			let fee = match fee {
+				Some(fee) => {
+					ensure!(fee.1 != 0 && fee.0 <= fee.1, Error::<T>::InvalidFee);
+					ensure!(Self::is_fee_within_bounds(fee), Error::<T>::FeeOutOfBounds);
+					fee
+				}
+				None => T::GetExchangeFee::get(),
+			};
+
 			let asset_pair = AssetPair {
 				asset_in: asset_a,
 				asset_out: asset_b,
@@ -355,6 +422,8 @@ pub mod pallet {
 
 			<ShareToken<T>>::insert(&pair_account, share_token);
 			<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
+			<PoolFee<T>>::insert(&pair_account, fee);
+			<PoolCreator<T>>::insert(&pair_account, &who);
 
 			Self::deposit_event(Event::PoolCreated {
 				who: who.clone(),
@@ -590,6 +659,8 @@ pub mod pallet {
 				<ShareToken<T>>::remove(&pair_account);
 				<PoolAssets<T>>::remove(&pair_account);
 				<TotalLiquidity<T>>::remove(&pair_account);
+				<PoolFee<T>>::remove(&pair_account);
+				<PoolCreator<T>>::remove(&pair_account);
 
 				// Ignore the failure, this cant stop liquidity removal
 				let r = T::NonDustableWhitelistHandler::remove_account(&pair_account);
@@ -661,6 +732,59 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Update trading fee of a pool.
+		///
+		/// Can be called by `AuthorityOrigin`, which can set any valid fee, or by the pool creator,
+		/// who can only set fee within `MinPoolFee` and `MaxPoolFee` bounds.
+		///
+		/// Parameters:
+		/// - `origin`: `AuthorityOrigin` or pool creator
+		/// - `asset_a`: first asset of the pool
+		/// - `asset_b`: second asset of the pool
+		/// - `fee`: new fee as (numerator, denominator)
+		///
+		/// Emits `PoolFeeUpdated` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_pool_fee())]
+		pub fn update_pool_fee(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			fee: (u32, u32),
+		) -> DispatchResult {
+			let maybe_who = match T::AuthorityOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+
+			let asset_pair = AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			};
+
+			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+			ensure!(fee.1 != 0 && fee.0 <= fee.1, Error::<T>::InvalidFee);
+
+			let pair_account = Self::get_pair_id(asset_pair);
+
+			if let Some(who) = maybe_who {
+				ensure!(Self::pool_creator(&pair_account) == Some(who), Error::<T>::Forbidden);
+				ensure!(Self::is_fee_within_bounds(fee), Error::<T>::FeeOutOfBounds);
+			}
+
+			<PoolFee<T>>::insert(&pair_account, fee);
+
+			Self::deposit_event(Event::PoolFeeUpdated {
+				pool: pair_account,
+				asset_a,
+				asset_b,
+				fee,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -685,13 +809,48 @@ impl<T: Config> Pallet<T> {
 		)
 	}
 
-	/// Calculate trade fee
-	fn calculate_fee(amount: Balance) -> Result<Balance, DispatchError> {
-		let fee = T::GetExchangeFee::get();
+	/// Returns true if fee `a` is lower than fee `b`.
+	fn is_fee_lower(a: (u32, u32), b: (u32, u32)) -> bool {
+		(a.0 as u64).saturating_mul(b.1 as u64) < (b.0 as u64).saturating_mul(a.1 as u64)
+	}
+
+	/// Returns true if `fee` is within `MinPoolFee` and `MaxPoolFee` bounds.
+	fn is_fee_within_bounds(fee: (u32, u32)) -> bool {
+		!Self::is_fee_lower(fee, T::MinPoolFee::get()) && !Self::is_fee_lower(T::MaxPoolFee::get(), fee)
+	}
+
+	/// Calculate trade fee of given pool
+	fn calculate_fee(pool_account_id: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		Self::calculate_fee_amount(Self::get_fee(pool_account_id), amount)
+	}
+
+	/// Calculate trade fee of given pool to quote a trade. Dynamic fee is not updated.
+	pub(crate) fn calculate_quote_fee(
+		pool_account_id: &T::AccountId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Self::calculate_fee_amount(Self::trade_fee(pool_account_id, T::QuoteDynamicFee::get), amount)
+	}
+
+	fn calculate_fee_amount(fee: (u32, u32), amount: Balance) -> Result<Balance, DispatchError> {
 		Ok(hydra_dx_math::fee::calculate_pool_trade_fee(amount, (fee.0, fee.1))
 			.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?)
 	}
 
+	/// Trade fee of a pool - greater of pool fee and dynamic fee.
+	/// Default exchange fee is used for pools without pool fee.
+	fn trade_fee(pool_account_id: &T::AccountId, dynamic_fee: fn(&T::AccountId) -> (u32, u32)) -> (u32, u32) {
+		let Some(pool_fee) = <PoolFee<T>>::get(pool_account_id) else {
+			return T::GetExchangeFee::get();
+		};
+		let dynamic_fee = dynamic_fee(pool_account_id);
+		if Self::is_fee_lower(pool_fee, dynamic_fee) {
+			dynamic_fee
+		} else {
+			pool_fee
+		}
+	}
+
 	pub fn pair_account_from_assets(asset_a: AssetId, asset_b: AssetId) -> T::AccountId {
 		T::AssetPairAccountId::from_assets(asset_a, asset_b, "xyk")
 	}
@@ -791,7 +950,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		let transfer_fee = if discount {
 			Self::calculate_discounted_fee(amount_out)?
 		} else {
-			Self::calculate_fee(amount_out)?
+			Self::calculate_fee(&pair_account, amount_out)?
 		};
 
 		let amount_out_without_fee = amount_out
@@ -954,7 +1113,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		let transfer_fee = if discount {
 			Self::calculate_discounted_fee(buy_price)?
 		} else {
-			Self::calculate_fee(buy_price)?
+			Self::calculate_fee(&pair_account, buy_price)?
 		};
 
 		let buy_price_with_fee = buy_price
@@ -1074,8 +1233,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		T::MaxOutRatio::get()
 	}
 
-	fn get_fee(_pool_account_id: &T::AccountId) -> (u32, u32) {
-		T::GetExchangeFee::get()
+	fn get_fee(pool_account_id: &T::AccountId) -> (u32, u32) {
+		Self::trade_fee(pool_account_id, T::DynamicFee::get)
 	}
 }
 
@@ -0,0 +1,90 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{Config, Pallet, PoolAssets, PoolFee};
+use frame_support::{
+	log,
+	traits::{Get, StorageVersion},
+	weights::Weight,
+};
+
+/// Existing pools are assigned the current default fee.
+///
+/// Creator of existing pools is not known, so their fee can be updated only by `AuthorityOrigin`.
+pub mod v1 {
+	use super::*;
+
+	pub fn pre_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+		log::info!(
+			target: "runtime::xyk",
+			"XYK migration: PRE checks successful!"
+		);
+	}
+
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= 1 {
+			log::warn!(
+				target: "runtime::xyk",
+				"Attempted to apply migration to v1 but storage version is already v1"
+			);
+			return T::DbWeight::get().reads(1);
+		}
+
+		log::info!(
+			target: "runtime::xyk",
+			"Running migration to v1 for XYK"
+		);
+
+		let fee = T::GetExchangeFee::get();
+
+		let mut migrated: u64 = 0;
+		for (pool, _assets) in PoolAssets::<T>::iter() {
+			PoolFee::<T>::insert(pool, fee);
+			migrated += 1;
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		log::info!(
+			target: "runtime::xyk",
+			"Migrated fee of {:?} pools",
+			migrated
+		);
+
+		//storage version read and write included
+		T::DbWeight::get().reads_writes(migrated.saturating_add(1), migrated.saturating_add(1))
+	}
+
+	pub fn post_migrate<T: Config>() {
+		assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+		for (pool, _assets) in PoolAssets::<T>::iter() {
+			assert_eq!(
+				PoolFee::<T>::get(pool),
+				Some(T::GetExchangeFee::get()),
+				"Pool fee not migrated."
+			);
+		}
+
+		log::info!(
+			target: "runtime::xyk",
+			"XYK migration: POST checks successful!"
+		);
+	}
+}
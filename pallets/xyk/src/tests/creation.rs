@@ -20,6 +20,7 @@ fn create_pool_should_work() {
 			100_000_000_000_000,
 			asset_b,
 			10 * 100_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -93,21 +94,22 @@ fn create_same_pool_should_not_work() {
 			1000,
 			asset_a,
 			2000,
+			None,
 		));
 		assert_noop!(
-			XYK::create_pool(RuntimeOrigin::signed(user), asset_b, 999, asset_a, 2 * 999),
+			XYK::create_pool(RuntimeOrigin::signed(user), asset_b, 999, asset_a, 2 * 999, None),
 			Error::<Test>::InsufficientLiquidity
 		);
 		assert_noop!(
-			XYK::create_pool(RuntimeOrigin::signed(user), asset_b, 1000, asset_a, 0),
+			XYK::create_pool(RuntimeOrigin::signed(user), asset_b, 1000, asset_a, 0, None),
 			Error::<Test>::InsufficientLiquidity
 		);
 		assert_noop!(
-			XYK::create_pool(RuntimeOrigin::signed(user), asset_a, 1000, asset_a, 2000),
+			XYK::create_pool(RuntimeOrigin::signed(user), asset_a, 1000, asset_a, 2000, None),
 			Error::<Test>::CannotCreatePoolWithSameAssets
 		);
 		assert_noop!(
-			XYK::create_pool(RuntimeOrigin::signed(user), asset_b, 1000, asset_a, 2000),
+			XYK::create_pool(RuntimeOrigin::signed(user), asset_b, 1000, asset_a, 2000, None),
 			Error::<Test>::TokenPoolAlreadyExists
 		);
 
@@ -142,6 +144,7 @@ fn create_pool_with_insufficient_balance_should_not_work() {
 				100_000_000_000_000,
 				asset_a,
 				10 * 100_000_000_000_000,
+				None,
 			),
 			Error::<Test>::InsufficientAssetBalance
 		);
@@ -153,6 +156,7 @@ fn create_pool_with_insufficient_balance_should_not_work() {
 				100_000_000_000_000,
 				4000,
 				10 * 100_000_000_000_000,
+				None,
 			),
 			Error::<Test>::InsufficientAssetBalance
 		);
@@ -163,12 +167,12 @@ fn create_pool_with_insufficient_balance_should_not_work() {
 fn create_pool_with_insufficient_liquidity_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 500, HDX, 5000),
+			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 500, HDX, 5000, None),
 			Error::<Test>::InsufficientLiquidity
 		);
 
 		assert_noop!(
-			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 5000, HDX, 500),
+			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 5000, HDX, 500, None),
 			Error::<Test>::InsufficientLiquidity
 		);
 	});
@@ -186,6 +190,7 @@ fn create_pool_small_fixed_point_amount_should_work() {
 			100_000_000_000_000,
 			asset_b,
 			1_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -226,6 +231,7 @@ fn destroy_pool_on_remove_liquidity_and_recreate_should_work() {
 			100_000_000,
 			asset_b,
 			1_000_000_000_000,
+			None,
 		));
 
 		let asset_pair = AssetPair {
@@ -256,7 +262,8 @@ fn destroy_pool_on_remove_liquidity_and_recreate_should_work() {
 			asset_a,
 			100_000_000,
 			asset_b,
-			1_000_000_000_000
+			1_000_000_000_000,
+			None,
 		));
 
 		expect_events(vec![
@@ -329,7 +336,8 @@ fn create_pool_with_same_assets_should_not_be_allowed() {
 				asset_a,
 				100_000_000,
 				asset_a,
-				100_000_000_000_000_000_000
+				100_000_000_000_000_000_000,
+				None,
 			),
 			Error::<Test>::CannotCreatePoolWithSameAssets
 		);
@@ -348,6 +356,7 @@ fn can_create_pool_should_work() {
 				100_000_000_000_000,
 				asset_b,
 				1_000_000_000_000_000,
+				None,
 			),
 			Error::<Test>::CannotCreatePool
 		);
@@ -390,6 +399,7 @@ fn share_asset_id_should_be_offset() {
 			100_000_000_000_000,
 			ACA,
 			10 * 100_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(asset_pair);
@@ -408,6 +418,7 @@ fn share_asset_id_should_be_offset() {
 			100_000_000_000_000,
 			DOT,
 			10 * 100_000_000_000_000,
+			None,
 		));
 
 		let asset_pair = AssetPair {
@@ -9,8 +9,8 @@ use crate::types::AssetPair;
 #[test]
 fn fee_calculation() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(XYK::calculate_fee(100_000), Ok(200));
-		assert_eq!(XYK::calculate_fee(10_000), Ok(20));
+		assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100_000), Ok(200));
+		assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 10_000), Ok(20));
 
 		assert_eq!(XYK::calculate_discounted_fee(9_999), Ok(0));
 		assert_eq!(XYK::calculate_discounted_fee(10_000), Ok(7));
@@ -21,8 +21,8 @@ fn fee_calculation() {
 		.with_discounted_fee((10, 1000))
 		.build()
 		.execute_with(|| {
-			assert_eq!(XYK::calculate_fee(100_000), Ok(1_000));
-			assert_eq!(XYK::calculate_fee(10_000), Ok(100));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100_000), Ok(1_000));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 10_000), Ok(100));
 
 			assert_eq!(XYK::calculate_discounted_fee(999), Ok(0));
 			assert_eq!(XYK::calculate_discounted_fee(1_000), Ok(10));
@@ -33,14 +33,17 @@ fn fee_calculation() {
 		.with_exchange_fee((10, 0))
 		.build()
 		.execute_with(|| {
-			assert_eq!(XYK::calculate_fee(100000), Ok(0));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100000), Ok(0));
 		});
 
 	ExtBuilder::default()
 		.with_exchange_fee((10, 1))
 		.build()
 		.execute_with(|| {
-			assert_noop!(XYK::calculate_fee(u128::MAX), Error::<Test>::FeeAmountInvalid);
+			assert_noop!(
+				XYK::calculate_fee(&HDX_DOT_POOL_ID, u128::MAX),
+				Error::<Test>::FeeAmountInvalid
+			);
 		});
 }
 
@@ -53,6 +56,7 @@ fn get_fee_should_work() {
 			1_000_000_000,
 			DOT,
 			2_000_000_000,
+			None,
 		));
 
 		// existing pool
@@ -84,6 +88,7 @@ fn discount_sell_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 		assert_ok!(XYK::create_pool(
 			RuntimeOrigin::signed(ALICE),
@@ -91,6 +96,7 @@ fn discount_sell_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -155,6 +161,7 @@ fn discount_sell_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 		assert_ok!(XYK::create_pool(
 			RuntimeOrigin::signed(ALICE),
@@ -162,6 +169,7 @@ fn discount_sell_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -229,6 +237,7 @@ fn discount_sell_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 		assert_ok!(XYK::create_pool(
 			RuntimeOrigin::signed(ALICE),
@@ -236,6 +245,7 @@ fn discount_sell_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -298,6 +308,7 @@ fn discount_buy_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(XYK::create_pool(
@@ -306,6 +317,7 @@ fn discount_buy_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let native_pair_account = XYK::get_pair_id(AssetPair {
@@ -371,6 +383,7 @@ fn discount_buy_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(XYK::create_pool(
@@ -379,6 +392,7 @@ fn discount_buy_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let native_pair_account = XYK::get_pair_id(AssetPair {
@@ -447,6 +461,7 @@ fn discount_buy_fees_should_work() {
 			1_000_000_000_000,
 			HDX,
 			2_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(XYK::create_pool(
@@ -455,6 +470,7 @@ fn discount_buy_fees_should_work() {
 			200_000_000_000_000,
 			asset_b,
 			400_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -496,3 +512,27 @@ fn discount_buy_fees_should_work() {
 		.into()]);
 	});
 }
+
+#[test]
+fn get_fee_should_return_dynamic_fee_when_greater_than_pool_fee() {
+	ExtBuilder::default()
+		.with_dynamic_fee((5, 1_000))
+		.build()
+		.execute_with(|| {
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				1_000_000_000,
+				DOT,
+				2_000_000_000,
+				None,
+			));
+
+			assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (5, 1_000));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100_000), Ok(500));
+
+			assert_ok!(XYK::update_pool_fee(RuntimeOrigin::root(), HDX, DOT, (10, 1_000)));
+
+			assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (10, 1_000));
+		});
+}
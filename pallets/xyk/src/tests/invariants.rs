@@ -78,6 +78,7 @@ proptest! {
 					initial_liquidity,
 					asset_b,
 					FixedU128::from_float(price).saturating_mul_int(initial_liquidity),
+					None,
 				));
 
 				let pool_account = XYK::get_pair_id(AssetPair {
@@ -186,6 +187,7 @@ proptest! {
 					initial_liquidity,
 					asset_b,
 					FixedU128::from_float(price).saturating_mul_int(initial_liquidity),
+					None,
 				));
 
 				let pool_account = XYK::get_pair_id(AssetPair {
@@ -297,6 +299,7 @@ proptest! {
 					initial_liquidity,
 					asset_b,
 					FixedU128::from_float(price).saturating_mul_int(initial_liquidity),
+					None,
 				));
 
 				let pool_account = XYK::get_pair_id(AssetPair {
@@ -366,6 +369,7 @@ proptest! {
 					initial_liquidity,
 					asset_b,
 					FixedU128::from_float(price).saturating_mul_int(initial_liquidity),
+					None,
 				));
 
 				let pool_account = XYK::get_pair_id(AssetPair {
@@ -436,6 +440,7 @@ proptest! {
 					initial_liquidity,
 					asset_b,
 					FixedU128::from_float(price).saturating_mul_int(initial_liquidity),
+					None,
 				));
 
 				assert_ok!(XYK::create_pool(
@@ -444,6 +449,7 @@ proptest! {
 					10 * ONE,
 					HDX,
 					10 * ONE,
+					None,
 				));
 
 				let pool_account = XYK::get_pair_id(AssetPair {
@@ -513,6 +519,7 @@ proptest! {
 					initial_liquidity,
 					asset_b,
 					FixedU128::from_float(price).saturating_mul_int(initial_liquidity),
+					None,
 				));
 
 				assert_ok!(XYK::create_pool(
@@ -521,6 +528,7 @@ proptest! {
 					10 * ONE,
 					HDX,
 					10 * ONE,
+					None,
 				));
 
 				let pool_account = XYK::get_pair_id(AssetPair {
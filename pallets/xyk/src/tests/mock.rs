@@ -20,7 +20,7 @@ use crate::Config;
 use crate::*;
 use frame_support::parameter_types;
 use frame_system as system;
-use orml_traits::parameter_type_with_key;
+use orml_traits::{parameter_type_with_key, GetByKey};
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
@@ -31,7 +31,7 @@ use crate::types::{AssetId, Balance};
 use frame_support::traits::{Everything, GenesisBuild, Get, Nothing};
 use hydradx_traits::{AssetPairAccountIdFor, CanCreatePool, Source};
 
-use frame_system::EnsureSigned;
+use frame_system::{EnsureRoot, EnsureSigned};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use std::cell::RefCell;
 
@@ -71,6 +71,7 @@ thread_local! {
 		static EXCHANGE_FEE: RefCell<(u32, u32)> = RefCell::new((2, 1_000));
 		static DISCOUNTED_FEE: RefCell<(u32, u32)> = RefCell::new((7, 10_000));
 		static MAX_OUT_RATIO: RefCell<u128> = RefCell::new(3);
+		static DYNAMIC_FEE: RefCell<(u32, u32)> = RefCell::new((0, 1));
 }
 
 struct ExchangeFee;
@@ -87,6 +88,13 @@ impl Get<(u32, u32)> for DiscountedFee {
 	}
 }
 
+pub struct DynamicFee;
+impl GetByKey<AccountId, (u32, u32)> for DynamicFee {
+	fn get(_pool: &AccountId) -> (u32, u32) {
+		DYNAMIC_FEE.with(|v| *v.borrow())
+	}
+}
+
 struct MaximumOutRatio;
 impl Get<u128> for MaximumOutRatio {
 	fn get() -> u128 {
@@ -181,6 +189,8 @@ parameter_types! {
 	pub MaxOutRatio: u128 = MaximumOutRatio::get();
 	pub ExchangeFeeRate: (u32, u32) = ExchangeFee::get();
 	pub DiscountedFeeRate: (u32, u32) = DiscountedFee::get();
+	pub const MinPoolFee: (u32, u32) = (1, 1_000);
+	pub const MaxPoolFee: (u32, u32) = (10, 1_000);
 	pub const OracleSourceIdentifier: Source = *b"hydraxyk";
 }
 
@@ -200,6 +210,11 @@ impl Config for Test {
 	type NativeAssetId = NativeAssetId;
 	type WeightInfo = ();
 	type GetExchangeFee = ExchangeFeeRate;
+	type MinPoolFee = MinPoolFee;
+	type MaxPoolFee = MaxPoolFee;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type DynamicFee = DynamicFee;
+	type QuoteDynamicFee = DynamicFee;
 	type MinTradingLimit = MinTradingLimit;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MaxInRatio = MaxInRatio;
@@ -250,6 +265,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_dynamic_fee(self, f: (u32, u32)) -> Self {
+		DYNAMIC_FEE.with(|v| *v.borrow_mut() = f);
+		self
+	}
+
 	pub fn with_max_out_ratio(self, f: u128) -> Self {
 		MAX_OUT_RATIO.with(|v| *v.borrow_mut() = f);
 		self
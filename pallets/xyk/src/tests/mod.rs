@@ -4,5 +4,6 @@ mod fees;
 mod invariants;
 mod liquidity;
 pub(crate) mod mock;
+mod pool_fee;
 mod spot_price;
 mod trades;
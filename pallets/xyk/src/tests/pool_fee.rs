@@ -0,0 +1,206 @@
+pub use super::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::AMM as AmmPool;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn create_hdx_dot_pool() {
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		1_000_000_000,
+		DOT,
+		2_000_000_000,
+		None,
+	));
+}
+
+#[test]
+fn create_pool_should_set_default_fee_and_creator() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_eq!(XYK::pool_fee(HDX_DOT_POOL_ID), Some((2, 1_000)));
+		assert_eq!(XYK::pool_creator(HDX_DOT_POOL_ID), Some(ALICE));
+	});
+}
+
+#[test]
+fn create_pool_should_set_provided_fee_when_within_bounds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			1_000_000_000,
+			DOT,
+			2_000_000_000,
+			Some((5, 1_000)),
+		));
+
+		assert_eq!(XYK::pool_fee(HDX_DOT_POOL_ID), Some((5, 1_000)));
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (5, 1_000));
+		assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100_000), Ok(500));
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_fee_is_out_of_bounds() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				1_000_000_000,
+				DOT,
+				2_000_000_000,
+				Some((11, 1_000)),
+			),
+			Error::<Test>::FeeOutOfBounds
+		);
+		assert_noop!(
+			XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				1_000_000_000,
+				DOT,
+				2_000_000_000,
+				Some((9, 10_000)),
+			),
+			Error::<Test>::FeeOutOfBounds
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_fee_is_invalid() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				1_000_000_000,
+				DOT,
+				2_000_000_000,
+				Some((1, 0)),
+			),
+			Error::<Test>::InvalidFee
+		);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_work_when_called_by_creator_within_bounds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::update_pool_fee(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DOT,
+			(10, 1_000)
+		));
+
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (10, 1_000));
+		assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100_000), Ok(1_000));
+
+		expect_events(vec![Event::PoolFeeUpdated {
+			pool: HDX_DOT_POOL_ID,
+			asset_a: HDX,
+			asset_b: DOT,
+			fee: (10, 1_000),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_work_when_called_by_authority_outside_bounds() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::update_pool_fee(RuntimeOrigin::root(), HDX, DOT, (5, 100)));
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (5, 100));
+
+		assert_ok!(XYK::update_pool_fee(RuntimeOrigin::root(), HDX, DOT, (0, 1)));
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (0, 1));
+	});
+}
+
+#[test]
+fn update_pool_fee_should_fail_when_fee_is_out_of_bounds() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_noop!(
+			XYK::update_pool_fee(RuntimeOrigin::signed(ALICE), HDX, DOT, (11, 1_000)),
+			Error::<Test>::FeeOutOfBounds
+		);
+		assert_noop!(
+			XYK::update_pool_fee(RuntimeOrigin::signed(ALICE), HDX, DOT, (9, 10_000)),
+			Error::<Test>::FeeOutOfBounds
+		);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_fail_when_fee_is_invalid() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_noop!(
+			XYK::update_pool_fee(RuntimeOrigin::root(), HDX, DOT, (1, 0)),
+			Error::<Test>::InvalidFee
+		);
+		assert_noop!(
+			XYK::update_pool_fee(RuntimeOrigin::root(), HDX, DOT, (2, 1)),
+			Error::<Test>::InvalidFee
+		);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_fail_when_called_by_other_account() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_noop!(
+			XYK::update_pool_fee(RuntimeOrigin::signed(BOB), HDX, DOT, (5, 1_000)),
+			Error::<Test>::Forbidden
+		);
+		assert_noop!(
+			XYK::update_pool_fee(RuntimeOrigin::none(), HDX, DOT, (5, 1_000)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn update_pool_fee_should_fail_when_pool_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::update_pool_fee(RuntimeOrigin::root(), HDX, DOT, (5, 1_000)),
+			Error::<Test>::TokenPoolNotFound
+		);
+	});
+}
+
+#[test]
+fn pool_fee_should_be_removed_when_pool_is_destroyed() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::update_pool_fee(RuntimeOrigin::root(), HDX, DOT, (5, 1_000)));
+
+		assert_ok!(XYK::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DOT,
+			1_000_000_000
+		));
+
+		assert_eq!(XYK::pool_fee(HDX_DOT_POOL_ID), None);
+		assert_eq!(XYK::pool_creator(HDX_DOT_POOL_ID), None);
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (2, 1_000));
+	});
+}
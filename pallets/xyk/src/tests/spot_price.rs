@@ -22,7 +22,8 @@ fn spot_price_provider_should_return_correct_price_when_pool_exists() {
 				asset_a,
 				initial,
 				asset_b,
-				39_600_000_000_000
+				39_600_000_000_000,
+				None,
 			));
 
 			let price = XYKSpotPrice::<Test>::spot_price(asset_a, asset_b);
@@ -59,7 +60,8 @@ fn spot_price_provider_should_return_none_when_asset_reserve_is_zero() {
 				asset_a,
 				initial,
 				asset_b,
-				39_600_000_000_000
+				39_600_000_000_000,
+				None,
 			));
 
 			let pool_account = XYK::get_pair_id(AssetPair {
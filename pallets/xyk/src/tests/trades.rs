@@ -19,6 +19,7 @@ fn sell_test() {
 			200_000_000_000,
 			asset_b,
 			600_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -99,6 +100,7 @@ fn work_flow_happy_path_should_work() {
 			350_000_000_000,
 			asset_b,
 			14_000_000_000_000,
+			None,
 		));
 
 		// User 1 really tries!
@@ -315,6 +317,7 @@ fn sell_with_correct_fees_should_work() {
 			10_000_000,
 			asset_b,
 			2_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -383,6 +386,7 @@ fn sell_without_sufficient_balance_should_not_work() {
 			1_000_000_000,
 			asset_b,
 			1_000_000_000,
+			None,
 		));
 
 		assert_ok!(Currency::transfer(
@@ -412,6 +416,7 @@ fn sell_without_sufficient_discount_balance_should_not_work() {
 			1_000_000_000_000,
 			asset_b,
 			1_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(XYK::create_pool(
@@ -420,6 +425,7 @@ fn sell_without_sufficient_discount_balance_should_not_work() {
 			1_000_000_000_000,
 			HDX,
 			1_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(Currency::transfer(
@@ -449,6 +455,7 @@ fn buy_without_sufficient_balance_should_not_work() {
 			1_000_000_000,
 			asset_b,
 			1_000_000_000,
+			None,
 		));
 
 		assert_ok!(Currency::transfer(
@@ -478,6 +485,7 @@ fn buy_without_sufficient_discount_balance_should_not_work() {
 			1_000_000_000_000,
 			asset_b,
 			1_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(XYK::create_pool(
@@ -486,6 +494,7 @@ fn buy_without_sufficient_discount_balance_should_not_work() {
 			1_000_000_000_000,
 			HDX,
 			1_000_000_000_000,
+			None,
 		));
 
 		assert_ok!(Currency::transfer(
@@ -522,6 +531,7 @@ fn single_buy_should_work() {
 			200_000_000,
 			asset_b,
 			640_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -581,12 +591,12 @@ fn single_buy_should_work() {
 fn create_pool_with_insufficient_liquidity_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 500, HDX, 1_600_000),
+			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 500, HDX, 1_600_000, None),
 			Error::<Test>::InsufficientLiquidity
 		);
 
 		assert_noop!(
-			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 5000, HDX, 500),
+			XYK::create_pool(RuntimeOrigin::signed(ALICE), ACA, 5000, HDX, 500, None),
 			Error::<Test>::InsufficientLiquidity
 		);
 	});
@@ -636,7 +646,8 @@ fn discount_sell_with_no_native_pool_should_not_work() {
 			ACA,
 			1000,
 			DOT,
-			3_200_000
+			3_200_000,
+			None,
 		));
 
 		assert_noop!(
@@ -671,7 +682,8 @@ fn discount_buy_with_no_native_pool_should_not_work() {
 			ACA,
 			10_000,
 			DOT,
-			32_000_000
+			32_000_000,
+			None,
 		));
 
 		assert_noop!(
@@ -697,6 +709,7 @@ fn money_in_sell_money_out_should_leave_the_same_balance() {
 			200_000_000_000,
 			asset_b,
 			600_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -761,6 +774,7 @@ fn money_in_money_out_should_leave_the_same_balance_for_both_accounts() {
 			100_000_000,
 			asset_b,
 			1_000_000_000_000,
+			None,
 		));
 
 		let asset_pair = AssetPair {
@@ -827,6 +841,7 @@ fn sell_test_not_reaching_limit() {
 			200_000_000_000,
 			asset_b,
 			600_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -875,6 +890,7 @@ fn buy_test_exceeding_max_limit() {
 			200_000_000_000,
 			asset_b,
 			600_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -923,6 +939,7 @@ fn single_buy_more_than_ratio_out_should_not_work() {
 			200_000_000,
 			asset_b,
 			640_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -964,7 +981,8 @@ fn single_buy_more_than_ratio_in_should_not_work() {
 			asset_a,
 			100_000_000_000,
 			asset_b,
-			100_000_000_000
+			100_000_000_000,
+			None,
 		));
 
 		assert_noop!(
@@ -994,6 +1012,7 @@ fn single_sell_more_than_ratio_in_should_not_work() {
 			200_000_000_000,
 			asset_b,
 			600_000_000_000_000,
+			None,
 		));
 
 		let pair_account = XYK::get_pair_id(AssetPair {
@@ -1035,7 +1054,8 @@ fn single_sell_more_than_ratio_out_should_not_work() {
 			asset_a,
 			100_000_000_000,
 			asset_b,
-			100_000_000_000
+			100_000_000_000,
+			None,
 		));
 
 		assert_noop!(
@@ -1075,7 +1095,7 @@ fn buy_with_low_amount_should_not_work() {
 #[test]
 fn buy_with_excesive_amount_should_not_work() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(XYK::create_pool(RuntimeOrigin::signed(ALICE), HDX, 10_000, DOT, 10_000,));
+		assert_ok!(XYK::create_pool(RuntimeOrigin::signed(ALICE), HDX, 10_000, DOT, 10_000,, None));
 
 		assert_noop!(
 			XYK::buy(RuntimeOrigin::signed(ALICE), HDX, DOT, 20_000, 1_000_000, false),
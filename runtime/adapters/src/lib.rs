@@ -22,7 +22,7 @@ use cumulus_primitives_core::relay_chain::Hash;
 use frame_support::{
 	sp_runtime::{
 		traits::{AtLeast32BitUnsigned, Convert, Get, MaybeSerializeDeserialize, Saturating, Zero},
-		ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128,
+		ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128, Permill,
 		SaturatedConversion,
 	},
 	traits::{Contains, LockIdentifier, OriginTrait},
@@ -436,16 +436,29 @@ where
 		)
 		.map_err(|(_, e)| e)?;
 
-		let amount_out = *asset.delta_changes.delta_reserve;
-
-		pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
-			Lrna::get().into(),
-			Balance::zero().into(),
-			Balance::zero().into(),
-			asset.asset_id.into(),
-			asset.before.reserve.into(),
-			amount_out.into(),
-		)?;
+		// Asset is sold to the pool for hub asset when its reserve increases, e.g. in hub asset buyback.
+		match asset.delta_changes.delta_reserve {
+			BalanceUpdate::Increase(amount_in) => {
+				pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
+					asset.asset_id.into(),
+					asset.before.reserve.into(),
+					amount_in.into(),
+					Lrna::get().into(),
+					Balance::zero().into(),
+					Balance::zero().into(),
+				)?
+			}
+			BalanceUpdate::Decrease(amount_out) => {
+				pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit(
+					Lrna::get().into(),
+					Balance::zero().into(),
+					Balance::zero().into(),
+					asset.asset_id.into(),
+					asset.before.reserve.into(),
+					amount_out.into(),
+				)?
+			}
+		};
 
 		Ok(Self::on_trade_weight())
 	}
@@ -836,6 +849,98 @@ where
 	}
 }
 
+/// Volume provider of XYK pools, keyed by pool account.
+/// Volume and liquidity are denominated in the first asset of the pool.
+pub struct OracleXYKPoolVolumeProvider<Runtime, Period>(PhantomData<(Runtime, Period)>);
+
+impl<Runtime, Period> pallet_dynamic_fees::traits::VolumeProvider<AccountId, Balance>
+	for OracleXYKPoolVolumeProvider<Runtime, Period>
+where
+	Runtime: pallet_ema_oracle::Config + pallet_xyk::Config + frame_system::Config<AccountId = AccountId>,
+	Period: Get<OraclePeriod>,
+{
+	type Volume = OracleVolume;
+
+	fn asset_volume(pool: AccountId) -> Option<Self::Volume> {
+		let (asset_a, asset_b) = pallet_xyk::Pallet::<Runtime>::pool_assets(pool)?;
+		let entry =
+			pallet_ema_oracle::Pallet::<Runtime>::get_entry(asset_a, asset_b, Period::get(), XYK_SOURCE).ok()?;
+		Some(OracleVolume(entry.volume.a_in, entry.volume.a_out))
+	}
+
+	fn asset_liquidity(pool: AccountId) -> Option<Balance> {
+		let (asset_a, asset_b) = pallet_xyk::Pallet::<Runtime>::pool_assets(pool)?;
+		let entry =
+			pallet_ema_oracle::Pallet::<Runtime>::get_entry(asset_a, asset_b, Period::get(), XYK_SOURCE).ok()?;
+		Some(entry.liquidity.a)
+	}
+}
+
+/// Volume provider of Stableswap pools, keyed by pool id.
+/// Volume and liquidity of the pool asset with the highest volume imbalance relative to its liquidity is used,
+/// so that assets with different decimals are not mixed.
+pub struct OracleStableswapPoolVolumeProvider<Runtime, Period>(PhantomData<(Runtime, Period)>);
+
+impl<Runtime, Period> OracleStableswapPoolVolumeProvider<Runtime, Period>
+where
+	Runtime: pallet_ema_oracle::Config + pallet_stableswap::Config<AssetId = AssetId>,
+	Period: Get<OraclePeriod>,
+{
+	fn most_imbalanced_entry(pool_id: AssetId) -> Option<(OracleVolume, Balance)> {
+		let pool = pallet_stableswap::Pallet::<Runtime>::pools(pool_id)?;
+		pool.assets
+			.iter()
+			.filter_map(|asset_id| {
+				let entry = pallet_ema_oracle::Pallet::<Runtime>::get_entry(
+					*asset_id,
+					pool_id,
+					Period::get(),
+					STABLESWAP_SOURCE,
+				)
+				.ok()?;
+				Some((OracleVolume(entry.volume.a_in, entry.volume.a_out), entry.liquidity.a))
+			})
+			.max_by_key(|(volume, liquidity)| {
+				FixedU128::checked_from_rational(volume.0.abs_diff(volume.1), *liquidity).unwrap_or_default()
+			})
+	}
+}
+
+impl<Runtime, Period> pallet_dynamic_fees::traits::VolumeProvider<AssetId, Balance>
+	for OracleStableswapPoolVolumeProvider<Runtime, Period>
+where
+	Runtime: pallet_ema_oracle::Config + pallet_stableswap::Config<AssetId = AssetId>,
+	Period: Get<OraclePeriod>,
+{
+	type Volume = OracleVolume;
+
+	fn asset_volume(pool_id: AssetId) -> Option<Self::Volume> {
+		Self::most_imbalanced_entry(pool_id).map(|(volume, _)| volume)
+	}
+
+	fn asset_liquidity(pool_id: AssetId) -> Option<Balance> {
+		Self::most_imbalanced_entry(pool_id).map(|(_, liquidity)| liquidity)
+	}
+}
+
+/// Provides asset fee of dynamic fees. Protocol fee is used by Omnipool only.
+pub struct DynamicAssetFee<Fees>(PhantomData<Fees>);
+
+impl<K, Fees: GetByKey<K, (Permill, Permill)>> GetByKey<K, Permill> for DynamicAssetFee<Fees> {
+	fn get(k: &K) -> Permill {
+		Fees::get(k).0
+	}
+}
+
+/// Provides asset fee of dynamic fees as a fraction, as used by XYK.
+pub struct DynamicAssetFeeFraction<Fees>(PhantomData<Fees>);
+
+impl<K, Fees: GetByKey<K, (Permill, Permill)>> GetByKey<K, (u32, u32)> for DynamicAssetFeeFraction<Fees> {
+	fn get(k: &K) -> (u32, u32) {
+		(Fees::get(k).0.deconstruct(), Permill::one().deconstruct())
+	}
+}
+
 pub struct VestingInfo<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> pallet_staking::traits::VestingDetails<AccountId, Balance> for VestingInfo<Runtime>
@@ -148,6 +148,12 @@ parameter_type_with_key! {
 	};
 }
 
+parameter_type_with_key! {
+	pub XYKDynamicFee: |_pool: AccountId| -> (u32, u32) {
+		(0, 1)
+	};
+}
+
 impl orml_tokens::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -165,6 +171,7 @@ impl orml_tokens::Config for Test {
 parameter_types! {
 	pub const HDXAssetId: AssetId = HDX;
 	pub const LRNAAssetId: AssetId = LRNA;
+	pub const TreasuryAccount: AccountId = 100;
 	pub const PosiitionCollectionId: u32= 1000;
 
 	pub ProtocolFee: Permill = PROTOCOL_FEE.with(|v| *v.borrow());
@@ -187,6 +194,8 @@ impl pallet_omnipool::Config for Test {
 	type Currency = Currencies;
 	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type HubAssetId = LRNAAssetId;
+	type ImbalanceBuybackAccount = TreasuryAccount;
+	type ImbalanceBuybackAssetId = HDXAssetId;
 	type Fee = FeeProvider;
 	type WeightInfo = ();
 	type HdxAssetId = HDXAssetId;
@@ -230,6 +239,8 @@ parameter_types! {
 
 	pub MinimumWithdrawalFee: Permill = Permill::from_rational(1u32,10000);
 	pub XYKExchangeFee: (u32, u32) = (3, 1_000);
+	pub const XYKMinPoolFee: (u32, u32) = (1, 1_000);
+	pub const XYKMaxPoolFee: (u32, u32) = (10, 1_000);
 	pub const DiscountedFee: (u32, u32) = (7, 10_000);
 }
 
@@ -241,6 +252,11 @@ impl pallet_xyk::Config for Test {
 	type NativeAssetId = HDXAssetId;
 	type WeightInfo = ();
 	type GetExchangeFee = XYKExchangeFee;
+	type MinPoolFee = XYKMinPoolFee;
+	type MaxPoolFee = XYKMaxPoolFee;
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
+	type DynamicFee = XYKDynamicFee;
+	type QuoteDynamicFee = XYKDynamicFee;
 	type MinTradingLimit = MinTradingLimit;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MaxInRatio = MaxInRatio;
@@ -322,6 +338,7 @@ impl pallet_route_executor::Config for Test {
 	type NativeAssetId = NativeCurrencyId;
 	type Currency = FungibleCurrencies<Test>;
 	type AMM = Pools;
+	type Pools = ();
 	type WeightInfo = ();
 }
 
@@ -19,8 +19,9 @@ use super::*;
 use crate::system::NativeAssetId;
 
 use hydradx_adapters::{
-	AssetFeeOraclePriceProvider, EmaOraclePriceAdapter, FreezableNFT, MultiCurrencyLockedBalance, OmnipoolHookAdapter,
-	OracleAssetVolumeProvider, PriceAdjustmentAdapter, StableswapHooksAdapter, VestingInfo,
+	AssetFeeOraclePriceProvider, DynamicAssetFee, DynamicAssetFeeFraction, EmaOraclePriceAdapter, FreezableNFT,
+	MultiCurrencyLockedBalance, OmnipoolHookAdapter, OracleAssetVolumeProvider, OracleStableswapPoolVolumeProvider,
+	OracleXYKPoolVolumeProvider, PriceAdjustmentAdapter, StableswapHooksAdapter, VestingInfo,
 };
 
 use hydradx_adapters::{RelayChainBlockHashProvider, RelayChainBlockNumberProvider};
@@ -35,7 +36,7 @@ use pallet_omnipool::{
 use pallet_otc::NamedReserveIdentifier;
 use pallet_stableswap::weights::WeightInfo as StableswapWeights;
 use pallet_transaction_multi_payment::{AddTxAssetOnAccount, RemoveTxAssetOnKilled};
-use primitives::constants::chain::XYK_SOURCE;
+use primitives::constants::chain::{STABLESWAP_SOURCE, XYK_SOURCE};
 use primitives::constants::time::DAYS;
 use primitives::constants::{
 	chain::OMNIPOOL_SOURCE,
@@ -61,7 +62,7 @@ use pallet_route_executor::{weights::WeightInfo as RouterWeights, AmmTradeWeight
 use pallet_staking::types::Action;
 use pallet_staking::SigmoidPercentage;
 use pallet_xyk::weights::WeightInfo as XykWeights;
-use sp_runtime::{DispatchError, FixedPointNumber};
+use sp_runtime::{transaction_validity::TransactionPriority, DispatchError, FixedPointNumber};
 use sp_std::num::NonZeroU16;
 
 parameter_types! {
@@ -238,6 +239,8 @@ impl pallet_omnipool::Config for Runtime {
 	type AssetRegistry = AssetRegistry;
 	type HdxAssetId = NativeAssetId;
 	type HubAssetId = LRNA;
+	type ImbalanceBuybackAccount = TreasuryAccount;
+	type ImbalanceBuybackAssetId = NativeAssetId;
 	type MinWithdrawalFee = MinimumWithdrawalFee;
 	type MinimumTradingLimit = MinTradingLimit;
 	type MinimumPoolLiquidity = MinPoolLiquidity;
@@ -300,9 +303,22 @@ parameter_types! {
 		OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::TenMinutes]);
 }
 
+/// Omnipool, Stableswap and XYK oracles are always tracked.
+/// XYK oracles are used by DCA price checks and by XYK dynamic fees.
+/// Oracles of other sources have to be added by `SuperMajorityTechCommittee`.
+pub struct OracleWhitelist;
+
+impl Contains<(Source, (AssetId, AssetId))> for OracleWhitelist {
+	fn contains(t: &(Source, (AssetId, AssetId))) -> bool {
+		t.0 == OMNIPOOL_SOURCE || t.0 == STABLESWAP_SOURCE || t.0 == XYK_SOURCE
+	}
+}
+
 impl pallet_ema_oracle::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::ema_oracle::HydraWeight<Runtime>;
+	type AuthorityOrigin = SuperMajorityTechCommittee;
+	type OracleWhitelist = OracleWhitelist;
 	/// The definition of the oracle time periods currently assumes a 6 second block time.
 	/// We use the parachain blocks anyway, because we want certain guarantees over how many blocks correspond
 	/// to which smoothing factor.
@@ -312,6 +328,8 @@ impl pallet_ema_oracle::Config for Runtime {
 	/// 40 seems a decent upper bound for the forseeable future.
 	///
 	type MaxUniqueEntries = ConstU32<40>;
+	/// Snapshots are taken only in blocks with activity, so 300 snapshots cover at least the last hour.
+	type MaxPriceSnapshots = ConstU32<300>;
 }
 
 pub struct DustRemovalWhitelist;
@@ -458,7 +476,7 @@ parameter_types! {
 	pub NamedReserveId: NamedReserveIdentifier = *b"dcaorder";
 	pub MaxNumberOfRetriesOnError: u8 = 3;
 	pub DCAOraclePeriod: OraclePeriod = OraclePeriod::Short;
-
+	pub const DCAHistoryRetentionPeriod: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_dca::Config for Runtime {
@@ -485,6 +503,7 @@ impl pallet_dca::Config for Runtime {
 	type MinimumTradingLimit = MinTradingLimit;
 	type FeeReceiver = TreasuryAccount;
 	type NamedReserveId = NamedReserveId;
+	type HistoryRetentionPeriod = DCAHistoryRetentionPeriod;
 	type WeightToFee = WeightToFee;
 	type AmmTradeWeights = RouterWeightInfo;
 	type WeightInfo = weights::dca::HydraWeight<Runtime>;
@@ -559,6 +578,17 @@ impl RouterWeightInfo {
 			number_of_times_execute_sell_amounts_executed,
 		))
 	}
+
+	// Trades of Omnipool assets moved to subpools are executed partly in Stableswap.
+	pub fn subpool_trades_sell_weight(trade: &Trade<AssetId>, c: u32, e: u32) -> Weight {
+		weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e)
+			.saturating_mul(OmnipoolSubpools::subpool_hops(trade.asset_in, trade.asset_out))
+	}
+
+	pub fn subpool_trades_buy_weight(trade: &Trade<AssetId>, c: u32, e: u32) -> Weight {
+		weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e)
+			.saturating_mul(OmnipoolSubpools::subpool_hops(trade.asset_in, trade.asset_out))
+	}
 }
 
 impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
@@ -573,6 +603,7 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 
 			let amm_weight = match trade.pool {
 				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_sell(c, e)
+					.saturating_add(Self::subpool_trades_sell_weight(trade, c, e))
 					.saturating_add(
 						<OmnipoolHookAdapter<RuntimeOrigin, NativeAssetId, LRNA, Runtime> as OmnipoolHooks<
 							RuntimeOrigin,
@@ -611,6 +642,7 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 
 			let amm_weight = match trade.pool {
 				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_buy(c, e)
+					.saturating_add(Self::subpool_trades_buy_weight(trade, c, e))
 					.saturating_add(
 						<OmnipoolHookAdapter<RuntimeOrigin, NativeAssetId, LRNA, Runtime> as OmnipoolHooks<
 							RuntimeOrigin,
@@ -638,6 +670,29 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 		weight
 	}
 
+	// Used in the router precompile quoteSell, which calls Router::calculate_sell_trade_amounts
+	fn calculate_sell_trade_amounts_weight(route: &[Trade<AssetId>]) -> Weight {
+		let mut weight = Weight::zero();
+		let c = 1; // number of times AMM::calculate_sell is executed
+		let e = 0; // number of times AMM::execute_sell is executed
+
+		for trade in route {
+			weight.saturating_accrue(Self::sell_and_calculate_sell_trade_amounts_overhead_weight(1, 0));
+
+			let amm_weight = match trade.pool {
+				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_sell(c, e)
+					.saturating_add(Self::subpool_trades_sell_weight(trade, c, e)),
+				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_sell(c, e),
+				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e),
+				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
+					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+			};
+			weight.saturating_accrue(amm_weight);
+		}
+
+		weight
+	}
+
 	// Used in DCA::schedule extrinsic, which calls Router::calculate_buy_trade_amounts
 	fn calculate_buy_trade_amounts_weight(route: &[Trade<AssetId>]) -> Weight {
 		let mut weight = Weight::zero();
@@ -648,7 +703,8 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 			weight.saturating_accrue(Self::buy_and_calculate_buy_trade_amounts_overhead_weight(1, 0));
 
 			let amm_weight = match trade.pool {
-				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_buy(c, e),
+				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_buy(c, e)
+					.saturating_add(Self::subpool_trades_buy_weight(trade, c, e)),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
@@ -670,7 +726,8 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 			weight.saturating_accrue(Self::sell_and_calculate_sell_trade_amounts_overhead_weight(1, 1));
 
 			let amm_weight = match trade.pool {
-				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_sell(c, e),
+				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_sell(c, e)
+					.saturating_add(Self::subpool_trades_sell_weight(trade, c, e)),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_sell(c, e),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
@@ -692,7 +749,8 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 			weight.saturating_accrue(Self::buy_and_calculate_buy_trade_amounts_overhead_weight(2, 1));
 
 			let amm_weight = match trade.pool {
-				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_buy(c, e),
+				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_buy(c, e)
+					.saturating_add(Self::subpool_trades_buy_weight(trade, c, e)),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
@@ -723,7 +781,8 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 		//Calculate sell amounts for the new route
 		for trade in route {
 			let amm_weight = match trade.pool {
-				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+					.saturating_add(Self::subpool_trades_sell_weight(trade, 1, 0)),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
@@ -734,7 +793,8 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 		//Calculate sell amounts for the inversed new route
 		for trade in inverse_route(route.to_vec()) {
 			let amm_weight = match trade.pool {
-				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Omnipool => weights::omnipool::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+					.saturating_add(Self::subpool_trades_sell_weight(&trade, 1, 0)),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
@@ -752,7 +812,8 @@ impl pallet_route_executor::Config for Runtime {
 	type Balance = Balance;
 	type Currency = FungibleCurrencies<Runtime>;
 	type WeightInfo = RouterWeightInfo;
-	type AMM = (Omnipool, Stableswap, XYK, LBP);
+	type AMM = (OmnipoolSubpools, Stableswap, XYK, LBP);
+	type Pools = (OmnipoolSubpools, Stableswap, XYK, LBP);
 	type NativeAssetId = NativeAssetId;
 }
 
@@ -770,6 +831,29 @@ impl pallet_otc::Config for Runtime {
 	type WeightInfo = weights::otc::HydraWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const OtcSettlementsPalletId: PalletId = PalletId(*b"otcsettl");
+	pub const OtcSettlementsMinProfitPercentage: Permill = Permill::from_rational(1u32, 1000u32);
+	pub const MaxOtcSettlementsPerBlock: u32 = 5;
+	pub const MaxOtcOrdersCheckedPerBlock: u32 = 20;
+	pub const OtcSettlementsUnsignedPriority: TransactionPriority = TransactionPriority::max_value() / 2;
+}
+
+impl pallet_otc_settlements::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = FungibleCurrencies<Runtime>;
+	type Router = Router;
+	type RouteProvider = Router;
+	type ProfitReceiver = TreasuryAccount;
+	type MinProfitPercentage = OtcSettlementsMinProfitPercentage;
+	type MaxSettlementsPerBlock = MaxOtcSettlementsPerBlock;
+	type MaxOrdersCheckedPerBlock = MaxOtcOrdersCheckedPerBlock;
+	type PalletId = OtcSettlementsPalletId;
+	type UnsignedPriority = OtcSettlementsUnsignedPriority;
+	type WeightInfo = weights::otc_settlements::HydraWeight<Runtime>;
+	type AmmTradeWeights = RouterWeightInfo;
+}
+
 // Dynamic fees
 parameter_types! {
 	pub AssetFeeParams: FeeParams<Permill> = FeeParams{
@@ -797,6 +881,58 @@ impl pallet_dynamic_fees::Config for Runtime {
 	type Oracle = OracleAssetVolumeProvider<Runtime, LRNA, DynamicFeesOraclePeriod>;
 	type AssetFeeParameters = AssetFeeParams;
 	type ProtocolFeeParameters = ProtocolFeeParams;
+	type AuthorityOrigin = SuperMajorityTechCommittee;
+	type WeightInfo = weights::dynamic_fees::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub XYKPoolFeeParams: FeeParams<Permill> = FeeParams{
+		min_fee: Permill::from_rational(1u32,1000u32),
+		max_fee: Permill::from_percent(1),
+		decay: FixedU128::from_rational(5,1000000),
+		amplification: FixedU128::one(),
+	};
+
+	pub StableswapPoolFeeParams: FeeParams<Permill> = FeeParams{
+		min_fee: Permill::zero(),
+		max_fee: Permill::from_rational(5u32,1000u32),
+		decay: FixedU128::from_rational(5,1000000),
+		amplification: FixedU128::one(),
+	};
+
+	// Protocol fee is used by Omnipool only
+	pub PoolProtocolFeeParams: FeeParams<Permill> = FeeParams{
+		min_fee: Permill::zero(),
+		max_fee: Permill::zero(),
+		decay: FixedU128::from_rational(5,1000000),
+		amplification: FixedU128::one(),
+	};
+}
+
+pub type XYKDynamicFeesInstance = pallet_dynamic_fees::Instance1;
+impl pallet_dynamic_fees::Config<XYKDynamicFeesInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BlockNumberProvider = System;
+	type Fee = Permill;
+	type AssetId = AccountId;
+	type Oracle = OracleXYKPoolVolumeProvider<Runtime, DynamicFeesOraclePeriod>;
+	type AssetFeeParameters = XYKPoolFeeParams;
+	type ProtocolFeeParameters = PoolProtocolFeeParams;
+	type AuthorityOrigin = SuperMajorityTechCommittee;
+	type WeightInfo = weights::dynamic_fees::HydraWeight<Runtime>;
+}
+
+pub type StableswapDynamicFeesInstance = pallet_dynamic_fees::Instance2;
+impl pallet_dynamic_fees::Config<StableswapDynamicFeesInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type BlockNumberProvider = System;
+	type Fee = Permill;
+	type AssetId = AssetId;
+	type Oracle = OracleStableswapPoolVolumeProvider<Runtime, DynamicFeesOraclePeriod>;
+	type AssetFeeParameters = StableswapPoolFeeParams;
+	type ProtocolFeeParameters = PoolProtocolFeeParams;
+	type AuthorityOrigin = SuperMajorityTechCommittee;
+	type WeightInfo = weights::dynamic_fees::HydraWeight<Runtime>;
 }
 
 // Stableswap
@@ -872,6 +1008,9 @@ impl pallet_stableswap::Config for Runtime {
 	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type DustAccountHandler = Duster;
 	type Hooks = StableswapHooksAdapter<Runtime>;
+	type DynamicFee =
+		DynamicAssetFee<pallet_dynamic_fees::UpdateAndRetrieveFees<Runtime, StableswapDynamicFeesInstance>>;
+	type QuoteDynamicFee = DynamicAssetFee<pallet_dynamic_fees::RetrieveFees<Runtime, StableswapDynamicFeesInstance>>;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MinTradingLimit = MinTradingLimit;
 	type AmplificationRange = StableswapAmplificationRange;
@@ -880,6 +1019,12 @@ impl pallet_stableswap::Config for Runtime {
 	type BenchmarkHelper = RegisterAsset<Runtime>;
 }
 
+impl pallet_omnipool_subpools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = weights::omnipool_subpools::HydraWeight<Runtime>;
+}
+
 // Bonds
 parameter_types! {
 	pub ProtocolFee: Permill = Permill::from_percent(2);
@@ -999,6 +1144,8 @@ impl pallet_lbp::Config for Runtime {
 
 parameter_types! {
 	pub XYKExchangeFee: (u32, u32) = (3, 1_000);
+	pub const XYKMinPoolFee: (u32, u32) = (1, 1_000);
+	pub const XYKMaxPoolFee: (u32, u32) = (10, 1_000);
 	pub const DiscountedFee: (u32, u32) = (7, 10_000);
 	pub const XYKOracleSourceIdentifier: Source = XYK_SOURCE;
 }
@@ -1011,6 +1158,12 @@ impl pallet_xyk::Config for Runtime {
 	type NativeAssetId = NativeAssetId;
 	type WeightInfo = weights::xyk::HydraWeight<Runtime>;
 	type GetExchangeFee = XYKExchangeFee;
+	type MinPoolFee = XYKMinPoolFee;
+	type MaxPoolFee = XYKMaxPoolFee;
+	type AuthorityOrigin = SuperMajorityTechCommittee;
+	type DynamicFee =
+		DynamicAssetFeeFraction<pallet_dynamic_fees::UpdateAndRetrieveFees<Runtime, XYKDynamicFeesInstance>>;
+	type QuoteDynamicFee = DynamicAssetFeeFraction<pallet_dynamic_fees::RetrieveFees<Runtime, XYKDynamicFeesInstance>>;
 	type MinTradingLimit = MinTradingLimit;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MaxInRatio = MaxInRatio;
@@ -1022,6 +1175,75 @@ impl pallet_xyk::Config for Runtime {
 	type OracleSource = XYKOracleSourceIdentifier;
 }
 
+parameter_types! {
+	pub const StableswapWarehouseLMPalletId: PalletId = PalletId(*b"SSwapWLM");
+	pub const StableswapLMPalletId: PalletId = PalletId(*b"SSwap/LM");
+	pub const StableswapLMCollectionId: CollectionId = 2585_u128;
+}
+
+type StableswapLiquidityMiningInstance = warehouse_liquidity_mining::Instance2;
+impl warehouse_liquidity_mining::Config<StableswapLiquidityMiningInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type MultiCurrency = Currencies;
+	type PalletId = StableswapWarehouseLMPalletId;
+	type MinTotalFarmRewards = MinTotalFarmRewards;
+	type MinPlannedYieldingPeriods = MinPlannedYieldingPeriods;
+	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type AmmPoolId = AssetId;
+	type MaxFarmEntriesPerDeposit = MaxEntriesPerDeposit;
+	type MaxYieldFarmsPerGlobalFarm = MaxYieldFarmsPerGlobalFarm;
+	type AssetRegistry = AssetRegistry;
+	type NonDustableWhitelistHandler = Duster;
+	type PriceAdjustment = warehouse_liquidity_mining::DefaultPriceAdjustment;
+}
+
+impl pallet_stableswap_liquidity_mining::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type CreateOrigin = AllTechnicalCommitteeMembers;
+	type PalletId = StableswapLMPalletId;
+	type NFTCollectionId = StableswapLMCollectionId;
+	type NFTHandler = Uniques;
+	type LiquidityMiningHandler = StableswapWarehouseLM;
+	type WeightInfo = weights::stableswap_lm::HydraWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const XYKWarehouseLMPalletId: PalletId = PalletId(*b"XYK//WLM");
+	pub const XYKLMPalletId: PalletId = PalletId(*b"XYK///LM");
+	pub const XYKLMCollectionId: CollectionId = 2586_u128;
+}
+
+type XYKLiquidityMiningInstance = warehouse_liquidity_mining::Instance3;
+impl warehouse_liquidity_mining::Config<XYKLiquidityMiningInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type MultiCurrency = Currencies;
+	type PalletId = XYKWarehouseLMPalletId;
+	type MinTotalFarmRewards = MinTotalFarmRewards;
+	type MinPlannedYieldingPeriods = MinPlannedYieldingPeriods;
+	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type AmmPoolId = AccountId;
+	type MaxFarmEntriesPerDeposit = MaxEntriesPerDeposit;
+	type MaxYieldFarmsPerGlobalFarm = MaxYieldFarmsPerGlobalFarm;
+	type AssetRegistry = AssetRegistry;
+	type NonDustableWhitelistHandler = Duster;
+	type PriceAdjustment = warehouse_liquidity_mining::DefaultPriceAdjustment;
+}
+
+impl pallet_xyk_liquidity_mining::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Currencies;
+	type CreateOrigin = AllTechnicalCommitteeMembers;
+	type PalletId = XYKLMPalletId;
+	type NFTCollectionId = XYKLMCollectionId;
+	type NFTHandler = Uniques;
+	type LiquidityMiningHandler = XYKWarehouseLM;
+	type AMM = XYK;
+	type WeightInfo = weights::xyk_lm::HydraWeight<Runtime>;
+}
+
 parameter_types! {
 	pub const ReferralsPalletId: PalletId = PalletId(*b"referral");
 	pub RegistrationFee: (AssetId,Balance, AccountId)= (NativeAssetId::get(), 222_000_000_000_000, TreasuryAccount::get());
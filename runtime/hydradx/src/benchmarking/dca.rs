@@ -35,8 +35,10 @@ use hydradx_traits::router::AssetPair;
 use hydradx_traits::router::PoolType;
 use orml_benchmarking::runtime_benchmarks;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended, NamedMultiReservableCurrency};
-use pallet_dca::types::{Order, Schedule, ScheduleId};
-use pallet_dca::{ScheduleIdsPerBlock, Schedules};
+use pallet_dca::types::{FeeCurrency, Order, Schedule, ScheduleId};
+use pallet_dca::{
+	ApprovedSponsors, FeeCurrencies, PausedSchedules, RemainingAmounts, ScheduleIdsPerBlock, Schedules, Sponsorships,
+};
 use pallet_route_executor::Trade;
 use pallet_route_executor::MAX_NUMBER_OF_TRADES;
 use scale_info::prelude::vec::Vec;
@@ -389,6 +391,168 @@ runtime_benchmarks! {
 		assert!(<Schedules<Runtime>>::get::<ScheduleId>(schedule_id).is_none());
 	}
 
+	pause {
+		let caller: AccountId = create_account_with_native_balance()?;
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+
+	}: _(RawOrigin::Signed(caller), schedule_id, None)
+	verify {
+		assert!(<PausedSchedules<Runtime>>::get::<ScheduleId>(schedule_id).is_some());
+	}
+
+	resume {
+		let caller: AccountId = create_account_with_native_balance()?;
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+		assert_ok!(DCA::pause(RawOrigin::Signed(caller.clone()).into(), schedule_id, None));
+
+		//We fill the block with schedules so the search for free block is triggered
+		let schedule_2 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		for _ in 0..MaxSchedulesPerBlock::get() {
+			assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule_2.clone(), Option::Some(execution_block)));
+		}
+
+	}: _(RawOrigin::Signed(caller), schedule_id, Option::Some(execution_block))
+	verify {
+		assert!(<PausedSchedules<Runtime>>::get::<ScheduleId>(schedule_id).is_none());
+	}
+
+	update_schedule {
+		let caller: AccountId = create_account_with_native_balance()?;
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+
+	}: _(RawOrigin::Signed(caller), schedule_id, Some(10u32), Some(Permill::from_percent(10)), Some(Permill::from_percent(10)), Some(5))
+	verify {
+		assert_eq!(<Schedules<Runtime>>::get::<ScheduleId>(schedule_id).unwrap().period, 10u32);
+	}
+
+	top_up {
+		let caller: AccountId = create_account_with_native_balance()?;
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let total_amount = schedule1.total_amount;
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+
+	}: _(RawOrigin::Signed(caller), schedule_id, ONE)
+	verify {
+		assert_eq!(<RemainingAmounts<Runtime>>::get::<ScheduleId>(schedule_id), Some(total_amount + ONE));
+	}
+
+	set_fee_currency {
+		let caller: AccountId = create_account_with_native_balance()?;
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+
+	}: _(RawOrigin::Signed(caller), schedule_id, FeeCurrency::BoughtAsset)
+	verify {
+		assert_eq!(<FeeCurrencies<Runtime>>::get::<ScheduleId>(schedule_id), FeeCurrency::BoughtAsset);
+	}
+
+	sponsor_fees {
+		let caller: AccountId = create_account_with_native_balance()?;
+		let sponsor: AccountId = funded_account("sponsor", 2, &[HDX]);
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+		assert_ok!(DCA::approve_sponsor(RawOrigin::Signed(caller).into(), schedule_id, Some(sponsor.clone())));
+
+	}: _(RawOrigin::Signed(sponsor), schedule_id, ONE)
+	verify {
+		assert_eq!(<Sponsorships<Runtime>>::get::<ScheduleId>(schedule_id).unwrap().remaining, ONE);
+	}
+
+	withdraw_sponsorship {
+		let caller: AccountId = create_account_with_native_balance()?;
+		let sponsor: AccountId = funded_account("sponsor", 2, &[HDX]);
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+		assert_ok!(DCA::approve_sponsor(RawOrigin::Signed(caller).into(), schedule_id, Some(sponsor.clone())));
+		assert_ok!(DCA::sponsor_fees(RawOrigin::Signed(sponsor.clone()).into(), schedule_id, ONE));
+
+	}: _(RawOrigin::Signed(sponsor), schedule_id)
+	verify {
+		assert!(<Sponsorships<Runtime>>::get::<ScheduleId>(schedule_id).is_none());
+	}
+
+	approve_sponsor {
+		let caller: AccountId = create_account_with_native_balance()?;
+		let sponsor: AccountId = funded_account("sponsor", 2, &[HDX]);
+		let new_sponsor: AccountId = funded_account("new_sponsor", 3, &[HDX]);
+
+		<Currencies as MultiCurrencyExtended<AccountId>>::update_balance(HDX, &caller, 100_000_000_000_000_000i128)?;
+
+		let amount_sell = 200 * ONE;
+		let schedule1 = schedule_fake(caller.clone(), HDX, DAI, amount_sell);
+		let schedule_id : ScheduleId = 0;
+
+		set_period(99);
+		let execution_block = 100u32;
+		assert_ok!(DCA::schedule(RawOrigin::Signed(caller.clone()).into(), schedule1, Option::Some(execution_block)));
+		assert_ok!(DCA::approve_sponsor(RawOrigin::Signed(caller.clone()).into(), schedule_id, Some(sponsor.clone())));
+		assert_ok!(DCA::sponsor_fees(RawOrigin::Signed(sponsor).into(), schedule_id, ONE));
+
+	}: _(RawOrigin::Signed(caller), schedule_id, Some(new_sponsor.clone()))
+	verify {
+		assert_eq!(<ApprovedSponsors<Runtime>>::get::<ScheduleId>(schedule_id), Some(new_sponsor));
+		assert!(<Sponsorships<Runtime>>::get::<ScheduleId>(schedule_id).is_none());
+	}
+
 }
 
 pub const INITIAL_BALANCE: Balance = 10_000_000 * ONE;
@@ -429,6 +593,7 @@ fn create_xyk_pool(asset_a: u32, asset_b: u32) {
 		amount,
 		asset_b,
 		amount,
+		None,
 	));
 
 	assert_ok!(XYK::sell(
@@ -5,9 +5,13 @@ pub mod dca;
 pub mod duster;
 pub mod multi_payment;
 pub mod omnipool;
+pub mod omnipool_subpools;
+pub mod otc_settlements;
 pub mod route_executor;
+pub mod stableswap_lm;
 pub mod tokens;
 pub mod vesting;
+pub mod xyk_lm;
 
 use crate::AssetRegistry;
 use frame_system::RawOrigin;
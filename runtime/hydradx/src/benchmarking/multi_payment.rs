@@ -180,6 +180,7 @@ where
 		amount_a,
 		asset_b,
 		amount_b,
+		None,
 	));
 }
 
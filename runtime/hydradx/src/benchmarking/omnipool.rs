@@ -1,5 +1,6 @@
 use crate::{
 	AccountId, AssetId, AssetRegistry, Balance, EmaOracle, Omnipool, Referrals, Runtime, RuntimeOrigin, System,
+	TreasuryAccount,
 };
 
 use super::*;
@@ -12,7 +13,8 @@ use frame_support::{
 		traits::{One, SaturatedConversion, Zero},
 		FixedU128, Permill,
 	},
-	traits::{OnFinalize, OnInitialize},
+	traits::{OnFinalize, OnIdle, OnInitialize},
+	weights::Weight,
 };
 use frame_system::RawOrigin;
 use hydradx_traits::{
@@ -49,6 +51,7 @@ fn run_to_block(to: u32) {
 }
 
 const HDX: AssetId = 0;
+const LRNA: AssetId = 1;
 const DAI: AssetId = 2;
 
 fn init() -> DispatchResult {
@@ -331,6 +334,72 @@ runtime_benchmarks! {
 		assert!(Omnipool::positions(current_position_id).is_none());
 	}
 
+	merge_positions {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		run_to_block(10);
+		let position_id = Omnipool::next_position_id();
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+		let other_position_id = Omnipool::next_position_id();
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+	}: { Omnipool::merge_positions(RawOrigin::Signed(lp_provider).into(), position_id, other_position_id)? }
+	verify {
+		assert!(Omnipool::positions(other_position_id).is_none());
+		assert_eq!(Omnipool::positions(position_id).unwrap().amount, 2 * liquidity_added);
+	}
+
+	split_position {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		run_to_block(10);
+		let position_id = Omnipool::next_position_id();
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+		let new_position_id = Omnipool::next_position_id();
+
+	}: { Omnipool::split_position(RawOrigin::Signed(lp_provider).into(), position_id, liquidity_added / 2)? }
+	verify {
+		assert!(Omnipool::positions(new_position_id).is_some());
+	}
+
 	set_asset_weight_cap {
 		init()?;
 	}: { Omnipool::set_asset_weight_cap(RawOrigin::Root.into(), DAI, Permill::from_percent(10))? }
@@ -339,6 +408,38 @@ runtime_benchmarks! {
 		assert!(asset_state.cap == 100_000_000_000_000_000u128);
 	}
 
+	set_tvl_cap {
+		init()?;
+	}: { Omnipool::set_tvl_cap(RawOrigin::Root.into(), DAI, 1_000_000_000_000_000u128)? }
+	verify {
+		assert_eq!(Omnipool::tvl_cap(), Some((DAI, 1_000_000_000_000_000u128)));
+	}
+
+	set_imbalance_buyback_budget {
+		init()?;
+	}: { Omnipool::set_imbalance_buyback_budget(RawOrigin::Root.into(), 1_000_000_000_000u128)? }
+	verify {
+		assert_eq!(Omnipool::imbalance_buyback_budget(), 1_000_000_000_000u128);
+	}
+
+	buy_back_hub_asset {
+		init()?;
+
+		// Selling hub asset to the pool creates negative imbalance
+		let seller: AccountId = account("seller", 3, 1);
+		update_balance(LRNA, &seller, 100_000_000_000_000u128);
+		Omnipool::sell(RawOrigin::Signed(seller).into(), LRNA, DAI, 10_000_000_000_000u128, Balance::zero())?;
+
+		update_balance(HDX, &TreasuryAccount::get(), 1_000_000_000_000_000u128);
+		Omnipool::set_imbalance_buyback_budget(RawOrigin::Root.into(), 1_000_000_000_000u128)?;
+
+		let imbalance = Omnipool::current_imbalance();
+		let block_number = System::block_number();
+	}: { Omnipool::on_idle(block_number, Weight::MAX) }
+	verify {
+		assert!(Omnipool::current_imbalance().value < imbalance.value);
+	}
+
 	withdraw_protocol_liquidity {
 		init()?;
 		let acc = Omnipool::protocol_account();
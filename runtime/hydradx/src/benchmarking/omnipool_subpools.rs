@@ -0,0 +1,116 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use crate::{AccountId, AssetId, Balance, Currencies, Omnipool, OmnipoolSubpools, RegisterAsset, Runtime};
+
+use frame_benchmarking::account;
+use frame_support::assert_ok;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrencyExtended;
+use pallet_stableswap::BenchmarkHelper;
+use primitives::constants::currency::UNITS;
+use sp_runtime::{DispatchError, FixedU128, Permill};
+use sp_std::vec::Vec;
+
+const SHARE_ASSET: AssetId = 200;
+const MAX_ASSETS_IN_POOL: u32 = 5;
+
+fn update_balance(asset_id: AssetId, who: &AccountId, amount: Balance) {
+	assert_ok!(<Currencies as MultiCurrencyExtended<_>>::update_balance(
+		asset_id,
+		who,
+		amount as i128,
+	));
+}
+
+/// Registers `n` stablecoins and the share asset and adds the stablecoins to Omnipool, all provided by `lp`.
+fn init_omnipool(n: u32, lp: AccountId) -> Result<Vec<AssetId>, DispatchError> {
+	let acc = Omnipool::protocol_account();
+
+	let assets: Vec<AssetId> = (0..n).map(|idx| 100 + idx).collect();
+	for asset_id in assets.iter() {
+		RegisterAsset::<Runtime>::register_asset(*asset_id, 12)?;
+		update_balance(*asset_id, &acc, 1_000_000 * UNITS);
+
+		Omnipool::add_token(
+			RawOrigin::Root.into(),
+			*asset_id,
+			FixedU128::from((1, 2)),
+			Permill::from_percent(100),
+			lp.clone(),
+		)?;
+	}
+	RegisterAsset::<Runtime>::register_asset(SHARE_ASSET, 18)?;
+
+	Ok(assets)
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_omnipool_subpools}
+
+	create_subpool {
+		let n in 2..MAX_ASSETS_IN_POOL;
+
+		let lp: AccountId = account("lp", 0, 1);
+		let assets = init_omnipool(n, lp)?;
+	}: _(RawOrigin::Root, SHARE_ASSET, assets.clone(), 100, Permill::zero(), Permill::from_percent(100))
+	verify {
+		assert!(Omnipool::exists(SHARE_ASSET));
+		for asset_id in assets {
+			assert_eq!(OmnipoolSubpools::subpool_of(asset_id), Some(SHARE_ASSET));
+		}
+	}
+
+	migrate_position {
+		let lp: AccountId = account("lp", 0, 1);
+		let position_id = Omnipool::next_position_id();
+		let assets = init_omnipool(2, lp.clone())?;
+
+		OmnipoolSubpools::create_subpool(RawOrigin::Root.into(), SHARE_ASSET, assets, 100, Permill::zero(), Permill::from_percent(100))?;
+	}: _(RawOrigin::Signed(lp), position_id)
+	verify {
+		assert_eq!(Omnipool::positions(position_id).map(|position| position.asset_id), Some(SHARE_ASSET));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use frame_support::traits::GenesisBuild;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<Runtime> {
+			registered_assets: vec![(b"LRNA".to_vec(), 1_000u128, Some(1))],
+			native_asset_name: b"HDX".to_vec(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
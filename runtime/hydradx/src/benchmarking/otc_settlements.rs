@@ -0,0 +1,135 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use crate::{
+	AccountId, AssetId, AssetRegistry, Balance, Currencies, OtcSettlements, Router, Runtime, RuntimeOrigin,
+	TreasuryAccount, OTC, XYK,
+};
+
+use frame_benchmarking::account;
+use frame_support::assert_ok;
+use frame_support::sp_runtime::traits::One;
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use hydradx_traits::router::{AssetPair, PoolType, RouterT, Trade};
+use hydradx_traits::Registry;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use primitives::constants::currency::UNITS;
+use sp_std::vec;
+
+pub const INITIAL_BALANCE: Balance = 10_000_000 * UNITS;
+
+fn funded_account(name: &'static str, index: u32, assets: &[AssetId]) -> AccountId {
+	let account: AccountId = account(name, index, 0);
+	for asset in assets {
+		assert_ok!(<Currencies as MultiCurrencyExtended<_>>::update_balance(
+			*asset,
+			&account,
+			INITIAL_BALANCE.try_into().unwrap(),
+		));
+	}
+	account
+}
+
+fn create_xyk_pool(caller: AccountId, asset_a: AssetId, asset_b: AssetId) {
+	let amount = 100_000 * UNITS;
+
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(caller),
+		asset_a,
+		amount,
+		asset_b,
+		amount,
+		None,
+	));
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_otc_settlements}
+
+	settle_otc_order {
+		let asset_in = AssetRegistry::create_asset(&b"FCA".to_vec(), Balance::one())?;
+		let asset_out = AssetRegistry::create_asset(&b"FCB".to_vec(), Balance::one())?;
+
+		let caller: AccountId = funded_account("caller", 0, &[asset_in, asset_out]);
+		let owner: AccountId = funded_account("owner", 1, &[asset_in, asset_out]);
+
+		create_xyk_pool(caller.clone(), asset_in, asset_out);
+
+		let route = vec![Trade {
+			pool: PoolType::XYK,
+			asset_in: asset_out,
+			asset_out: asset_in,
+		}];
+		Router::set_route(
+			RawOrigin::Signed(caller).into(),
+			AssetPair::new(asset_out, asset_in),
+			route,
+		)?;
+
+		// the order is priced well below the pool price
+		assert_ok!(OTC::place_order(
+			RawOrigin::Signed(owner).into(),
+			asset_in,
+			asset_out,
+			100 * UNITS,
+			200 * UNITS,
+			false,
+			None,
+			None,
+			None,
+		));
+
+		let treasury_balance_before = <Currencies as MultiCurrency<_>>::free_balance(asset_in, &TreasuryAccount::get());
+	}: _(RawOrigin::None, 0u32)
+	verify {
+		assert!(OTC::orders(0u32).is_none());
+		assert!(<Currencies as MultiCurrency<_>>::free_balance(asset_in, &TreasuryAccount::get()) > treasury_balance_before);
+		assert_eq!(OtcSettlements::settlements_in_block(), 1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use frame_support::traits::GenesisBuild;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<Runtime> {
+			registered_assets: vec![
+				(b"LRNA".to_vec(), 1_000u128, Some(1)),
+				(b"DAI".to_vec(), 1_000u128, Some(2)),
+			],
+			native_asset_name: b"HDX".to_vec(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
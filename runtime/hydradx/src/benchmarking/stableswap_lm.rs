@@ -0,0 +1,316 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use crate::{
+	AccountId, AssetId, Balance, Currencies, RegisterAsset, Runtime, RuntimeOrigin, Stableswap,
+	StableswapLiquidityMining, StableswapWarehouseLM, System,
+};
+
+use frame_benchmarking::account;
+use frame_support::{assert_ok, sp_runtime::traits::One};
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use pallet_stableswap::types::AssetAmount;
+use pallet_stableswap::BenchmarkHelper;
+use primitives::constants::currency::UNITS;
+use sp_runtime::{DispatchError, FixedU128, Permill, Perquintill};
+use sp_std::vec;
+use warehouse_liquidity_mining::LoyaltyCurve;
+
+const HDX: AssetId = 0;
+const DAI: AssetId = 100;
+const USDT: AssetId = 101;
+const USDC: AssetId = 102;
+const POOL_ID: AssetId = 200;
+const USDC_POOL_ID: AssetId = 201;
+
+const G_FARM_TOTAL_REWARDS: Balance = 10_000_000 * UNITS;
+const GLOBAL_FARM_ID: u32 = 1;
+const YIELD_FARM_ID: u32 = 2;
+const DEPOSIT_ID: u128 = 1;
+
+fn funded_account(name: &'static str, index: u32, assets: &[AssetId]) -> AccountId {
+	let account: AccountId = account(name, index, 0);
+	for asset in assets {
+		assert_ok!(<Currencies as MultiCurrencyExtended<_>>::update_balance(
+			*asset,
+			&account,
+			(G_FARM_TOTAL_REWARDS * 10) as i128,
+		));
+	}
+	account
+}
+
+/// Registers assets and creates DAI/USDT and DAI/USDC stableswap pools.
+fn init_stableswap() -> Result<(), DispatchError> {
+	for asset in [DAI, USDT, USDC] {
+		RegisterAsset::<Runtime>::register_asset(asset, 12)?;
+	}
+	for pool_id in [POOL_ID, USDC_POOL_ID] {
+		RegisterAsset::<Runtime>::register_asset(pool_id, 18)?;
+	}
+
+	Stableswap::create_pool(RawOrigin::Root.into(), POOL_ID, vec![DAI, USDT], 100, Permill::zero())?;
+	Stableswap::create_pool(
+		RawOrigin::Root.into(),
+		USDC_POOL_ID,
+		vec![DAI, USDC],
+		100,
+		Permill::zero(),
+	)
+}
+
+fn add_liquidity(lp: AccountId, pool_id: AssetId, assets: &[AssetId]) -> Result<Balance, DispatchError> {
+	let assets = assets
+		.iter()
+		.map(|asset| AssetAmount::new(*asset, 1_000 * UNITS))
+		.collect();
+
+	Stableswap::add_liquidity(RuntimeOrigin::signed(lp.clone()), pool_id, assets)?;
+
+	Ok(<Currencies as MultiCurrency<_>>::free_balance(pool_id, &lp))
+}
+
+/// Creates global farm incentivizing DAI and yield farm for the `POOL_ID`.
+fn init_farms(owner: AccountId) -> Result<(), DispatchError> {
+	StableswapLiquidityMining::create_global_farm(
+		RawOrigin::Root.into(),
+		G_FARM_TOTAL_REWARDS,
+		100_000_u32,
+		1_u32,
+		DAI,
+		HDX,
+		owner.clone(),
+		Perquintill::from_percent(20),
+		1_000,
+		FixedU128::one(),
+	)?;
+
+	StableswapLiquidityMining::create_yield_farm(
+		RuntimeOrigin::signed(owner),
+		GLOBAL_FARM_ID,
+		POOL_ID,
+		FixedU128::one(),
+		None,
+	)?;
+
+	//NOTE: pot has to be funded to not fall below existential deposit
+	let pot = StableswapWarehouseLM::pot_account_id().ok_or(DispatchError::Other("pot not available"))?;
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(HDX, &pot, (100 * UNITS) as i128)
+}
+
+/// Deposits all LP shares of newly created `lp` account to the default yield farm.
+fn init_deposit() -> Result<AccountId, DispatchError> {
+	let lp = funded_account("lp", 1, &[DAI, USDT]);
+	let shares = add_liquidity(lp.clone(), POOL_ID, &[DAI, USDT])?;
+
+	StableswapLiquidityMining::deposit_shares(
+		RuntimeOrigin::signed(lp.clone()),
+		GLOBAL_FARM_ID,
+		YIELD_FARM_ID,
+		POOL_ID,
+		shares,
+	)?;
+
+	Ok(lp)
+}
+
+fn set_period(to: u32) {
+	System::set_block_number(to);
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_stableswap_liquidity_mining}
+
+	create_global_farm {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+	}: _(RawOrigin::Root, G_FARM_TOTAL_REWARDS, 100_000_u32, 1_u32, DAI, HDX, owner, Perquintill::from_percent(20), 1_000, FixedU128::one())
+	verify {
+		assert!(StableswapWarehouseLM::global_farm(GLOBAL_FARM_ID).is_some());
+	}
+
+	update_global_farm {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone())?;
+		init_deposit()?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, FixedU128::from(2_u128))
+
+	terminate_global_farm {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone())?;
+		init_deposit()?;
+
+		set_period(100);
+
+		StableswapLiquidityMining::stop_yield_farm(RuntimeOrigin::signed(owner.clone()), GLOBAL_FARM_ID, POOL_ID)?;
+		StableswapLiquidityMining::terminate_yield_farm(RuntimeOrigin::signed(owner.clone()), GLOBAL_FARM_ID, YIELD_FARM_ID, POOL_ID)?;
+
+		set_period(200);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID)
+
+	create_yield_farm {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone())?;
+		init_deposit()?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, USDC_POOL_ID, FixedU128::one(), Some(LoyaltyCurve::default()))
+
+	update_yield_farm {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone())?;
+		init_deposit()?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, POOL_ID, FixedU128::from(2_u128))
+
+	stop_yield_farm {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone())?;
+		init_deposit()?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, POOL_ID)
+
+	resume_yield_farm {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone())?;
+		init_deposit()?;
+
+		set_period(100);
+
+		StableswapLiquidityMining::stop_yield_farm(RuntimeOrigin::signed(owner.clone()), GLOBAL_FARM_ID, POOL_ID)?;
+
+		set_period(200);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, YIELD_FARM_ID, POOL_ID, FixedU128::from(2_u128))
+
+	terminate_yield_farm {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone())?;
+		init_deposit()?;
+
+		set_period(100);
+
+		StableswapLiquidityMining::stop_yield_farm(RuntimeOrigin::signed(owner.clone()), GLOBAL_FARM_ID, POOL_ID)?;
+
+		set_period(200);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, YIELD_FARM_ID, POOL_ID)
+
+	deposit_shares {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner)?;
+		init_deposit()?;
+
+		let lp = funded_account("lp", 2, &[DAI, USDT]);
+		let shares = add_liquidity(lp.clone(), POOL_ID, &[DAI, USDT])?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(lp.clone()), GLOBAL_FARM_ID, YIELD_FARM_ID, POOL_ID, shares)
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(POOL_ID, &lp), 0);
+	}
+
+	redeposit_shares {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner)?;
+		let lp = init_deposit()?;
+
+		let owner2 = funded_account("owner", 3, &[HDX]);
+		StableswapLiquidityMining::create_global_farm(
+			RawOrigin::Root.into(),
+			G_FARM_TOTAL_REWARDS,
+			100_000_u32,
+			1_u32,
+			USDT,
+			HDX,
+			owner2.clone(),
+			Perquintill::from_percent(20),
+			1_000,
+			FixedU128::one(),
+		)?;
+		StableswapLiquidityMining::create_yield_farm(RuntimeOrigin::signed(owner2), 3, POOL_ID, FixedU128::one(), None)?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(lp), 3, 4, DEPOSIT_ID)
+
+	claim_rewards {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner)?;
+		let lp = init_deposit()?;
+
+		set_period(200);
+	}: _(RawOrigin::Signed(lp), DEPOSIT_ID, YIELD_FARM_ID)
+
+	withdraw_shares {
+		init_stableswap()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner)?;
+		let lp = init_deposit()?;
+
+		set_period(200);
+	}: _(RawOrigin::Signed(lp.clone()), DEPOSIT_ID, YIELD_FARM_ID)
+	verify {
+		assert!(<Currencies as MultiCurrency<_>>::free_balance(POOL_ID, &lp) > 0);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use frame_support::traits::GenesisBuild;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<Runtime> {
+			registered_assets: vec![],
+			native_asset_name: b"HDX".to_vec(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		<pallet_stableswap_liquidity_mining::GenesisConfig as GenesisBuild<Runtime>>::assimilate_storage(
+			&pallet_stableswap_liquidity_mining::GenesisConfig::default(),
+			&mut t,
+		)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,331 @@
+// This file is part of HydraDX-node
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+#![allow(clippy::result_large_err)]
+
+use crate::{
+	AccountId, AssetId, AssetRegistry, Balance, Currencies, Runtime, RuntimeOrigin, System, XYKLiquidityMining,
+	XYKWarehouseLM, XYK,
+};
+
+use frame_benchmarking::account;
+use frame_support::{assert_ok, sp_runtime::traits::One};
+use frame_system::RawOrigin;
+use hydradx_traits::{Registry, AMM};
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::{MultiCurrency, MultiCurrencyExtended};
+use pallet_xyk::types::AssetPair;
+use primitives::constants::currency::UNITS;
+use sp_runtime::{DispatchError, FixedU128, Perquintill};
+use warehouse_liquidity_mining::LoyaltyCurve;
+
+const HDX: AssetId = 0;
+
+const G_FARM_TOTAL_REWARDS: Balance = 10_000_000 * UNITS;
+const GLOBAL_FARM_ID: u32 = 1;
+const YIELD_FARM_ID: u32 = 2;
+const DEPOSIT_ID: u128 = 1;
+
+fn funded_account(name: &'static str, index: u32, assets: &[AssetId]) -> AccountId {
+	let account: AccountId = account(name, index, 0);
+	for asset in assets {
+		assert_ok!(<Currencies as MultiCurrencyExtended<_>>::update_balance(
+			*asset,
+			&account,
+			(G_FARM_TOTAL_REWARDS * 10) as i128,
+		));
+	}
+	account
+}
+
+/// Registers assets and creates HDX/A and HDX/B xyk pools. Returns HDX/A and HDX/B asset pairs.
+fn init_xyk() -> Result<(AssetPair, AssetPair), DispatchError> {
+	let asset_a = AssetRegistry::create_asset(&b"FCA".to_vec(), Balance::one())?;
+	let asset_b = AssetRegistry::create_asset(&b"FCB".to_vec(), Balance::one())?;
+
+	let creator = funded_account("creator", 0, &[HDX, asset_a, asset_b]);
+
+	XYK::create_pool(
+		RuntimeOrigin::signed(creator.clone()),
+		HDX,
+		1_000_000 * UNITS,
+		asset_a,
+		1_000_000 * UNITS,
+		None,
+	)?;
+	XYK::create_pool(
+		RuntimeOrigin::signed(creator),
+		HDX,
+		1_000_000 * UNITS,
+		asset_b,
+		1_000_000 * UNITS,
+		None,
+	)?;
+
+	Ok((
+		AssetPair {
+			asset_in: HDX,
+			asset_out: asset_a,
+		},
+		AssetPair {
+			asset_in: HDX,
+			asset_out: asset_b,
+		},
+	))
+}
+
+fn add_liquidity(lp: AccountId, asset_pair: AssetPair) -> Result<Balance, DispatchError> {
+	XYK::add_liquidity(
+		RuntimeOrigin::signed(lp.clone()),
+		asset_pair.asset_in,
+		asset_pair.asset_out,
+		1_000 * UNITS,
+		10_000 * UNITS,
+	)?;
+
+	Ok(<Currencies as MultiCurrency<_>>::free_balance(
+		XYK::get_share_token(asset_pair),
+		&lp,
+	))
+}
+
+/// Creates global farm incentivizing HDX and yield farm for the `asset_pair`.
+fn init_farms(owner: AccountId, asset_pair: AssetPair) -> Result<(), DispatchError> {
+	XYKLiquidityMining::create_global_farm(
+		RawOrigin::Root.into(),
+		G_FARM_TOTAL_REWARDS,
+		100_000_u32,
+		1_u32,
+		HDX,
+		HDX,
+		owner.clone(),
+		Perquintill::from_percent(20),
+		1_000,
+		FixedU128::one(),
+	)?;
+
+	XYKLiquidityMining::create_yield_farm(
+		RuntimeOrigin::signed(owner),
+		GLOBAL_FARM_ID,
+		asset_pair,
+		FixedU128::one(),
+		None,
+	)?;
+
+	//NOTE: pot has to be funded to not fall below existential deposit
+	let pot = XYKWarehouseLM::pot_account_id().ok_or(DispatchError::Other("pot not available"))?;
+	<Currencies as MultiCurrencyExtended<_>>::update_balance(HDX, &pot, (100 * UNITS) as i128)
+}
+
+/// Deposits all LP shares of newly created `lp` account to the default yield farm.
+fn init_deposit(asset_pair: AssetPair) -> Result<AccountId, DispatchError> {
+	let lp = funded_account("lp", 1, &[asset_pair.asset_in, asset_pair.asset_out]);
+	let shares = add_liquidity(lp.clone(), asset_pair)?;
+
+	XYKLiquidityMining::deposit_shares(
+		RuntimeOrigin::signed(lp.clone()),
+		GLOBAL_FARM_ID,
+		YIELD_FARM_ID,
+		asset_pair,
+		shares,
+	)?;
+
+	Ok(lp)
+}
+
+fn set_period(to: u32) {
+	System::set_block_number(to);
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_xyk_liquidity_mining}
+
+	create_global_farm {
+		init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+	}: _(RawOrigin::Root, G_FARM_TOTAL_REWARDS, 100_000_u32, 1_u32, HDX, HDX, owner, Perquintill::from_percent(20), 1_000, FixedU128::one())
+	verify {
+		assert!(XYKWarehouseLM::global_farm(GLOBAL_FARM_ID).is_some());
+	}
+
+	update_global_farm {
+		let (asset_pair, _) = init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone(), asset_pair)?;
+		init_deposit(asset_pair)?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, FixedU128::from(2_u128))
+
+	terminate_global_farm {
+		let (asset_pair, _) = init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone(), asset_pair)?;
+		init_deposit(asset_pair)?;
+
+		set_period(100);
+
+		XYKLiquidityMining::stop_yield_farm(RuntimeOrigin::signed(owner.clone()), GLOBAL_FARM_ID, asset_pair)?;
+		XYKLiquidityMining::terminate_yield_farm(RuntimeOrigin::signed(owner.clone()), GLOBAL_FARM_ID, YIELD_FARM_ID, asset_pair)?;
+
+		set_period(200);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID)
+
+	create_yield_farm {
+		let (asset_pair, asset_pair_2) = init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone(), asset_pair)?;
+		init_deposit(asset_pair)?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, asset_pair_2, FixedU128::one(), Some(LoyaltyCurve::default()))
+
+	update_yield_farm {
+		let (asset_pair, _) = init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone(), asset_pair)?;
+		init_deposit(asset_pair)?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, asset_pair, FixedU128::from(2_u128))
+
+	stop_yield_farm {
+		let (asset_pair, _) = init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone(), asset_pair)?;
+		init_deposit(asset_pair)?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, asset_pair)
+
+	resume_yield_farm {
+		let (asset_pair, _) = init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone(), asset_pair)?;
+		init_deposit(asset_pair)?;
+
+		set_period(100);
+
+		XYKLiquidityMining::stop_yield_farm(RuntimeOrigin::signed(owner.clone()), GLOBAL_FARM_ID, asset_pair)?;
+
+		set_period(200);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, YIELD_FARM_ID, asset_pair, FixedU128::from(2_u128))
+
+	terminate_yield_farm {
+		let (asset_pair, _) = init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner.clone(), asset_pair)?;
+		init_deposit(asset_pair)?;
+
+		set_period(100);
+
+		XYKLiquidityMining::stop_yield_farm(RuntimeOrigin::signed(owner.clone()), GLOBAL_FARM_ID, asset_pair)?;
+
+		set_period(200);
+	}: _(RawOrigin::Signed(owner), GLOBAL_FARM_ID, YIELD_FARM_ID, asset_pair)
+
+	deposit_shares {
+		let (asset_pair, _) = init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner, asset_pair)?;
+		init_deposit(asset_pair)?;
+
+		let lp = funded_account("lp", 2, &[asset_pair.asset_in, asset_pair.asset_out]);
+		let shares = add_liquidity(lp.clone(), asset_pair)?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(lp.clone()), GLOBAL_FARM_ID, YIELD_FARM_ID, asset_pair, shares)
+	verify {
+		assert_eq!(<Currencies as MultiCurrency<_>>::free_balance(XYK::get_share_token(asset_pair), &lp), 0);
+	}
+
+	redeposit_shares {
+		let (asset_pair, _) = init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner, asset_pair)?;
+		let lp = init_deposit(asset_pair)?;
+
+		let owner2 = funded_account("owner", 3, &[HDX]);
+		XYKLiquidityMining::create_global_farm(
+			RawOrigin::Root.into(),
+			G_FARM_TOTAL_REWARDS,
+			100_000_u32,
+			1_u32,
+			asset_pair.asset_out,
+			HDX,
+			owner2.clone(),
+			Perquintill::from_percent(20),
+			1_000,
+			FixedU128::one(),
+		)?;
+		XYKLiquidityMining::create_yield_farm(RuntimeOrigin::signed(owner2), 3, asset_pair, FixedU128::one(), None)?;
+
+		set_period(100);
+	}: _(RawOrigin::Signed(lp), 3, 4, DEPOSIT_ID)
+
+	claim_rewards {
+		let (asset_pair, _) = init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner, asset_pair)?;
+		let lp = init_deposit(asset_pair)?;
+
+		set_period(200);
+	}: _(RawOrigin::Signed(lp), DEPOSIT_ID, YIELD_FARM_ID)
+
+	withdraw_shares {
+		let (asset_pair, _) = init_xyk()?;
+		let owner = funded_account("owner", 0, &[HDX]);
+		init_farms(owner, asset_pair)?;
+		let lp = init_deposit(asset_pair)?;
+
+		set_period(200);
+	}: _(RawOrigin::Signed(lp.clone()), DEPOSIT_ID, YIELD_FARM_ID)
+	verify {
+		assert!(<Currencies as MultiCurrency<_>>::free_balance(XYK::get_share_token(asset_pair), &lp) > 0);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use frame_support::traits::GenesisBuild;
+	use orml_benchmarking::impl_benchmark_test_suite;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<Runtime> {
+			registered_assets: vec![],
+			native_asset_name: b"HDX".to_vec(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		<pallet_xyk_liquidity_mining::GenesisConfig as GenesisBuild<Runtime>>::assimilate_storage(
+			&pallet_xyk_liquidity_mining::GenesisConfig::default(),
+			&mut t,
+		)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
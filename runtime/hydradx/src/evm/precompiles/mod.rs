@@ -21,7 +21,9 @@
 
 use core::marker::PhantomData;
 
-use crate::evm::precompiles::{erc20_mapping::is_asset_address, multicurrency::MultiCurrencyPrecompile};
+use crate::evm::precompiles::{
+	erc20_mapping::is_asset_address, multicurrency::MultiCurrencyPrecompile, router::RouterPrecompile,
+};
 use codec::Decode;
 use frame_support::dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo};
 use pallet_evm::{
@@ -38,6 +40,7 @@ pub mod costs;
 pub mod erc20_mapping;
 pub mod handle;
 pub mod multicurrency;
+pub mod router;
 pub mod substrate;
 
 pub type EvmResult<T = ()> = Result<T, PrecompileFailure>;
@@ -78,6 +81,7 @@ impl<R> HydraDXPrecompiles<R> {
 // See also
 // https://docs.moonbeam.network/builders/pallets-precompiles/precompiles/overview/#precompiled-contract-addresses
 const DISPATCH_ADDR: H160 = addr(1025);
+pub const ROUTER_ADDR: H160 = addr(1026);
 
 impl<R> PrecompileSet for HydraDXPrecompiles<R>
 where
@@ -85,12 +89,15 @@ where
 	R::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + Decode,
 	<R::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<R::AccountId>>,
 	MultiCurrencyPrecompile<R>: Precompile,
+	RouterPrecompile<R>: Precompile,
 {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
 		let address = handle.code_address();
 
 		if address == DISPATCH_ADDR {
 			Some(pallet_evm_precompile_dispatch::Dispatch::<R>::execute(handle))
+		} else if address == ROUTER_ADDR {
+			Some(RouterPrecompile::<R>::execute(handle))
 		} else if is_asset_address(address) {
 			Some(MultiCurrencyPrecompile::<R>::execute(handle))
 		} else {
@@ -99,7 +106,7 @@ where
 	}
 
 	fn is_precompile(&self, address: H160) -> bool {
-		address == DISPATCH_ADDR || is_asset_address(address)
+		address == DISPATCH_ADDR || address == ROUTER_ADDR || is_asset_address(address)
 	}
 }
 
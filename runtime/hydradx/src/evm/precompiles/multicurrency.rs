@@ -26,6 +26,7 @@ use crate::{
 		precompiles::{
 			erc20_mapping::{Erc20Mapping, HydraErc20Mapping},
 			handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
+			revert,
 			substrate::RuntimeHelper,
 			succeed, Address, Output,
 		},
@@ -35,11 +36,12 @@ use crate::{
 };
 use codec::EncodeLike;
 use frame_support::traits::OriginTrait;
+use hex_literal::hex;
 use hydradx_traits::InspectRegistry;
 use num_enum::{IntoPrimitive, TryFromPrimitive};
 use orml_traits::{MultiCurrency as MultiCurrencyT, MultiCurrency};
 use pallet_evm::{AddressMapping, ExitRevert, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult};
-use primitive_types::H160;
+use primitive_types::{H160, H256, U256};
 use primitives::{AssetId, Balance};
 use sp_runtime::{traits::Dispatchable, RuntimeDebug};
 use sp_std::{marker::PhantomData, prelude::*};
@@ -58,6 +60,13 @@ pub enum Action {
 	Approve = "approve(address,uint256)",
 	TransferFrom = "transferFrom(address,address,uint256)",
 }
+
+/// Topic of the `Transfer(address,address,uint256)` event log.
+pub const SELECTOR_LOG_TRANSFER: H256 = H256(hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"));
+
+/// Topic of the `Approval(address,address,uint256)` event log.
+pub const SELECTOR_LOG_APPROVAL: H256 = H256(hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925"));
+
 pub struct MultiCurrencyPrecompile<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> Precompile for MultiCurrencyPrecompile<Runtime>
@@ -68,6 +77,8 @@ where
 	<Runtime as pallet_asset_registry::Config>::AssetId: core::convert::From<AssetId>,
 	Currencies: MultiCurrency<Runtime::AccountId, CurrencyId = AssetId, Balance = Balance>,
 	pallet_currencies::Pallet<Runtime>: MultiCurrency<Runtime::AccountId, CurrencyId = AssetId, Balance = Balance>,
+	<Runtime as pallet_currencies::Config>::MultiCurrency:
+		MultiCurrency<Runtime::AccountId, CurrencyId = AssetId, Balance = Balance>,
 	<Runtime as frame_system::Config>::AccountId: core::convert::From<sp_runtime::AccountId32>,
 	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: OriginTrait,
 {
@@ -82,7 +93,7 @@ where
 			};
 
 			handle.check_function_modifier(match selector {
-				Action::Transfer => FunctionModifier::NonPayable,
+				Action::Transfer | Action::Approve | Action::TransferFrom => FunctionModifier::NonPayable,
 				_ => FunctionModifier::View,
 			})?;
 
@@ -93,9 +104,9 @@ where
 				Action::TotalSupply => Self::total_supply(asset_id, handle),
 				Action::BalanceOf => Self::balance_of(asset_id, handle),
 				Action::Transfer => Self::transfer(asset_id, handle),
-				Action::Allowance => Self::not_supported(asset_id, handle),
-				Action::Approve => Self::not_supported(asset_id, handle),
-				Action::TransferFrom => Self::not_supported(asset_id, handle),
+				Action::Allowance => Self::allowance(asset_id, handle),
+				Action::Approve => Self::approve(asset_id, handle),
+				Action::TransferFrom => Self::transfer_from(asset_id, handle),
 			};
 		}
 		Err(PrecompileFailure::Revert {
@@ -112,6 +123,8 @@ where
 	<Runtime as pallet_asset_registry::Config>::AssetId: core::convert::From<AssetId>,
 	Currencies: MultiCurrency<Runtime::AccountId, CurrencyId = AssetId, Balance = Balance>,
 	pallet_currencies::Pallet<Runtime>: MultiCurrency<Runtime::AccountId, CurrencyId = AssetId, Balance = Balance>,
+	<Runtime as pallet_currencies::Config>::MultiCurrency:
+		MultiCurrency<Runtime::AccountId, CurrencyId = AssetId, Balance = Balance>,
 	<Runtime as frame_system::Config>::AccountId: core::convert::From<sp_runtime::AccountId32>,
 	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: OriginTrait,
 {
@@ -215,6 +228,7 @@ where
 
 	fn transfer(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
 
 		// Parse input
 		let mut input = handle.read_input()?;
@@ -223,15 +237,16 @@ where
 		let to: H160 = input.read::<Address>()?.into();
 		let amount = input.read::<Balance>()?;
 
-		let origin = ExtendedAddressMapping::into_account_id(handle.context().caller);
-		let to = ExtendedAddressMapping::into_account_id(to);
+		let caller = handle.context().caller;
+		let origin = ExtendedAddressMapping::into_account_id(caller);
+		let to_account = ExtendedAddressMapping::into_account_id(to);
 
-		log::debug!(target: "evm", "multicurrency: transfer from: {:?}, to: {:?}, amount: {:?}", origin, to, amount);
+		log::debug!(target: "evm", "multicurrency: transfer from: {:?}, to: {:?}, amount: {:?}", origin, to_account, amount);
 
 		<pallet_currencies::Pallet<Runtime> as MultiCurrency<Runtime::AccountId>>::transfer(
 			asset_id,
 			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(origin)),
-			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(to)),
+			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(to_account)),
 			amount,
 		)
 		.map_err(|e| PrecompileFailure::Revert {
@@ -239,12 +254,113 @@ where
 			output: Into::<&str>::into(e).as_bytes().to_vec(),
 		})?;
 
+		Self::log_with_amount(handle, SELECTOR_LOG_TRANSFER, caller, to, amount)?;
+
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
 
-	fn not_supported(_: AssetId, _: &mut impl PrecompileHandle) -> PrecompileResult {
-		Err(PrecompileFailure::Error {
-			exit_status: pallet_evm::ExitError::Other("not supported".into()),
-		})
+	fn allowance(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let owner: H160 = input.read::<Address>()?.into();
+		let spender: H160 = input.read::<Address>()?.into();
+
+		let owner: Runtime::AccountId = ExtendedAddressMapping::into_account_id(owner).into();
+		let spender: Runtime::AccountId = ExtendedAddressMapping::into_account_id(spender).into();
+
+		let allowance = pallet_currencies::Pallet::<Runtime>::allowance((asset_id, owner, spender));
+
+		log::debug!(target: "evm", "multicurrency: allowance: {:?}", allowance);
+
+		let encoded = Output::encode_uint::<u128>(allowance);
+
+		Ok(succeed(encoded))
+	}
+
+	fn approve(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let spender: H160 = input.read::<Address>()?.into();
+		let amount = Self::saturated_amount(input.read::<U256>()?);
+
+		let caller = handle.context().caller;
+		let owner: Runtime::AccountId = ExtendedAddressMapping::into_account_id(caller).into();
+		let spender_account: Runtime::AccountId = ExtendedAddressMapping::into_account_id(spender).into();
+
+		log::debug!(target: "evm", "multicurrency: approve owner: {:?}, spender: {:?}, amount: {:?}", owner, spender_account, amount);
+
+		pallet_currencies::Pallet::<Runtime>::approve(asset_id, &owner, &spender_account, amount).map_err(|e| {
+			PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: Into::<&str>::into(e).as_bytes().to_vec(),
+			}
+		})?;
+
+		Self::log_with_amount(handle, SELECTOR_LOG_APPROVAL, caller, spender, amount)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	fn transfer_from(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let from: H160 = input.read::<Address>()?.into();
+		let to: H160 = input.read::<Address>()?.into();
+		let amount = Balance::try_from(input.read::<U256>()?).map_err(|_| revert("amount overflow"))?;
+
+		let spender: Runtime::AccountId = ExtendedAddressMapping::into_account_id(handle.context().caller).into();
+		let from_account: Runtime::AccountId = ExtendedAddressMapping::into_account_id(from).into();
+		let to_account: Runtime::AccountId = ExtendedAddressMapping::into_account_id(to).into();
+
+		log::debug!(target: "evm", "multicurrency: transferFrom spender: {:?}, from: {:?}, to: {:?}, amount: {:?}", spender, from_account, to_account, amount);
+
+		pallet_currencies::Pallet::<Runtime>::transfer_from(asset_id, &spender, &from_account, &to_account, amount)
+			.map_err(|e| PrecompileFailure::Revert {
+				exit_status: ExitRevert::Reverted,
+				output: Into::<&str>::into(e).as_bytes().to_vec(),
+			})?;
+
+		Self::log_with_amount(handle, SELECTOR_LOG_TRANSFER, from, to, amount)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
+	/// Converts an `uint256` amount to `Balance`, saturating at `Balance::MAX`.
+	/// `type(uint256).max` is commonly used for an infinite approval, which is `Balance::MAX` in the runtime.
+	fn saturated_amount(amount: U256) -> Balance {
+		Balance::try_from(amount).unwrap_or(Balance::MAX)
+	}
+
+	fn log_with_amount(
+		handle: &mut impl PrecompileHandle,
+		selector: H256,
+		from: H160,
+		to: H160,
+		amount: Balance,
+	) -> Result<(), PrecompileFailure> {
+		let address = handle.code_address();
+
+		handle
+			.log(
+				address,
+				sp_std::vec![selector, from.into(), to.into()],
+				EvmDataWriter::new().write(amount).build(),
+			)
+			.map_err(|exit_status| PrecompileFailure::Error { exit_status })
 	}
 }
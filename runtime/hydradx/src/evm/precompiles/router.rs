@@ -0,0 +1,284 @@
+//                    :                     $$\   $$\                 $$\                    $$$$$$$\  $$\   $$\
+//                  !YJJ^                   $$ |  $$ |                $$ |                   $$  __$$\ $$ |  $$ |
+//                7B5. ~B5^                 $$ |  $$ |$$\   $$\  $$$$$$$ | $$$$$$\  $$$$$$\  $$ |  $$ |\$$\ $$  |
+//             .?B@G    ~@@P~               $$$$$$$$ |$$ |  $$ |$$  __$$ |$$  __$$\ \____$$\ $$ |  $$ | \$$$$  /
+//           :?#@@@Y    .&@@@P!.            $$  __$$ |$$ |  $$ |$$ /  $$ |$$ |  \__|$$$$$$$ |$$ |  $$ | $$  $$<
+//         ^?J^7P&@@!  .5@@#Y~!J!.          $$ |  $$ |$$ |  $$ |$$ |  $$ |$$ |     $$  __$$ |$$ |  $$ |$$  /\$$\
+//       ^JJ!.   :!J5^ ?5?^    ^?Y7.        $$ |  $$ |\$$$$$$$ |\$$$$$$$ |$$ |     \$$$$$$$ |$$$$$$$  |$$ /  $$ |
+//     ~PP: 7#B5!.         :?P#G: 7G?.      \__|  \__| \____$$ | \_______|\__|      \_______|\_______/ \__|  \__|
+//  .!P@G    7@@@#Y^    .!P@@@#.   ~@&J:              $$\   $$ |
+//  !&@@J    :&@@@@P.   !&@@@@5     #@@P.             \$$$$$$  |
+//   :J##:   Y@@&P!      :JB@@&~   ?@G!                \______/
+//     .?P!.?GY7:   .. .    ^?PP^:JP~
+//       .7Y7.  .!YGP^ ?BP?^   ^JJ^         This file is part of https://github.com/galacticcouncil/HydraDX-node
+//         .!Y7Y#@@#:   ?@@@G?JJ^           Built with <3 for decentralisation.
+//            !G@@@Y    .&@@&J:
+//              ^5@#.   7@#?.               Copyright (C) 2021-2023  Intergalactic, Limited (GIB).
+//                :5P^.?G7.                 SPDX-License-Identifier: Apache-2.0
+//                  :?Y!                    Licensed under the Apache License, Version 2.0 (the "License");
+//                                          you may not use this file except in compliance with the License.
+//                                          http://www.apache.org/licenses/LICENSE-2.0
+
+use crate::evm::precompiles::{
+	handle::{EvmDataWriter, FunctionModifier, PrecompileHandleExt},
+	revert,
+	substrate::RuntimeHelper,
+	succeed,
+};
+use frame_support::{
+	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
+	log,
+	traits::{fungibles::Inspect, Get},
+};
+use hydradx_traits::router::{AmmTradeWeights, AssetPair, RouteProvider, Trade};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use pallet_evm::{
+	AddressMapping, ExitError, GasWeightMapping, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult,
+};
+use primitives::{AssetId, Balance};
+use sp_runtime::{traits::CheckedDiv, FixedPointNumber, FixedU128, RuntimeDebug};
+use sp_std::{marker::PhantomData, prelude::*};
+
+#[module_evm_utility_macro::generate_function_selector]
+#[derive(RuntimeDebug, Eq, PartialEq, TryFromPrimitive, IntoPrimitive)]
+#[repr(u32)]
+pub enum Action {
+	Sell = "sell(uint32,uint32,uint256,uint256)",
+	Buy = "buy(uint32,uint32,uint256,uint256)",
+	QuoteSell = "quoteSell(uint32,uint32,uint256)",
+	QuoteBuy = "quoteBuy(uint32,uint32,uint256)",
+	OmnipoolSpotPrice = "omnipoolSpotPrice(uint32,uint32)",
+	StableswapSpotPrice = "stableswapSpotPrice(uint32,uint32,uint32)",
+}
+
+/// Precompile to trade via the route executor and to query prices of Omnipool and Stableswap pools.
+///
+/// Trades are executed using the on-chain route of the asset pair, or Omnipool if there is none.
+/// Prices are encoded as fixed point numbers with 18 decimals.
+pub struct RouterPrecompile<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Precompile for RouterPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config
+		+ pallet_route_executor::Config<AssetId = AssetId, Balance = Balance>
+		+ pallet_omnipool::Config<AssetId = AssetId>
+		+ pallet_stableswap::Config<AssetId = AssetId>,
+	Runtime::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_route_executor::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+{
+	fn execute(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		let selector = handle.read_selector()?;
+
+		handle.check_function_modifier(match selector {
+			Action::Sell | Action::Buy => FunctionModifier::NonPayable,
+			_ => FunctionModifier::View,
+		})?;
+
+		match selector {
+			Action::Sell => Self::sell(handle),
+			Action::Buy => Self::buy(handle),
+			Action::QuoteSell => Self::quote_sell(handle),
+			Action::QuoteBuy => Self::quote_buy(handle),
+			Action::OmnipoolSpotPrice => Self::omnipool_spot_price(handle),
+			Action::StableswapSpotPrice => Self::stableswap_spot_price(handle),
+		}
+	}
+}
+
+impl<Runtime> RouterPrecompile<Runtime>
+where
+	Runtime: pallet_evm::Config
+		+ pallet_route_executor::Config<AssetId = AssetId, Balance = Balance>
+		+ pallet_omnipool::Config<AssetId = AssetId>
+		+ pallet_stableswap::Config<AssetId = AssetId>,
+	Runtime::RuntimeCall:
+		Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo + From<pallet_route_executor::Call<Runtime>>,
+	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+{
+	fn sell(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+
+		let asset_in = input.read::<AssetId>()?;
+		let asset_out = input.read::<AssetId>()?;
+		let amount_in = input.read::<Balance>()?;
+		let min_amount_out = input.read::<Balance>()?;
+
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		log::debug!(target: "evm", "router: sell asset in: {:?}, asset out: {:?}, amount in: {:?}", asset_in, asset_out, amount_in);
+
+		let balance_before = <Runtime as pallet_route_executor::Config>::Currency::balance(asset_out, &who);
+
+		let call = pallet_route_executor::Call::<Runtime>::sell {
+			asset_in,
+			asset_out,
+			amount_in,
+			min_amount_out,
+			route: vec![],
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(who.clone()).into(), call)?;
+
+		let amount_out = <Runtime as pallet_route_executor::Config>::Currency::balance(asset_out, &who)
+			.saturating_sub(balance_before);
+
+		Ok(succeed(EvmDataWriter::new().write(amount_out).build()))
+	}
+
+	fn buy(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(4)?;
+
+		let asset_in = input.read::<AssetId>()?;
+		let asset_out = input.read::<AssetId>()?;
+		let amount_out = input.read::<Balance>()?;
+		let max_amount_in = input.read::<Balance>()?;
+
+		let who = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		log::debug!(target: "evm", "router: buy asset in: {:?}, asset out: {:?}, amount out: {:?}", asset_in, asset_out, amount_out);
+
+		let balance_before = <Runtime as pallet_route_executor::Config>::Currency::balance(asset_in, &who);
+
+		let call = pallet_route_executor::Call::<Runtime>::buy {
+			asset_in,
+			asset_out,
+			amount_out,
+			max_amount_in,
+			route: vec![],
+		};
+		RuntimeHelper::<Runtime>::try_dispatch(handle, Some(who.clone()).into(), call)?;
+
+		let amount_in = balance_before.saturating_sub(<Runtime as pallet_route_executor::Config>::Currency::balance(
+			asset_in, &who,
+		));
+
+		Ok(succeed(EvmDataWriter::new().write(amount_in).build()))
+	}
+
+	fn quote_sell(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let asset_in = input.read::<AssetId>()?;
+		let asset_out = input.read::<AssetId>()?;
+		let amount_in = input.read::<Balance>()?;
+
+		let route = Self::route(asset_in, asset_out);
+		handle.record_cost(Runtime::GasWeightMapping::weight_to_gas(
+			<Runtime as pallet_route_executor::Config>::WeightInfo::calculate_sell_trade_amounts_weight(&route),
+		))?;
+
+		let trade_amounts = pallet_route_executor::Pallet::<Runtime>::quote_sell(asset_in, asset_out, amount_in, route)
+			.map_err(|_| revert("route quote failed"))?;
+		let amount_out = trade_amounts
+			.last()
+			.map(|trade_amount| trade_amount.amount_out)
+			.ok_or_else(|| revert("route quote failed"))?;
+
+		log::debug!(target: "evm", "router: quoteSell: {:?}", amount_out);
+
+		Ok(succeed(EvmDataWriter::new().write(amount_out).build()))
+	}
+
+	fn quote_buy(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let asset_in = input.read::<AssetId>()?;
+		let asset_out = input.read::<AssetId>()?;
+		let amount_out = input.read::<Balance>()?;
+
+		let route = Self::route(asset_in, asset_out);
+		handle.record_cost(Runtime::GasWeightMapping::weight_to_gas(
+			<Runtime as pallet_route_executor::Config>::WeightInfo::calculate_buy_trade_amounts_weight(&route),
+		))?;
+
+		let trade_amounts = pallet_route_executor::Pallet::<Runtime>::quote_buy(asset_in, asset_out, amount_out, route)
+			.map_err(|_| revert("route quote failed"))?;
+		let amount_in = trade_amounts
+			.first()
+			.map(|trade_amount| trade_amount.amount_in)
+			.ok_or_else(|| revert("route quote failed"))?;
+
+		log::debug!(target: "evm", "router: quoteBuy: {:?}", amount_in);
+
+		Ok(succeed(EvmDataWriter::new().write(amount_in).build()))
+	}
+
+	fn omnipool_spot_price(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let asset_a = input.read::<AssetId>()?;
+		let asset_b = input.read::<AssetId>()?;
+
+		let price = Self::omnipool_hub_price(asset_a)
+			.zip(Self::omnipool_hub_price(asset_b))
+			.and_then(|(price_a, price_b)| price_a.checked_div(&price_b))
+			.ok_or_else(Self::price_not_available)?;
+
+		log::debug!(target: "evm", "router: omnipoolSpotPrice: {:?}", price);
+
+		Ok(succeed(EvmDataWriter::new().write(price.into_inner()).build()))
+	}
+
+	fn stableswap_spot_price(handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(3))?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(3)?;
+
+		let pool_id = input.read::<AssetId>()?;
+		let asset_a = input.read::<AssetId>()?;
+		let asset_b = input.read::<AssetId>()?;
+
+		let price = pallet_stableswap::Pallet::<Runtime>::calculate_spot_price(pool_id, asset_a, asset_b)
+			.ok_or_else(Self::price_not_available)?;
+
+		log::debug!(target: "evm", "router: stableswapSpotPrice: {:?}", price);
+
+		Ok(succeed(EvmDataWriter::new().write(price.into_inner()).build()))
+	}
+
+	/// On-chain route of the asset pair, or the default Omnipool route if there is none.
+	fn route(asset_in: AssetId, asset_out: AssetId) -> Vec<Trade<AssetId>> {
+		<pallet_route_executor::Pallet<Runtime> as RouteProvider<AssetId>>::get_route(AssetPair::new(
+			asset_in, asset_out,
+		))
+	}
+
+	/// Price of the asset denominated in the hub asset.
+	fn omnipool_hub_price(asset_id: AssetId) -> Option<FixedU128> {
+		if asset_id == <Runtime as pallet_omnipool::Config>::HubAssetId::get() {
+			return Some(FixedU128::from(1));
+		}
+
+		pallet_omnipool::Pallet::<Runtime>::load_asset_state(asset_id)
+			.ok()?
+			.price()
+	}
+
+	fn price_not_available() -> PrecompileFailure {
+		PrecompileFailure::Error {
+			exit_status: ExitError::Other("Price not available.".into()),
+		}
+	}
+}
@@ -63,8 +63,10 @@ use sp_version::RuntimeVersion;
 // A few exports that help ease life for downstream crates.
 use frame_support::{construct_runtime, weights::Weight};
 pub use hex_literal::hex;
+use hydradx_traits::router::{AmountInAndOut, Trade, TradeQuoteError};
 /// Import HydraDX pallets
 pub use pallet_claims;
+pub use pallet_ema_oracle;
 use pallet_ethereum::{Transaction as EthereumTransaction, TransactionStatus};
 use pallet_evm::{Account as EVMAccount, FeeCalculator, Runner};
 pub use primitives::{
@@ -99,7 +101,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 197,
+	spec_version: 249,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -166,9 +168,17 @@ construct_runtime!(
 		Staking: pallet_staking = 69,
 		Stableswap: pallet_stableswap = 70,
 		Bonds: pallet_bonds = 71,
+		OtcSettlements: pallet_otc_settlements = 72,
 		LBP: pallet_lbp = 73,
 		XYK: pallet_xyk = 74,
 		Referrals: pallet_referrals = 75,
+		StableswapWarehouseLM: warehouse_liquidity_mining::<Instance2> = 76,
+		StableswapLiquidityMining: pallet_stableswap_liquidity_mining = 78,
+		XYKWarehouseLM: warehouse_liquidity_mining::<Instance3> = 80,
+		XYKLiquidityMining: pallet_xyk_liquidity_mining = 82,
+		XYKDynamicFees: pallet_dynamic_fees::<Instance1> = 83,
+		StableswapDynamicFees: pallet_dynamic_fees::<Instance2> = 84,
+		OmnipoolSubpools: pallet_omnipool_subpools = 85,
 
 		// ORML related modules
 		Tokens: orml_tokens = 77,
@@ -242,6 +252,15 @@ pub type UncheckedExtrinsic = fp_self_contained::UncheckedExtrinsic<Address, Run
 
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = fp_self_contained::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra, H160>;
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
+}
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -386,6 +405,76 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_route_executor_runtime_api::RouterApi<Block, AssetId, Balance> for Runtime {
+		fn quote_sell(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+			route: Vec<Trade<AssetId>>,
+		) -> Result<Vec<AmountInAndOut<Balance>>, TradeQuoteError> {
+			Router::quote_sell(asset_in, asset_out, amount_in, route)
+		}
+
+		fn quote_buy(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+			route: Vec<Trade<AssetId>>,
+		) -> Result<Vec<AmountInAndOut<Balance>>, TradeQuoteError> {
+			Router::quote_buy(asset_in, asset_out, amount_out, route)
+		}
+
+		fn find_best_route(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Result<(Vec<Trade<AssetId>>, Balance), sp_runtime::DispatchError> {
+			Router::find_best_route(asset_in, asset_out, amount_in)
+		}
+	}
+
+	impl pallet_ema_oracle_runtime_api::EmaOracleApi<Block, AssetId, Balance, BlockNumber, pallet_ema_oracle::Price, pallet_ema_oracle::OracleError> for Runtime {
+		fn get_twap(
+			source: pallet_ema_oracle_runtime_api::Source,
+			assets: (AssetId, AssetId),
+			from_block: BlockNumber,
+			to_block: BlockNumber,
+		) -> Result<pallet_ema_oracle::Price, pallet_ema_oracle::OracleError> {
+			EmaOracle::get_twap(source, assets, from_block, to_block)
+		}
+
+		fn get_entry(
+			source: pallet_ema_oracle_runtime_api::Source,
+			assets: (AssetId, AssetId),
+			period: pallet_ema_oracle_runtime_api::OraclePeriod,
+		) -> Result<pallet_ema_oracle_runtime_api::AggregatedEntry<Balance, BlockNumber, pallet_ema_oracle::Price>, pallet_ema_oracle::OracleError> {
+			use hydradx_traits::AggregatedOracle;
+			EmaOracle::get_entry(assets.0, assets.1, period, source)
+		}
+
+		fn tracked_pairs() -> Vec<(pallet_ema_oracle_runtime_api::Source, (AssetId, AssetId))> {
+			EmaOracle::tracked_pairs()
+		}
+	}
+
+	impl pallet_dca_runtime_api::DcaApi<
+		Block,
+		AccountId,
+		pallet_dca::types::ScheduleId,
+		pallet_dca::types::Schedule<AccountId, AssetId, BlockNumber>,
+		pallet_dca::types::ScheduleHistory<BlockNumber>,
+	> for Runtime {
+		fn schedule_history(schedule_id: pallet_dca::types::ScheduleId) -> Option<pallet_dca::types::ScheduleHistory<BlockNumber>> {
+			DCA::schedule_history(schedule_id)
+		}
+
+		fn active_schedules(
+			owner: AccountId,
+		) -> Vec<(pallet_dca::types::ScheduleId, pallet_dca::types::Schedule<AccountId, AssetId, BlockNumber>)> {
+			DCA::active_schedules(&owner)
+		}
+	}
+
 	// Frontier RPC support
 	impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		fn chain_id() -> u64 {
@@ -551,6 +640,7 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_lbp, LBP);
 			list_benchmark!(list, extra, pallet_xyk, XYK);
 			list_benchmark!(list, extra, pallet_referrals, Referrals);
+			list_benchmark!(list, extra, pallet_dynamic_fees, DynamicFees);
 
 			list_benchmark!(list, extra, cumulus_pallet_xcmp_queue, XcmpQueue);
 			list_benchmark!(list, extra, pallet_transaction_pause, TransactionPause);
@@ -564,8 +654,12 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, pallet_transaction_multi_payment, benchmarking::multi_payment);
 			orml_list_benchmark!(list, extra, pallet_duster, benchmarking::duster);
 			orml_list_benchmark!(list, extra, pallet_omnipool, benchmarking::omnipool);
+			orml_list_benchmark!(list, extra, pallet_omnipool_subpools, benchmarking::omnipool_subpools);
 			orml_list_benchmark!(list, extra, pallet_route_executor, benchmarking::route_executor);
 			orml_list_benchmark!(list, extra, pallet_dca, benchmarking::dca);
+			orml_list_benchmark!(list, extra, pallet_otc_settlements, benchmarking::otc_settlements);
+			orml_list_benchmark!(list, extra, pallet_stableswap_liquidity_mining, benchmarking::stableswap_lm);
+			orml_list_benchmark!(list, extra, pallet_xyk_liquidity_mining, benchmarking::xyk_lm);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -622,6 +716,7 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_xyk, XYK);
 			add_benchmark!(params, batches, pallet_stableswap, Stableswap);
 			add_benchmark!(params, batches, pallet_referrals, Referrals);
+			add_benchmark!(params, batches, pallet_dynamic_fees, DynamicFees);
 
 			add_benchmark!(params, batches, cumulus_pallet_xcmp_queue, XcmpQueue);
 			add_benchmark!(params, batches, pallet_transaction_pause, TransactionPause);
@@ -635,8 +730,12 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, pallet_transaction_multi_payment, benchmarking::multi_payment);
 			orml_add_benchmark!(params, batches, pallet_duster, benchmarking::duster);
 			orml_add_benchmark!(params, batches, pallet_omnipool, benchmarking::omnipool);
+			orml_add_benchmark!(params, batches, pallet_omnipool_subpools, benchmarking::omnipool_subpools);
 			orml_add_benchmark!(params, batches, pallet_route_executor, benchmarking::route_executor);
 			orml_add_benchmark!(params, batches, pallet_dca, benchmarking::dca);
+			orml_add_benchmark!(params, batches, pallet_otc_settlements, benchmarking::otc_settlements);
+			orml_add_benchmark!(params, batches, pallet_stableswap_liquidity_mining, benchmarking::stableswap_lm);
+			orml_add_benchmark!(params, batches, pallet_xyk_liquidity_mining, benchmarking::xyk_lm);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
@@ -1,20 +1,42 @@
 #![allow(unused_imports)]
-use crate::Vec;
+use crate::{Runtime, StableswapLiquidityMining, Vec, XYKLiquidityMining};
 use frame_support::{codec::alloc::vec, traits::OnRuntimeUpgrade, weights::Weight};
 pub struct OnRuntimeUpgradeMigration;
 
 impl OnRuntimeUpgrade for OnRuntimeUpgradeMigration {
 	#[cfg(feature = "try-runtime")]
 	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		pallet_otc::migration::v1::pre_migrate::<Runtime>();
+		pallet_xyk::migration::v1::pre_migrate::<Runtime>();
+		pallet_ema_oracle::migration::v1::pre_migrate::<Runtime>();
+
 		Ok(vec![])
 	}
 
 	fn on_runtime_upgrade() -> Weight {
-		Weight::zero()
+		let mut weight = pallet_otc::migration::v1::migrate::<Runtime>();
+
+		weight = weight.saturating_add(pallet_stableswap_liquidity_mining::migration::migrate_to_v1::<
+			Runtime,
+			StableswapLiquidityMining,
+		>());
+
+		weight = weight.saturating_add(pallet_xyk_liquidity_mining::migration::migrate_to_v1::<
+			Runtime,
+			XYKLiquidityMining,
+		>());
+
+		weight = weight.saturating_add(pallet_xyk::migration::v1::migrate::<Runtime>());
+
+		weight.saturating_add(pallet_ema_oracle::migration::v1::migrate::<Runtime>())
 	}
 
 	#[cfg(feature = "try-runtime")]
 	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		pallet_otc::migration::v1::post_migrate::<Runtime>();
+		pallet_xyk::migration::v1::post_migrate::<Runtime>();
+		pallet_ema_oracle::migration::v1::post_migrate::<Runtime>();
+
 		Ok(())
 	}
 }
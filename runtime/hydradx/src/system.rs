@@ -348,16 +348,33 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				RuntimeCall::Omnipool(pallet_omnipool::Call::add_liquidity { .. })
 					| RuntimeCall::Omnipool(pallet_omnipool::Call::remove_liquidity { .. })
 			),
-			ProxyType::LiquidityMining => matches!(
-				c,
-				RuntimeCall::OmnipoolLiquidityMining(pallet_omnipool_liquidity_mining::Call::deposit_shares { .. })
-					| RuntimeCall::OmnipoolLiquidityMining(
-						pallet_omnipool_liquidity_mining::Call::redeposit_shares { .. }
-					) | RuntimeCall::OmnipoolLiquidityMining(pallet_omnipool_liquidity_mining::Call::claim_rewards { .. })
-					| RuntimeCall::OmnipoolLiquidityMining(
-						pallet_omnipool_liquidity_mining::Call::withdraw_shares { .. }
+			ProxyType::LiquidityMining => {
+				matches!(
+					c,
+					RuntimeCall::OmnipoolLiquidityMining(
+						pallet_omnipool_liquidity_mining::Call::deposit_shares { .. }
+							| pallet_omnipool_liquidity_mining::Call::redeposit_shares { .. }
+							| pallet_omnipool_liquidity_mining::Call::claim_rewards { .. }
+							| pallet_omnipool_liquidity_mining::Call::withdraw_shares { .. }
 					)
-			),
+				) || matches!(
+					c,
+					RuntimeCall::StableswapLiquidityMining(
+						pallet_stableswap_liquidity_mining::Call::deposit_shares { .. }
+							| pallet_stableswap_liquidity_mining::Call::redeposit_shares { .. }
+							| pallet_stableswap_liquidity_mining::Call::claim_rewards { .. }
+							| pallet_stableswap_liquidity_mining::Call::withdraw_shares { .. }
+					)
+				) || matches!(
+					c,
+					RuntimeCall::XYKLiquidityMining(
+						pallet_xyk_liquidity_mining::Call::deposit_shares { .. }
+							| pallet_xyk_liquidity_mining::Call::redeposit_shares { .. }
+							| pallet_xyk_liquidity_mining::Call::claim_rewards { .. }
+							| pallet_xyk_liquidity_mining::Call::withdraw_shares { .. }
+					)
+				)
+			}
 		}
 	}
 	fn is_superset(&self, o: &Self) -> bool {
@@ -62,34 +62,43 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
 	// Storage: EmaOracle Oracles (r:117 w:117)
 	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	// Storage: EmaOracle SnapshotCursor (r:39 w:39)
+	// Proof: EmaOracle SnapshotCursor (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: EmaOracle PriceSnapshots (r:39 w:39)
+	// Proof: EmaOracle PriceSnapshots (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
 	/// The range of component `b` is `[1, 39]`.
 	fn on_finalize_multiple_tokens(b: u32) -> Weight {
-		// Minimum execution time: 44_637 nanoseconds.
-		Weight::from_ref_time(9_798_669 as u64) // Standard Error: 47_796
-			.saturating_add(Weight::from_ref_time(33_611_646 as u64).saturating_mul(b as u64))
+		// Not benchmarked: per token ref time estimated for the added snapshot accesses.
+		// Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(9_798_669 as u64)
+			.saturating_add(Weight::from_ref_time(41_482_903 as u64).saturating_mul(b as u64))
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(b as u64)))
+			.saturating_add(T::DbWeight::get().reads((5 as u64).saturating_mul(b as u64)))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
-			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(b as u64)))
+			.saturating_add(T::DbWeight::get().writes((5 as u64).saturating_mul(b as u64)))
 	}
+	// Storage: EmaOracle WhitelistedAssets (r:1 w:0)
+	// Proof: EmaOracle WhitelistedAssets (max_values: Some(1), max_size: Some(641), added: 1136, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
 	/// The range of component `b` is `[1, 39]`.
 	fn on_trade_multiple_tokens(b: u32) -> Weight {
-		// Minimum execution time: 10_368 nanoseconds.
-		Weight::from_ref_time(10_544_600 as u64) // Standard Error: 2_268
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(10_544_600 as u64)
 			.saturating_add(Weight::from_ref_time(432_909 as u64).saturating_mul(b as u64))
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: EmaOracle WhitelistedAssets (r:1 w:0)
+	// Proof: EmaOracle WhitelistedAssets (max_values: Some(1), max_size: Some(641), added: 1136, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
 	/// The range of component `b` is `[1, 39]`.
 	fn on_liquidity_changed_multiple_tokens(b: u32) -> Weight {
-		// Minimum execution time: 10_369 nanoseconds.
-		Weight::from_ref_time(10_608_047 as u64) // Standard Error: 2_062
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(10_608_047 as u64)
 			.saturating_add(Weight::from_ref_time(432_350 as u64).saturating_mul(b as u64))
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: EmaOracle Oracles (r:2 w:0)
@@ -98,4 +107,28 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 		// Minimum execution time: 17_936 nanoseconds.
 		Weight::from_ref_time(18_521_000 as u64).saturating_add(T::DbWeight::get().reads(2 as u64))
 	}
+	// Storage: EmaOracle WhitelistedAssets (r:1 w:1)
+	// Proof: EmaOracle WhitelistedAssets (max_values: Some(1), max_size: Some(641), added: 1136, mode: MaxEncodedLen)
+	fn add_oracle() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(15_093_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: EmaOracle WhitelistedAssets (r:1 w:1)
+	// Proof: EmaOracle WhitelistedAssets (max_values: Some(1), max_size: Some(641), added: 1136, mode: MaxEncodedLen)
+	// Storage: EmaOracle Oracles (r:1 w:3)
+	// Proof: EmaOracle Oracles (max_values: None, max_size: Some(177), added: 2652, mode: MaxEncodedLen)
+	// Storage: EmaOracle PriceSnapshots (r:0 w:300)
+	// Proof: EmaOracle PriceSnapshots (max_values: None, max_size: Some(124), added: 2599, mode: MaxEncodedLen)
+	// Storage: EmaOracle SnapshotCursor (r:0 w:1)
+	// Proof: EmaOracle SnapshotCursor (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: EmaOracle Accumulator (r:1 w:1)
+	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	fn remove_oracle() -> Weight {
+		// Not benchmarked: estimated from the storage accesses. Regenerate with `benchmark pallet`.
+		Weight::from_ref_time(1_398_570_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(306 as u64))
+	}
 }
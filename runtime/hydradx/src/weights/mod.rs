@@ -8,12 +8,15 @@ pub mod currencies;
 pub mod dca;
 pub mod democracy;
 pub mod duster;
+pub mod dynamic_fees;
 pub mod ema_oracle;
 pub mod identity;
 pub mod lbp;
 pub mod omnipool;
 pub mod omnipool_lm;
+pub mod omnipool_subpools;
 pub mod otc;
+pub mod otc_settlements;
 pub mod payment;
 pub mod preimage;
 pub mod proxy;
@@ -22,6 +25,7 @@ pub mod registry;
 pub mod route_executor;
 pub mod scheduler;
 pub mod stableswap;
+pub mod stableswap_lm;
 pub mod staking;
 pub mod system;
 pub mod technical_comittee;
@@ -34,3 +38,4 @@ pub mod vesting;
 pub mod xcm;
 pub mod xcmp_queue;
 pub mod xyk;
+pub mod xyk_lm;
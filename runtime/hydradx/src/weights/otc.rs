@@ -114,4 +114,18 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(3 as u64))
 			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
+	// Storage: OTC OrderExpiries (r:0 w:1)
+	// Proof: OTC OrderExpiries (max_values: None, max_size: Some(32), added: 2507, mode: MaxEncodedLen)
+	// Storage: OTC Orders (r:1 w:1)
+	// Proof: OTC Orders (max_values: None, max_size: Some(175), added: 2650, mode: MaxEncodedLen)
+	// Storage: Balances Reserves (r:1 w:1)
+	// Proof: Balances Reserves (max_values: None, max_size: Some(1249), added: 3724, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn expire_order() -> Weight {
+		// Minimum execution time: 51_870 nanoseconds.
+		Weight::from_ref_time(52_618_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
 }
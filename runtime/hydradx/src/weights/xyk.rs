@@ -78,11 +78,15 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: XYK TotalLiquidity (max_values: None, max_size: Some(64), added: 2539, mode: MaxEncodedLen)
 	// Storage: XYK PoolAssets (r:0 w:1)
 	// Proof: XYK PoolAssets (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: XYK PoolFee (r:0 w:1)
+	// Proof: XYK PoolFee (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: XYK PoolCreator (r:0 w:1)
+	// Proof: XYK PoolCreator (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	fn create_pool() -> Weight {
 		// Minimum execution time: 190_185 nanoseconds.
 		Weight::from_ref_time(192_567_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(17 as u64))
-			.saturating_add(T::DbWeight::get().writes(16 as u64))
+			.saturating_add(T::DbWeight::get().writes(18 as u64))
 	}
 	// Storage: XYK ShareToken (r:1 w:0)
 	// Proof: XYK ShareToken (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
@@ -160,6 +164,18 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	}
 	// Storage: XYK ShareToken (r:1 w:0)
 	// Proof: XYK ShareToken (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: XYK PoolCreator (r:1 w:0)
+	// Proof: XYK PoolCreator (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	// Storage: XYK PoolFee (r:0 w:1)
+	// Proof: XYK PoolFee (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn update_pool_fee() -> Weight {
+		// Minimum execution time: 22_517 nanoseconds.
+		Weight::from_ref_time(23_104_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: XYK ShareToken (r:1 w:0)
+	// Proof: XYK ShareToken (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
 	// Storage: Tokens Accounts (r:4 w:4)
 	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
 	// Storage: AssetRegistry Assets (r:2 w:0)
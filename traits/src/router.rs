@@ -3,6 +3,8 @@ use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::sp_runtime::{DispatchError, DispatchResult};
 use frame_support::weights::Weight;
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -55,6 +57,7 @@ pub trait RouteProvider<AssetId> {
 	}
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum PoolType<AssetId> {
 	XYK,
@@ -70,6 +73,7 @@ pub enum ExecutorError<E> {
 }
 
 ///A single trade for buy/sell, describing the asset pair and the pool type in which the trade is executed
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Copy, Clone, TypeInfo, MaxEncodedLen)]
 pub struct Trade<AssetId> {
 	pub pool: PoolType<AssetId>,
@@ -77,12 +81,24 @@ pub struct Trade<AssetId> {
 	pub asset_out: AssetId,
 }
 
-#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo)]
 pub struct AmountInAndOut<Balance> {
 	pub amount_in: Balance,
 	pub amount_out: Balance,
 }
 
+///Error of a route calculation
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo)]
+pub enum TradeQuoteError {
+	/// The route has more trades than allowed
+	RouteTooLong,
+	/// No route was provided and none could be found for the asset pair
+	RouteNotFound,
+	/// The trade of the route at `trade_index` could not be calculated
+	TradeCalculationFailed { trade_index: u32, error: DispatchError },
+}
+
 pub fn inverse_route<AssetId>(trades: Vec<Trade<AssetId>>) -> Vec<Trade<AssetId>> {
 	trades
 		.into_iter()
@@ -268,10 +284,33 @@ impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>
 	}
 }
 
+/// Provides all trades which can be executed in the pools of an AMM.
+/// Used by the router to discover routes between assets.
+pub trait TradablePairs<AssetId> {
+	/// Returns a trade for each direction of every tradable asset pair of every pool.
+	fn tradable_pairs() -> Vec<Trade<AssetId>>;
+}
+
+impl<AssetId> TradablePairs<AssetId> for () {
+	fn tradable_pairs() -> Vec<Trade<AssetId>> {
+		Vec::new()
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(1, 5)]
+impl<AssetId> TradablePairs<AssetId> for Tuple {
+	fn tradable_pairs() -> Vec<Trade<AssetId>> {
+		let mut trades = Vec::new();
+		for_tuples!( #( trades.extend(Tuple::tradable_pairs()); )* );
+		trades
+	}
+}
+
 /// Provides weight info for the router. Calculates the weight of a route based on the AMMs.
 pub trait AmmTradeWeights<Trade> {
 	fn sell_weight(route: &[Trade]) -> Weight;
 	fn buy_weight(route: &[Trade]) -> Weight;
+	fn calculate_sell_trade_amounts_weight(route: &[Trade]) -> Weight;
 	fn calculate_buy_trade_amounts_weight(route: &[Trade]) -> Weight;
 	fn sell_and_calculate_sell_trade_amounts_weight(route: &[Trade]) -> Weight;
 	fn buy_and_calculate_buy_trade_amounts_weight(route: &[Trade]) -> Weight;
@@ -285,6 +324,9 @@ impl<Trade> AmmTradeWeights<Trade> for () {
 	fn buy_weight(_route: &[Trade]) -> Weight {
 		Weight::zero()
 	}
+	fn calculate_sell_trade_amounts_weight(_route: &[Trade]) -> Weight {
+		Weight::zero()
+	}
 	fn calculate_buy_trade_amounts_weight(_route: &[Trade]) -> Weight {
 		Weight::zero()
 	}